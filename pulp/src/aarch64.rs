@@ -271,9 +271,552 @@ macro_rules! splat {
 	};
 }
 
+macro_rules! impl_shift {
+	($ty: ident, $signed: ident, $neon_u: ident, $neon_s: ident) => {
+		paste! {
+			#[inline(always)]
+			fn [<wrapping_dyn_shl_ $ty s>](self, a: Self::[<$ty s>], amount: Self::[<$ty s>]) -> Self::[<$ty s>] {
+				unsafe {
+					let amount = [<vandq_ $neon_u>](cast!(amount), [<vdupq_n_ $neon_u>]($ty::BITS as $ty - 1));
+					cast!([<vshlq_ $neon_u>](cast!(a), cast!(amount)))
+				}
+			}
+
+			#[inline(always)]
+			fn [<wrapping_dyn_shr_ $ty s>](self, a: Self::[<$ty s>], amount: Self::[<$ty s>]) -> Self::[<$ty s>] {
+				unsafe {
+					let amount = [<vandq_ $neon_u>](cast!(amount), [<vdupq_n_ $neon_u>]($ty::BITS as $ty - 1));
+					cast!([<vshlq_ $neon_u>](cast!(a), [<vnegq_ $neon_s>](cast!(amount))))
+				}
+			}
+
+			#[inline(always)]
+			fn [<wrapping_dyn_sra_ $signed s>](self, a: Self::[<$signed s>], amount: Self::[<$ty s>]) -> Self::[<$signed s>] {
+				unsafe {
+					let amount = [<vandq_ $neon_u>](cast!(amount), [<vdupq_n_ $neon_u>]($ty::BITS as $ty - 1));
+					cast!([<vshlq_ $neon_s>](cast!(a), [<vnegq_ $neon_s>](cast!(amount))))
+				}
+			}
+		}
+	};
+	($($ty: ident => $signed: ident: $neon_u: ident, $neon_s: ident),*) => {
+		$(impl_shift!($ty, $signed, $neon_u, $neon_s);)*
+	};
+}
+
+macro_rules! impl_widen_narrow {
+	(widen $from: ident => $to: ident: $neon: ident) => {
+		paste! {
+			#[inline(always)]
+			fn [<widen_low_ $from s>](self, a: Self::[<$from s>]) -> Self::[<$to s>] {
+				unsafe { cast!([<vmovl_ $neon>]([<vget_low_ $neon>](cast!(a)))) }
+			}
+
+			#[inline(always)]
+			fn [<widen_high_ $from s>](self, a: Self::[<$from s>]) -> Self::[<$to s>] {
+				unsafe { cast!([<vmovl_high_ $neon>](cast!(a))) }
+			}
+		}
+	};
+	(narrow $func: ident, $op: ident, $from: ident => $to: ident: $neon: ident) => {
+		paste! {
+			#[inline(always)]
+			fn [<$func _ $from s>](self, a: Self::[<$from s>], b: Self::[<$from s>]) -> Self::[<$to s>] {
+				unsafe { cast!([<$op _high_ $neon>]([<$op _ $neon>](cast!(a)), cast!(b))) }
+			}
+		}
+	};
+	() => {
+		impl_widen_narrow!(widen u8 => u16: u8);
+		impl_widen_narrow!(widen i8 => i16: s8);
+		impl_widen_narrow!(widen u16 => u32: u16);
+		impl_widen_narrow!(widen i16 => i32: s16);
+		impl_widen_narrow!(widen u32 => u64: u32);
+		impl_widen_narrow!(widen i32 => i64: s32);
+
+		impl_widen_narrow!(narrow narrow, vmovn, u16 => u8: u16);
+		impl_widen_narrow!(narrow narrow, vmovn, i16 => i8: s16);
+		impl_widen_narrow!(narrow narrow, vmovn, u32 => u16: u32);
+		impl_widen_narrow!(narrow narrow, vmovn, i32 => i16: s32);
+		impl_widen_narrow!(narrow narrow, vmovn, u64 => u32: u64);
+		impl_widen_narrow!(narrow narrow, vmovn, i64 => i32: s64);
+
+		impl_widen_narrow!(narrow narrow_saturating, vqmovn, u16 => u8: u16);
+		impl_widen_narrow!(narrow narrow_saturating, vqmovn, i16 => i8: s16);
+		impl_widen_narrow!(narrow narrow_saturating, vqmovn, u32 => u16: u32);
+		impl_widen_narrow!(narrow narrow_saturating, vqmovn, i32 => i16: s32);
+		impl_widen_narrow!(narrow narrow_saturating, vqmovn, u64 => u32: u64);
+		impl_widen_narrow!(narrow narrow_saturating, vqmovn, i64 => i32: s64);
+
+		impl_widen_narrow!(narrow narrow_saturating_unsigned, vqmovun, i16 => u8: s16);
+		impl_widen_narrow!(narrow narrow_saturating_unsigned, vqmovun, i32 => u16: s32);
+		impl_widen_narrow!(narrow narrow_saturating_unsigned, vqmovun, i64 => u32: s64);
+
+		#[inline(always)]
+		fn widen_low_f32s(self, a: Self::f32s) -> Self::f64s {
+			unsafe { cast!(vcvt_f64_f32(vget_low_f32(cast!(a)))) }
+		}
+
+		#[inline(always)]
+		fn widen_high_f32s(self, a: Self::f32s) -> Self::f64s {
+			unsafe { cast!(vcvt_high_f64_f32(cast!(a))) }
+		}
+
+		#[inline(always)]
+		fn narrow_f64s(self, a: Self::f64s, b: Self::f64s) -> Self::f32s {
+			unsafe { cast!(vcvt_high_f32_f64(vcvt_f32_f64(cast!(a)), cast!(b))) }
+		}
+	};
+}
+
+macro_rules! impl_intrinsic_binop {
+	($func: ident, $op: ident, $($ty: ident: $neon: ident),*) => {
+		paste! {$(
+			#[inline(always)]
+			fn [<$func _ $ty s>](self, a: Self::[<$ty s>], b: Self::[<$ty s>]) -> Self::[<$ty s>] {
+				unsafe { cast!([<$op _ $neon>](cast!(a), cast!(b))) }
+			}
+		)*}
+	};
+}
+
+static NEON_COMPRESS_IDX_32: [[u8; 16]; 16] = compress_idx::<16, 4, 16>();
+static NEON_COMPRESS_IDX_64: [[u8; 16]; 4] = compress_idx::<16, 8, 4>();
+static NEON_EXPAND_IDX_32: [[u8; 16]; 16] = expand_idx::<16, 4, 16>();
+static NEON_EXPAND_IDX_64: [[u8; 16]; 4] = expand_idx::<16, 8, 4>();
+
+macro_rules! impl_compress_expand {
+	() => {
+		#[inline(always)]
+		fn compress_store_u32s(
+			self,
+			mask: Self::m32s,
+			values: Self::u32s,
+			out: &mut [u32],
+		) -> usize {
+			#[cfg(miri)]
+			{
+				compress_store_fallback(mask, values, out)
+			}
+			#[cfg(not(miri))]
+			{
+				let mask = unsafe { vaddvq_u32(vandq_u32(cast!(mask), cast!(u32x4(1, 2, 4, 8)))) };
+				let mask = mask as usize;
+				let count = mask.count_ones() as usize;
+				let values =
+					unsafe { vqtbl1q_u8(cast!(values), cast!(NEON_COMPRESS_IDX_32[mask])) };
+				self.partial_store_u32s(&mut out[..count], cast!(values));
+				count
+			}
+		}
+
+		#[inline(always)]
+		fn compress_store_u64s(
+			self,
+			mask: Self::m64s,
+			values: Self::u64s,
+			out: &mut [u64],
+		) -> usize {
+			#[cfg(miri)]
+			{
+				compress_store_fallback(mask, values, out)
+			}
+			#[cfg(not(miri))]
+			{
+				let mask = unsafe { vaddvq_u64(vandq_u64(cast!(mask), cast!(u64x2(1, 2)))) };
+				let mask = mask as usize;
+				let count = mask.count_ones() as usize;
+				let values =
+					unsafe { vqtbl1q_u8(cast!(values), cast!(NEON_COMPRESS_IDX_64[mask])) };
+				self.partial_store_u64s(&mut out[..count], cast!(values));
+				count
+			}
+		}
+
+		#[inline(always)]
+		fn expand_load_u32s(self, mask: Self::m32s, values: &[u32]) -> Self::u32s {
+			#[cfg(miri)]
+			{
+				expand_load_fallback(mask, values)
+			}
+			#[cfg(not(miri))]
+			{
+				let mask = unsafe { vaddvq_u32(vandq_u32(cast!(mask), cast!(u32x4(1, 2, 4, 8)))) };
+				let mask = mask as usize;
+				let count = mask.count_ones() as usize;
+				let values = self.partial_load_u32s(&values[..count]);
+				unsafe { cast!(vqtbl1q_u8(cast!(values), cast!(NEON_EXPAND_IDX_32[mask]))) }
+			}
+		}
+
+		#[inline(always)]
+		fn expand_load_u64s(self, mask: Self::m64s, values: &[u64]) -> Self::u64s {
+			#[cfg(miri)]
+			{
+				expand_load_fallback(mask, values)
+			}
+			#[cfg(not(miri))]
+			{
+				let mask = unsafe { vaddvq_u64(vandq_u64(cast!(mask), cast!(u64x2(1, 2)))) };
+				let mask = mask as usize;
+				let count = mask.count_ones() as usize;
+				let values = self.partial_load_u64s(&values[..count]);
+				unsafe { cast!(vqtbl1q_u8(cast!(values), cast!(NEON_EXPAND_IDX_64[mask]))) }
+			}
+		}
+	};
+}
+
+macro_rules! impl_mask_query {
+	() => {
+		#[inline(always)]
+		fn to_bitmask_m8s(self, mask: Self::m8s) -> u64 {
+			unsafe {
+				let bits = vandq_u8(
+					cast!(mask),
+					cast!(u8x16(
+						1, 2, 4, 8, 16, 32, 64, 128, 1, 2, 4, 8, 16, 32, 64, 128
+					)),
+				);
+				vaddv_u8(vget_low_u8(bits)) as u64 | ((vaddv_u8(vget_high_u8(bits)) as u64) << 8)
+			}
+		}
+
+		#[inline(always)]
+		fn to_bitmask_m16s(self, mask: Self::m16s) -> u64 {
+			unsafe {
+				vaddvq_u16(vandq_u16(
+					cast!(mask),
+					cast!(u16x8(1, 2, 4, 8, 16, 32, 64, 128)),
+				)) as u64
+			}
+		}
+
+		#[inline(always)]
+		fn to_bitmask_m32s(self, mask: Self::m32s) -> u64 {
+			unsafe { vaddvq_u32(vandq_u32(cast!(mask), cast!(u32x4(1, 2, 4, 8)))) as u64 }
+		}
+
+		#[inline(always)]
+		fn to_bitmask_m64s(self, mask: Self::m64s) -> u64 {
+			unsafe { vaddvq_u64(vandq_u64(cast!(mask), cast!(u64x2(1, 2)))) }
+		}
+
+		#[inline(always)]
+		fn from_bitmask_m8s(self, bits: u64) -> Self::m8s {
+			unsafe {
+				let bits = vcombine_u8(vdup_n_u8(bits as u8), vdup_n_u8((bits >> 8) as u8));
+				cast!(vtstq_u8(
+					bits,
+					cast!(u8x16(
+						1, 2, 4, 8, 16, 32, 64, 128, 1, 2, 4, 8, 16, 32, 64, 128
+					)),
+				))
+			}
+		}
+
+		#[inline(always)]
+		fn from_bitmask_m16s(self, bits: u64) -> Self::m16s {
+			unsafe {
+				cast!(vtstq_u16(
+					vdupq_n_u16(bits as u16),
+					cast!(u16x8(1, 2, 4, 8, 16, 32, 64, 128)),
+				))
+			}
+		}
+
+		#[inline(always)]
+		fn from_bitmask_m32s(self, bits: u64) -> Self::m32s {
+			unsafe {
+				cast!(vtstq_u32(
+					vdupq_n_u32(bits as u32),
+					cast!(u32x4(1, 2, 4, 8))
+				))
+			}
+		}
+
+		#[inline(always)]
+		fn from_bitmask_m64s(self, bits: u64) -> Self::m64s {
+			unsafe { cast!(vtstq_u64(vdupq_n_u64(bits), cast!(u64x2(1, 2)))) }
+		}
+
+		// the lanes of a mask are all ones or all zeros, so a horizontal max or min over any lane
+		// width tells whether some or all of the lanes are enabled
+		#[inline(always)]
+		fn any_m8s(self, mask: Self::m8s) -> bool {
+			unsafe { vmaxvq_u32(cast!(mask)) != 0 }
+		}
+
+		#[inline(always)]
+		fn any_m16s(self, mask: Self::m16s) -> bool {
+			unsafe { vmaxvq_u32(cast!(mask)) != 0 }
+		}
+
+		#[inline(always)]
+		fn any_m32s(self, mask: Self::m32s) -> bool {
+			unsafe { vmaxvq_u32(cast!(mask)) != 0 }
+		}
+
+		#[inline(always)]
+		fn any_m64s(self, mask: Self::m64s) -> bool {
+			unsafe { vmaxvq_u32(cast!(mask)) != 0 }
+		}
+
+		#[inline(always)]
+		fn all_m8s(self, mask: Self::m8s) -> bool {
+			unsafe { vminvq_u8(cast!(mask)) != 0 }
+		}
+
+		#[inline(always)]
+		fn all_m16s(self, mask: Self::m16s) -> bool {
+			unsafe { vminvq_u16(cast!(mask)) != 0 }
+		}
+
+		#[inline(always)]
+		fn all_m32s(self, mask: Self::m32s) -> bool {
+			unsafe { vminvq_u32(cast!(mask)) != 0 }
+		}
+
+		#[inline(always)]
+		fn all_m64s(self, mask: Self::m64s) -> bool {
+			unsafe { vminvq_u32(cast!(mask)) != 0 }
+		}
+	};
+}
+
+macro_rules! impl_ieee_min_max {
+	() => {
+		// `fmin` and `fmax` already propagate nans and order `-0.0` below `+0.0`
+		#[inline(always)]
+		fn min_nan_propagating_f32s(self, a: Self::f32s, b: Self::f32s) -> Self::f32s {
+			self.min_f32x4(a, b)
+		}
+
+		#[inline(always)]
+		fn min_nan_propagating_f64s(self, a: Self::f64s, b: Self::f64s) -> Self::f64s {
+			self.min_f64x2(a, b)
+		}
+
+		#[inline(always)]
+		fn max_nan_propagating_f32s(self, a: Self::f32s, b: Self::f32s) -> Self::f32s {
+			self.max_f32x4(a, b)
+		}
+
+		#[inline(always)]
+		fn max_nan_propagating_f64s(self, a: Self::f64s, b: Self::f64s) -> Self::f64s {
+			self.max_f64x2(a, b)
+		}
+
+		#[inline(always)]
+		fn reduce_min_nan_propagating_f32s(self, a: Self::f32s) -> f32 {
+			unsafe { vminvq_f32(cast!(a)) }
+		}
+
+		#[inline(always)]
+		fn reduce_min_nan_propagating_f64s(self, a: Self::f64s) -> f64 {
+			unsafe { vminvq_f64(cast!(a)) }
+		}
+
+		#[inline(always)]
+		fn reduce_max_nan_propagating_f32s(self, a: Self::f32s) -> f32 {
+			unsafe { vmaxvq_f32(cast!(a)) }
+		}
+
+		#[inline(always)]
+		fn reduce_max_nan_propagating_f64s(self, a: Self::f64s) -> f64 {
+			unsafe { vmaxvq_f64(cast!(a)) }
+		}
+	};
+}
+
+macro_rules! impl_mul_high {
+	() => {
+		#[inline(always)]
+		fn mul_u8s(self, a: Self::u8s, b: Self::u8s) -> Self::u8s {
+			unsafe { cast!(vmulq_u8(cast!(a), cast!(b))) }
+		}
+
+		#[inline(always)]
+		fn mul_high_u16s(self, a: Self::u16s, b: Self::u16s) -> Self::u16s {
+			unsafe {
+				let a: uint16x8_t = cast!(a);
+				let b: uint16x8_t = cast!(b);
+				let lo = vmull_u16(vget_low_u16(a), vget_low_u16(b));
+				let hi = vmull_high_u16(a, b);
+				cast!(vuzp2q_u16(cast!(lo), cast!(hi)))
+			}
+		}
+
+		#[inline(always)]
+		fn mul_high_i16s(self, a: Self::i16s, b: Self::i16s) -> Self::i16s {
+			unsafe {
+				let a: int16x8_t = cast!(a);
+				let b: int16x8_t = cast!(b);
+				let lo = vmull_s16(vget_low_s16(a), vget_low_s16(b));
+				let hi = vmull_high_s16(a, b);
+				cast!(vuzp2q_s16(cast!(lo), cast!(hi)))
+			}
+		}
+	};
+}
+
+macro_rules! impl_fixed_point {
+	() => {
+		#[inline(always)]
+		fn mul_round_q15_i16s(self, a: Self::i16s, b: Self::i16s) -> Self::i16s {
+			unsafe { cast!(vqrdmulhq_s16(cast!(a), cast!(b))) }
+		}
+
+		#[inline(always)]
+		fn mul_round_q31_i32s(self, a: Self::i32s, b: Self::i32s) -> Self::i32s {
+			unsafe { cast!(vqrdmulhq_s32(cast!(a), cast!(b))) }
+		}
+
+		// `sqshl` saturates any nonzero lane once the amount reaches the lane width
+		#[inline(always)]
+		fn saturating_shl_i16s(self, a: Self::i16s, amount: u32) -> Self::i16s {
+			unsafe {
+				cast!(vqshlq_s16(
+					cast!(a),
+					vdupq_n_s16(Ord::min(amount, 16) as i16)
+				))
+			}
+		}
+
+		#[inline(always)]
+		fn saturating_shl_i32s(self, a: Self::i32s, amount: u32) -> Self::i32s {
+			unsafe {
+				cast!(vqshlq_s32(
+					cast!(a),
+					vdupq_n_s32(Ord::min(amount, 32) as i32)
+				))
+			}
+		}
+	};
+}
+
+macro_rules! impl_recip_approx {
+	() => {
+		#[inline(always)]
+		fn recip_approx_f32s(self, a: Self::f32s) -> Self::f32s {
+			unsafe { cast!(vrecpeq_f32(cast!(a))) }
+		}
+
+		#[inline(always)]
+		fn rsqrt_approx_f32s(self, a: Self::f32s) -> Self::f32s {
+			unsafe { cast!(vrsqrteq_f32(cast!(a))) }
+		}
+
+		// the initial estimate only has around 8 bits of precision, so two newton steps are needed.
+		// the step instructions already handle `0 * inf` for zero and infinite inputs
+		#[inline(always)]
+		fn recip_approx_refined_f32s(self, a: Self::f32s) -> Self::f32s {
+			recip_in_range_f32s(self, a, |a| unsafe {
+				let a: float32x4_t = cast!(a);
+				let x = vrecpeq_f32(a);
+				let x = vmulq_f32(x, vrecpsq_f32(a, x));
+				let x = vmulq_f32(x, vrecpsq_f32(a, x));
+				cast!(x)
+			})
+		}
+
+		#[inline(always)]
+		fn rsqrt_approx_refined_f32s(self, a: Self::f32s) -> Self::f32s {
+			rsqrt_in_range_f32s(self, a, |a| unsafe {
+				let a: float32x4_t = cast!(a);
+				let y = vrsqrteq_f32(a);
+				let y = vmulq_f32(y, vrsqrtsq_f32(vmulq_f32(y, y), a));
+				let y = vmulq_f32(y, vrsqrtsq_f32(vmulq_f32(y, y), a));
+				cast!(y)
+			})
+		}
+	};
+}
+
+macro_rules! impl_int_reduce {
+	($func: ident, $op: ident, $($ty: ident: $neon: ident),*) => {
+		paste! {$(
+			#[inline(always)]
+			fn [<reduce_ $func _ $ty s>](self, a: Self::[<$ty s>]) -> $ty {
+				unsafe { [<$op _ $neon>](cast!(a)) }
+			}
+		)*}
+	};
+	() => {
+		#[inline(always)]
+		fn reduce_sum_u8s(self, a: Self::u8s) -> u64 {
+			unsafe { vaddlvq_u8(cast!(a)) as u64 }
+		}
+
+		impl_int_reduce!(sum, vaddvq, u32: u32, i32: s32, u64: u64, i64: s64);
+		impl_int_reduce!(min, vminvq, u8: u8, i8: s8, u16: u16, i16: s16, u32: u32, i32: s32);
+		impl_int_reduce!(max, vmaxvq, u8: u8, i8: s8, u16: u16, i16: s16, u32: u32, i32: s32);
+	};
+}
+
+macro_rules! impl_bit_count {
+	(trailing_zeros $ty: ident: $neon: ident, $rev: path) => {
+		paste! {
+			#[inline(always)]
+			fn [<trailing_zeros_ $ty s>](self, a: Self::[<$ty s>]) -> Self::[<$ty s>] {
+				// reversing the bits of each lane turns trailing zeros into leading zeros
+				unsafe { cast!([<vclzq_ $neon>](cast!($rev(vrbitq_u8(cast!(a)))))) }
+			}
+		}
+	};
+	() => {
+		#[inline(always)]
+		fn count_ones_u8s(self, a: Self::u8s) -> Self::u8s {
+			unsafe { cast!(vcntq_u8(cast!(a))) }
+		}
+
+		#[inline(always)]
+		fn count_ones_u16s(self, a: Self::u16s) -> Self::u16s {
+			unsafe { cast!(vpaddlq_u8(vcntq_u8(cast!(a)))) }
+		}
+
+		#[inline(always)]
+		fn count_ones_u32s(self, a: Self::u32s) -> Self::u32s {
+			unsafe { cast!(vpaddlq_u16(vpaddlq_u8(vcntq_u8(cast!(a))))) }
+		}
+
+		#[inline(always)]
+		fn count_ones_u64s(self, a: Self::u64s) -> Self::u64s {
+			unsafe { cast!(vpaddlq_u32(vpaddlq_u16(vpaddlq_u8(vcntq_u8(cast!(a)))))) }
+		}
+
+		#[inline(always)]
+		fn leading_zeros_u8s(self, a: Self::u8s) -> Self::u8s {
+			unsafe { cast!(vclzq_u8(cast!(a))) }
+		}
+
+		#[inline(always)]
+		fn leading_zeros_u16s(self, a: Self::u16s) -> Self::u16s {
+			unsafe { cast!(vclzq_u16(cast!(a))) }
+		}
+
+		#[inline(always)]
+		fn leading_zeros_u32s(self, a: Self::u32s) -> Self::u32s {
+			unsafe { cast!(vclzq_u32(cast!(a))) }
+		}
+
+		impl_bit_count!(trailing_zeros u8: u8, core::convert::identity);
+		impl_bit_count!(trailing_zeros u16: u16, vrev16q_u8);
+		impl_bit_count!(trailing_zeros u32: u32, vrev32q_u8);
+
+		#[inline(always)]
+		fn trailing_zeros_u64s(self, a: Self::u64s) -> Self::u64s {
+			// the trailing zeros of `a` are exactly the ones of `!a & (a - 1)`
+			self.count_ones_u64s(self.and_u64s(self.not_u64s(a), self.sub_u64s(a, self.splat_u64s(1))))
+		}
+	};
+}
+
 impl Simd for Neon {
+	type bf16s = u16x8;
 	type c32s = f32x4;
 	type c64s = f64x2;
+	type f16s = u16x8;
 	type f32s = f32x4;
 	type f64s = f64x2;
 	type i16s = i16x8;
@@ -299,6 +842,24 @@ impl Simd for Neon {
 
 	impl_binop!(sub, wrapping_sub, u8 x 16, i8 x 16, u16 x 8, i16 x 8, u32 x 4, i32 x 4, u64 x 2, i64 x 2);
 
+	impl_intrinsic_binop!(saturating_add, vqaddq, u8: u8, i8: s8, u16: u16, i16: s16);
+
+	impl_intrinsic_binop!(saturating_sub, vqsubq, u8: u8, i8: s8, u16: u16, i16: s16);
+
+	impl_intrinsic_binop!(average, vrhaddq, u8: u8, u16: u16);
+
+	impl_compress_expand!();
+
+	impl_mask_query!();
+
+	impl_ieee_min_max!();
+
+	impl_mul_high!();
+
+	impl_fixed_point!();
+
+	impl_recip_approx!();
+
 	impl_binop!(mul, u16 x 8, i16 x 8, u32 x 4, i32 x 4);
 
 	impl_binop_scalar!(mul, u64, i64);
@@ -333,6 +894,12 @@ impl Simd for Neon {
 
 	splat!(u8 x 16, i8 x 16, u16 x 8, i16 x 8, u32 x 4, i32 x 4, u64 x 2, i64 x 2, f32 x 4, f64 x 2);
 
+	impl_shift!(u8 => i8: u8, s8, u16 => i16: u16, s16, u32 => i32: u32, s32, u64 => i64: u64, s64);
+
+	impl_widen_narrow!();
+	impl_int_reduce!();
+	impl_bit_count!();
+
 	#[inline(always)]
 	fn abs2_c32s(self, a: Self::c32s) -> Self::c32s {
 		unsafe {
@@ -1335,6 +1902,18 @@ impl Simd for Neon {
 		}
 	}
 
+	#[inline(always)]
+	fn table_lookup_u8s(self, table: Self::u8s, idx: Self::u8s) -> Self::u8s {
+		#[cfg(miri)]
+		{
+			Scalar128b.table_lookup_u8s(table, idx)
+		}
+		#[cfg(not(miri))]
+		{
+			unsafe { cast!(vqtbl1q_u8(cast!(table), cast!(idx))) }
+		}
+	}
+
 	#[inline(always)]
 	fn select_u32s(
 		self,
@@ -1355,6 +1934,21 @@ impl Simd for Neon {
 		self.select_u64x2(mask, if_true, if_false)
 	}
 
+	#[inline(always)]
+	fn select_u8s(self, mask: Self::m8s, if_true: Self::u8s, if_false: Self::u8s) -> Self::u8s {
+		self.select_u8x16(mask, if_true, if_false)
+	}
+
+	#[inline(always)]
+	fn select_u16s(
+		self,
+		mask: Self::m16s,
+		if_true: Self::u16s,
+		if_false: Self::u16s,
+	) -> Self::u16s {
+		self.select_u16x8(mask, if_true, if_false)
+	}
+
 	#[inline(always)]
 	fn splat_c32s(self, value: c32) -> Self::c32s {
 		cast!(self.splat_f64x2(cast!(value)))
@@ -1427,34 +2021,131 @@ impl Simd for Neon {
 	}
 
 	#[inline(always)]
-	fn wrapping_dyn_shl_u32s(self, a: Self::u32s, amount: Self::u32s) -> Self::u32s {
-		self.shl_dyn_u32x4(a, self.and_i32x4(cast!(amount), self.splat_i32x4(32 - 1)))
+	fn sqrt_f32s(self, a: Self::f32s) -> Self::f32s {
+		cast!(self.neon.vsqrtq_f32(cast!(a)))
 	}
 
 	#[inline(always)]
-	fn wrapping_dyn_shr_u32s(self, a: Self::u32s, amount: Self::u32s) -> Self::u32s {
-		u32x4(
-			a.0 >> amount.0,
-			a.1 >> amount.1,
-			a.2 >> amount.2,
-			a.3 >> amount.3,
-		)
+	fn sqrt_f64s(self, a: Self::f64s) -> Self::f64s {
+		cast!(self.neon.vsqrtq_f64(cast!(a)))
 	}
 
 	#[inline(always)]
-	fn sqrt_f32s(self, a: Self::f32s) -> Self::f32s {
-		cast!(self.neon.vsqrtq_f32(cast!(a)))
+	fn floor_f32s(self, a: Self::f32s) -> Self::f32s {
+		cast!(self.neon.vrndmq_f32(cast!(a)))
 	}
 
 	#[inline(always)]
-	fn sqrt_f64s(self, a: Self::f64s) -> Self::f64s {
-		cast!(self.neon.vsqrtq_f64(cast!(a)))
+	fn floor_f64s(self, a: Self::f64s) -> Self::f64s {
+		cast!(self.neon.vrndmq_f64(cast!(a)))
+	}
+
+	#[inline(always)]
+	fn ceil_f32s(self, a: Self::f32s) -> Self::f32s {
+		cast!(self.neon.vrndpq_f32(cast!(a)))
+	}
+
+	#[inline(always)]
+	fn ceil_f64s(self, a: Self::f64s) -> Self::f64s {
+		cast!(self.neon.vrndpq_f64(cast!(a)))
+	}
+
+	#[inline(always)]
+	fn trunc_f32s(self, a: Self::f32s) -> Self::f32s {
+		cast!(self.neon.vrndq_f32(cast!(a)))
+	}
+
+	#[inline(always)]
+	fn trunc_f64s(self, a: Self::f64s) -> Self::f64s {
+		cast!(self.neon.vrndq_f64(cast!(a)))
+	}
+
+	#[inline(always)]
+	fn round_ties_even_f32s(self, a: Self::f32s) -> Self::f32s {
+		cast!(self.neon.vrndnq_f32(cast!(a)))
+	}
+
+	#[inline(always)]
+	fn round_ties_even_f64s(self, a: Self::f64s) -> Self::f64s {
+		cast!(self.neon.vrndnq_f64(cast!(a)))
+	}
+
+	#[inline(always)]
+	fn round_ties_away_f32s(self, a: Self::f32s) -> Self::f32s {
+		cast!(self.neon.vrndaq_f32(cast!(a)))
+	}
+
+	#[inline(always)]
+	fn round_ties_away_f64s(self, a: Self::f64s) -> Self::f64s {
+		cast!(self.neon.vrndaq_f64(cast!(a)))
+	}
+
+	#[inline(always)]
+	fn convert_f32s_i32s(self, a: Self::f32s) -> Self::i32s {
+		cast!(self.neon.vcvtq_s32_f32(cast!(a)))
+	}
+
+	#[inline(always)]
+	fn convert_f32s_u32s(self, a: Self::f32s) -> Self::u32s {
+		cast!(self.neon.vcvtq_u32_f32(cast!(a)))
+	}
+
+	#[inline(always)]
+	fn convert_f64s_i64s(self, a: Self::f64s) -> Self::i64s {
+		cast!(self.neon.vcvtq_s64_f64(cast!(a)))
+	}
+
+	#[inline(always)]
+	fn convert_f64s_u64s(self, a: Self::f64s) -> Self::u64s {
+		cast!(self.neon.vcvtq_u64_f64(cast!(a)))
+	}
+
+	#[inline(always)]
+	fn convert_round_f32s_i32s(self, a: Self::f32s) -> Self::i32s {
+		cast!(self.neon.vcvtnq_s32_f32(cast!(a)))
+	}
+
+	#[inline(always)]
+	fn convert_round_f32s_u32s(self, a: Self::f32s) -> Self::u32s {
+		cast!(self.neon.vcvtnq_u32_f32(cast!(a)))
+	}
+
+	#[inline(always)]
+	fn convert_round_f64s_i64s(self, a: Self::f64s) -> Self::i64s {
+		cast!(self.neon.vcvtnq_s64_f64(cast!(a)))
+	}
+
+	#[inline(always)]
+	fn convert_round_f64s_u64s(self, a: Self::f64s) -> Self::u64s {
+		cast!(self.neon.vcvtnq_u64_f64(cast!(a)))
+	}
+
+	#[inline(always)]
+	fn convert_i32s_f32s(self, a: Self::i32s) -> Self::f32s {
+		cast!(self.neon.vcvtq_f32_s32(cast!(a)))
+	}
+
+	#[inline(always)]
+	fn convert_u32s_f32s(self, a: Self::u32s) -> Self::f32s {
+		cast!(self.neon.vcvtq_f32_u32(cast!(a)))
+	}
+
+	#[inline(always)]
+	fn convert_i64s_f64s(self, a: Self::i64s) -> Self::f64s {
+		cast!(self.neon.vcvtq_f64_s64(cast!(a)))
+	}
+
+	#[inline(always)]
+	fn convert_u64s_f64s(self, a: Self::u64s) -> Self::f64s {
+		cast!(self.neon.vcvtq_f64_u64(cast!(a)))
 	}
 }
 
 impl Simd for NeonFcma {
+	type bf16s = u16x8;
 	type c32s = f32x4;
 	type c64s = f64x2;
+	type f16s = u16x8;
 	type f32s = f32x4;
 	type f64s = f64x2;
 	type i16s = i16x8;
@@ -1480,6 +2171,24 @@ impl Simd for NeonFcma {
 
 	impl_binop!(sub, wrapping_sub, u8 x 16, i8 x 16, u16 x 8, i16 x 8, u32 x 4, i32 x 4, u64 x 2, i64 x 2);
 
+	impl_intrinsic_binop!(saturating_add, vqaddq, u8: u8, i8: s8, u16: u16, i16: s16);
+
+	impl_intrinsic_binop!(saturating_sub, vqsubq, u8: u8, i8: s8, u16: u16, i16: s16);
+
+	impl_intrinsic_binop!(average, vrhaddq, u8: u8, u16: u16);
+
+	impl_compress_expand!();
+
+	impl_mask_query!();
+
+	impl_ieee_min_max!();
+
+	impl_mul_high!();
+
+	impl_fixed_point!();
+
+	impl_recip_approx!();
+
 	impl_binop!(mul, u16 x 8, i16 x 8, u32 x 4, i32 x 4);
 
 	impl_binop_scalar!(mul, u64, i64);
@@ -1514,6 +2223,12 @@ impl Simd for NeonFcma {
 
 	splat!(u8 x 16, i8 x 16, u16 x 8, i16 x 8, u32 x 4, i32 x 4, u64 x 2, i64 x 2, f32 x 4, f64 x 2);
 
+	impl_shift!(u8 => i8: u8, s8, u16 => i16: u16, s16, u32 => i32: u32, s32, u64 => i64: u64, s64);
+
+	impl_widen_narrow!();
+	impl_int_reduce!();
+	impl_bit_count!();
+
 	#[inline(always)]
 	fn abs2_c32s(self, a: Self::c32s) -> Self::c32s {
 		unsafe {
@@ -2287,6 +3002,18 @@ impl Simd for NeonFcma {
 		}
 	}
 
+	#[inline(always)]
+	fn table_lookup_u8s(self, table: Self::u8s, idx: Self::u8s) -> Self::u8s {
+		#[cfg(miri)]
+		{
+			Scalar128b.table_lookup_u8s(table, idx)
+		}
+		#[cfg(not(miri))]
+		{
+			unsafe { cast!(vqtbl1q_u8(cast!(table), cast!(idx))) }
+		}
+	}
+
 	#[inline(always)]
 	fn select_u32s(
 		self,
@@ -2307,6 +3034,21 @@ impl Simd for NeonFcma {
 		self.select_u64x2(mask, if_true, if_false)
 	}
 
+	#[inline(always)]
+	fn select_u8s(self, mask: Self::m8s, if_true: Self::u8s, if_false: Self::u8s) -> Self::u8s {
+		self.select_u8x16(mask, if_true, if_false)
+	}
+
+	#[inline(always)]
+	fn select_u16s(
+		self,
+		mask: Self::m16s,
+		if_true: Self::u16s,
+		if_false: Self::u16s,
+	) -> Self::u16s {
+		self.select_u16x8(mask, if_true, if_false)
+	}
+
 	#[inline(always)]
 	fn splat_c32s(self, value: c32) -> Self::c32s {
 		cast!(self.splat_f64x2(cast!(value)))
@@ -2379,28 +3121,123 @@ impl Simd for NeonFcma {
 	}
 
 	#[inline(always)]
-	fn wrapping_dyn_shl_u32s(self, a: Self::u32s, amount: Self::u32s) -> Self::u32s {
-		self.shl_dyn_u32x4(a, self.and_i32x4(cast!(amount), self.splat_i32x4(32 - 1)))
+	fn sqrt_f32s(self, a: Self::f32s) -> Self::f32s {
+		cast!(self.neon.vsqrtq_f32(cast!(a)))
 	}
 
 	#[inline(always)]
-	fn wrapping_dyn_shr_u32s(self, a: Self::u32s, amount: Self::u32s) -> Self::u32s {
-		u32x4(
-			a.0 >> amount.0,
-			a.1 >> amount.1,
-			a.2 >> amount.2,
-			a.3 >> amount.3,
-		)
+	fn sqrt_f64s(self, a: Self::f64s) -> Self::f64s {
+		cast!(self.neon.vsqrtq_f64(cast!(a)))
 	}
 
 	#[inline(always)]
-	fn sqrt_f32s(self, a: Self::f32s) -> Self::f32s {
-		cast!(self.neon.vsqrtq_f32(cast!(a)))
+	fn floor_f32s(self, a: Self::f32s) -> Self::f32s {
+		cast!(self.neon.vrndmq_f32(cast!(a)))
 	}
 
 	#[inline(always)]
-	fn sqrt_f64s(self, a: Self::f64s) -> Self::f64s {
-		cast!(self.neon.vsqrtq_f64(cast!(a)))
+	fn floor_f64s(self, a: Self::f64s) -> Self::f64s {
+		cast!(self.neon.vrndmq_f64(cast!(a)))
+	}
+
+	#[inline(always)]
+	fn ceil_f32s(self, a: Self::f32s) -> Self::f32s {
+		cast!(self.neon.vrndpq_f32(cast!(a)))
+	}
+
+	#[inline(always)]
+	fn ceil_f64s(self, a: Self::f64s) -> Self::f64s {
+		cast!(self.neon.vrndpq_f64(cast!(a)))
+	}
+
+	#[inline(always)]
+	fn trunc_f32s(self, a: Self::f32s) -> Self::f32s {
+		cast!(self.neon.vrndq_f32(cast!(a)))
+	}
+
+	#[inline(always)]
+	fn trunc_f64s(self, a: Self::f64s) -> Self::f64s {
+		cast!(self.neon.vrndq_f64(cast!(a)))
+	}
+
+	#[inline(always)]
+	fn round_ties_even_f32s(self, a: Self::f32s) -> Self::f32s {
+		cast!(self.neon.vrndnq_f32(cast!(a)))
+	}
+
+	#[inline(always)]
+	fn round_ties_even_f64s(self, a: Self::f64s) -> Self::f64s {
+		cast!(self.neon.vrndnq_f64(cast!(a)))
+	}
+
+	#[inline(always)]
+	fn round_ties_away_f32s(self, a: Self::f32s) -> Self::f32s {
+		cast!(self.neon.vrndaq_f32(cast!(a)))
+	}
+
+	#[inline(always)]
+	fn round_ties_away_f64s(self, a: Self::f64s) -> Self::f64s {
+		cast!(self.neon.vrndaq_f64(cast!(a)))
+	}
+
+	#[inline(always)]
+	fn convert_f32s_i32s(self, a: Self::f32s) -> Self::i32s {
+		cast!(self.neon.vcvtq_s32_f32(cast!(a)))
+	}
+
+	#[inline(always)]
+	fn convert_f32s_u32s(self, a: Self::f32s) -> Self::u32s {
+		cast!(self.neon.vcvtq_u32_f32(cast!(a)))
+	}
+
+	#[inline(always)]
+	fn convert_f64s_i64s(self, a: Self::f64s) -> Self::i64s {
+		cast!(self.neon.vcvtq_s64_f64(cast!(a)))
+	}
+
+	#[inline(always)]
+	fn convert_f64s_u64s(self, a: Self::f64s) -> Self::u64s {
+		cast!(self.neon.vcvtq_u64_f64(cast!(a)))
+	}
+
+	#[inline(always)]
+	fn convert_round_f32s_i32s(self, a: Self::f32s) -> Self::i32s {
+		cast!(self.neon.vcvtnq_s32_f32(cast!(a)))
+	}
+
+	#[inline(always)]
+	fn convert_round_f32s_u32s(self, a: Self::f32s) -> Self::u32s {
+		cast!(self.neon.vcvtnq_u32_f32(cast!(a)))
+	}
+
+	#[inline(always)]
+	fn convert_round_f64s_i64s(self, a: Self::f64s) -> Self::i64s {
+		cast!(self.neon.vcvtnq_s64_f64(cast!(a)))
+	}
+
+	#[inline(always)]
+	fn convert_round_f64s_u64s(self, a: Self::f64s) -> Self::u64s {
+		cast!(self.neon.vcvtnq_u64_f64(cast!(a)))
+	}
+
+	#[inline(always)]
+	fn convert_i32s_f32s(self, a: Self::i32s) -> Self::f32s {
+		cast!(self.neon.vcvtq_f32_s32(cast!(a)))
+	}
+
+	#[inline(always)]
+	fn convert_u32s_f32s(self, a: Self::u32s) -> Self::f32s {
+		cast!(self.neon.vcvtq_f32_u32(cast!(a)))
+	}
+
+	#[inline(always)]
+	fn convert_i64s_f64s(self, a: Self::i64s) -> Self::f64s {
+		cast!(self.neon.vcvtq_f64_s64(cast!(a)))
+	}
+
+	#[inline(always)]
+	fn convert_u64s_f64s(self, a: Self::u64s) -> Self::f64s {
+		cast!(self.neon.vcvtq_f64_u64(cast!(a)))
 	}
 
 	#[inline(always)]
@@ -3363,22 +4200,99 @@ impl Neon {
 pub enum Arch {
 	Scalar = 0,
 	Neon(Neon) = 1,
+	NeonFcma(NeonFcma) = 2,
+}
+
+/// aarch64 instruction set levels, ordered from the least to the most capable.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[non_exhaustive]
+pub enum ArchLevel {
+	Scalar,
+	Neon,
+	NeonFcma,
+}
+
+impl ArchLevel {
+	const NAMES: &[(&str, Self)] = &[
+		("scalar", Self::Scalar),
+		("neon", Self::Neon),
+		("neon-fcma", Self::NeonFcma),
+	];
+
+	/// Parses a level from its name, ignoring case: `"scalar"`, `"neon"` or `"neon-fcma"`.
+	#[inline]
+	pub fn from_name(name: &str) -> Option<Self> {
+		let name = name.trim();
+		Self::NAMES
+			.iter()
+			.find(|(level_name, _)| level_name.eq_ignore_ascii_case(name))
+			.map(|&(_, level)| level)
+	}
+
+	/// Returns the name of the level, as accepted by [`ArchLevel::from_name`].
+	#[inline]
+	pub fn name(self) -> &'static str {
+		Self::NAMES
+			.iter()
+			.find(|&&(_, level)| level == self)
+			.unwrap()
+			.0
+	}
 }
 
 impl Arch {
 	/// Detects the best available instruction set.
+	///
+	/// With the `std` feature, a lower level can be requested through the `PULP_ARCH` environment
+	/// variable (e.g. `PULP_ARCH=scalar`), which is read once and clamped to what the cpu supports.
+	/// Unknown names are ignored.
 	#[inline]
 	pub fn new() -> Self {
-		if let Some(simd) = Neon::try_new() {
-			return Self::Neon(simd);
+		#[cfg(feature = "std")]
+		if let Some(level) = crate::arch_override() {
+			return Self::with_max_level(level);
+		}
+		Self::with_max_level(ArchLevel::NeonFcma)
+	}
+
+	/// Detects the best available instruction set that does not exceed `level`.
+	#[inline]
+	pub fn with_max_level(level: ArchLevel) -> Self {
+		if level >= ArchLevel::NeonFcma {
+			if let Some(simd) = NeonFcma::try_new() {
+				return Self::NeonFcma(simd);
+			}
+		}
+		if level >= ArchLevel::Neon {
+			if let Some(simd) = Neon::try_new() {
+				return Self::Neon(simd);
+			}
 		}
 		Self::Scalar
 	}
 
+	/// Detects the best available instruction set that does not exceed the level named `name`, see
+	/// [`ArchLevel::from_name`]. Returns `None` if the name is not recognized.
+	#[inline]
+	pub fn from_name(name: &str) -> Option<Self> {
+		ArchLevel::from_name(name).map(Self::with_max_level)
+	}
+
+	/// Returns the level of the instruction set.
+	#[inline]
+	pub fn level(self) -> ArchLevel {
+		match self {
+			Arch::NeonFcma(_) => ArchLevel::NeonFcma,
+			Arch::Neon(_) => ArchLevel::Neon,
+			Arch::Scalar => ArchLevel::Scalar,
+		}
+	}
+
 	/// Detects the best available instruction set.
 	#[inline(always)]
 	pub fn dispatch<Op: WithSimd>(self, op: Op) -> Op::Output {
 		match self {
+			Arch::NeonFcma(simd) => Simd::vectorize(simd, op),
 			Arch::Neon(simd) => Simd::vectorize(simd, op),
 			Arch::Scalar => Simd::vectorize(Scalar::new(), op),
 		}
@@ -3561,6 +4475,66 @@ mod tests {
 		}
 	}
 
+	#[test]
+	fn test_arch_level() {
+		assert!(matches!(
+			Arch::with_max_level(ArchLevel::Scalar),
+			Arch::Scalar
+		));
+		assert!(Arch::from_name("sve").is_none());
+
+		for level in [ArchLevel::Scalar, ArchLevel::Neon, ArchLevel::NeonFcma] {
+			assert_eq!(ArchLevel::from_name(level.name()), Some(level));
+			let arch = Arch::with_max_level(level);
+			assert!(arch.level() <= level);
+		}
+
+		if NeonFcma::try_new().is_some() {
+			assert!(matches!(Arch::new(), Arch::NeonFcma(_)));
+			assert!(matches!(
+				Arch::with_max_level(ArchLevel::Neon),
+				Arch::Neon(_)
+			));
+		}
+	}
+
+	#[test]
+	fn test_arch_neon_fcma_dispatch() {
+		if NeonFcma::try_new().is_none() {
+			return;
+		}
+		let arch = Arch::with_max_level(ArchLevel::NeonFcma);
+		assert!(matches!(arch, Arch::NeonFcma(_)));
+
+		struct Dot<'a>(&'a [c32], &'a [c32]);
+		impl WithSimd for Dot<'_> {
+			type Output = c32;
+
+			#[inline(always)]
+			fn with_simd<S: Simd>(self, simd: S) -> Self::Output {
+				let (a, a_tail) = S::as_simd_c32s(self.0);
+				let (b, b_tail) = S::as_simd_c32s(self.1);
+				let mut acc = simd.splat_c32s(c32::new(0.0, 0.0));
+				for (&a, &b) in core::iter::zip(a, b) {
+					acc = simd.conj_mul_add_c32s(a, b, acc);
+				}
+				let mut acc = simd.reduce_sum_c32s(acc);
+				for (&a, &b) in core::iter::zip(a_tail, b_tail) {
+					acc += a.conj() * b;
+				}
+				acc
+			}
+		}
+
+		let a: [c32; 101] = core::array::from_fn(|_| c32::new(random(), random()));
+		let b: [c32; 101] = core::array::from_fn(|_| c32::new(random(), random()));
+
+		let expected = Simd::vectorize(Scalar::new(), Dot(&a, &b));
+		let actual = arch.dispatch(Dot(&a, &b));
+		assert!((actual.re - expected.re).abs() < 1e-3);
+		assert!((actual.im - expected.im).abs() < 1e-3);
+	}
+
 	#[test]
 	fn test_rotate() {
 		if let Some(simd) = Neon::try_new() {
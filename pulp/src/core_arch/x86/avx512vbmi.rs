@@ -0,0 +1,12 @@
+use super::*;
+
+impl Avx512vbmi {
+	delegate!({
+		fn _mm512_permutexvar_epi8(idx: __m512i, a: __m512i) -> __m512i;
+		fn _mm512_maskz_permutexvar_epi8(k: __mmask64, idx: __m512i, a: __m512i) -> __m512i;
+		fn _mm256_permutexvar_epi8(idx: __m256i, a: __m256i) -> __m256i;
+		fn _mm256_maskz_permutexvar_epi8(k: __mmask32, idx: __m256i, a: __m256i) -> __m256i;
+		fn _mm_permutexvar_epi8(idx: __m128i, a: __m128i) -> __m128i;
+		fn _mm_maskz_permutexvar_epi8(k: __mmask16, idx: __m128i, a: __m128i) -> __m128i;
+	});
+}
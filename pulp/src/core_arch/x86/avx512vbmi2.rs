@@ -0,0 +1,18 @@
+use super::*;
+
+impl Avx512vbmi2 {
+	delegate!({
+		fn _mm512_maskz_compress_epi8(k: __mmask64, a: __m512i) -> __m512i;
+		fn _mm512_maskz_compress_epi16(k: __mmask32, a: __m512i) -> __m512i;
+		fn _mm512_maskz_expand_epi8(k: __mmask64, a: __m512i) -> __m512i;
+		fn _mm512_maskz_expand_epi16(k: __mmask32, a: __m512i) -> __m512i;
+		fn _mm256_maskz_compress_epi8(k: __mmask32, a: __m256i) -> __m256i;
+		fn _mm256_maskz_compress_epi16(k: __mmask16, a: __m256i) -> __m256i;
+		fn _mm256_maskz_expand_epi8(k: __mmask32, a: __m256i) -> __m256i;
+		fn _mm256_maskz_expand_epi16(k: __mmask16, a: __m256i) -> __m256i;
+		fn _mm_maskz_compress_epi8(k: __mmask16, a: __m128i) -> __m128i;
+		fn _mm_maskz_compress_epi16(k: __mmask8, a: __m128i) -> __m128i;
+		fn _mm_maskz_expand_epi8(k: __mmask16, a: __m128i) -> __m128i;
+		fn _mm_maskz_expand_epi16(k: __mmask8, a: __m128i) -> __m128i;
+	});
+}
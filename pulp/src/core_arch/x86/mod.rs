@@ -369,6 +369,7 @@ pub use gate_v4::*;
 
 mod avx;
 mod avx2;
+mod f16c;
 mod fma;
 mod sse;
 mod sse2;
@@ -392,3 +393,9 @@ mod avx512f;
 #[cfg(feature = "x86-v4")]
 #[cfg_attr(docsrs, doc(cfg(feature = "x86-v4")))]
 mod avx512ifma;
+#[cfg(feature = "x86-v4")]
+#[cfg_attr(docsrs, doc(cfg(feature = "x86-v4")))]
+mod avx512vbmi;
+#[cfg(feature = "x86-v4")]
+#[cfg_attr(docsrs, doc(cfg(feature = "x86-v4")))]
+mod avx512vbmi2;
@@ -54,8 +54,14 @@
 //! 	assert_eq!(x, 3.0 * i as f64);
 //! }
 //! ```
-
-// FIXME: replace x86 non-ieee min/max functions to propagate nans instead
+//!
+//! # Overriding the detected instruction set
+//!
+//! With the `std` feature, [`Arch::new`] honours the `PULP_ARCH` environment variable, which names
+//! the highest [`ArchLevel`] that may be used (e.g. `PULP_ARCH=scalar`). This makes it possible to
+//! exercise the code paths of older cpus on newer ones. Names that are not recognized by
+//! [`ArchLevel::from_name`] are ignored, so the best available instruction set is used instead.
+//! [`Arch::with_max_level`] and [`Arch::from_name`] provide the same clamping programmatically.
 
 #![allow(
 	non_camel_case_types,
@@ -229,6 +235,98 @@ pub use num_complex;
 pub type c32 = Complex<f32>;
 pub type c64 = Complex<f64>;
 
+/// IEEE 754 half precision floating point number, stored as its bit pattern.
+#[derive(Copy, Clone, Default)]
+#[repr(transparent)]
+pub struct f16(pub u16);
+
+/// bfloat16 floating point number, i.e. the upper half of an [`f32`], stored as its bit pattern.
+#[derive(Copy, Clone, Default)]
+#[repr(transparent)]
+pub struct bf16(pub u16);
+
+impl f16 {
+	/// Creates a value from its bit pattern.
+	#[inline(always)]
+	pub const fn from_bits(bits: u16) -> Self {
+		Self(bits)
+	}
+
+	/// Returns the bit pattern of `self`.
+	#[inline(always)]
+	pub const fn to_bits(self) -> u16 {
+		self.0
+	}
+
+	/// Converts `value` to half precision, rounding to the nearest representable value with ties
+	/// to even.
+	#[inline]
+	pub fn from_f32(value: f32) -> Self {
+		Self(f32_to_f16_bits(Scalar, value.to_bits()) as u16)
+	}
+
+	/// Converts `self` to single precision. The conversion is exact.
+	#[inline]
+	pub fn to_f32(self) -> f32 {
+		f32::from_bits(f16_to_f32_bits(Scalar, self.0 as u32))
+	}
+}
+
+impl bf16 {
+	/// Creates a value from its bit pattern.
+	#[inline(always)]
+	pub const fn from_bits(bits: u16) -> Self {
+		Self(bits)
+	}
+
+	/// Returns the bit pattern of `self`.
+	#[inline(always)]
+	pub const fn to_bits(self) -> u16 {
+		self.0
+	}
+
+	/// Converts `value` to bfloat16, rounding to the nearest representable value with ties to
+	/// even.
+	#[inline]
+	pub fn from_f32(value: f32) -> Self {
+		Self(f32_to_bf16_bits(Scalar, value.to_bits()) as u16)
+	}
+
+	/// Converts `self` to single precision. The conversion is exact.
+	#[inline]
+	pub fn to_f32(self) -> f32 {
+		f32::from_bits((self.0 as u32) << 16)
+	}
+}
+
+impl Debug for f16 {
+	#[inline]
+	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+		self.to_f32().fmt(f)
+	}
+}
+
+impl Debug for bf16 {
+	#[inline]
+	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+		self.to_f32().fmt(f)
+	}
+}
+
+impl PartialEq for f16 {
+	#[inline]
+	fn eq(&self, other: &Self) -> bool {
+		self.to_f32() == other.to_f32()
+	}
+}
+
+impl PartialEq for bf16 {
+	#[inline]
+	fn eq(&self, other: &Self) -> bool {
+		self.to_f32() == other.to_f32()
+	}
+}
+
 #[derive(Copy, Clone)]
 #[repr(transparent)]
 struct DebugCplx<T>(T);
@@ -334,6 +432,80 @@ impl<T: Copy> MemMask<T> {
 	}
 }
 
+/// Precomputed magic numbers for dividing integers by a runtime-invariant divisor, see
+/// [`Simd::div_u32s`] and the related functions.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Divisor<T> {
+	divisor: T,
+	magic: T,
+	shift: u32,
+}
+
+// the magic numbers are computed as described in "Division by Invariant Integers using
+// Multiplication" by Granlund and Montgomery
+macro_rules! impl_divisor {
+	($u: ident, $i: ident, $wide: ident) => {
+		impl Divisor<$u> {
+			/// Precomputes the magic numbers for dividing by `divisor`.
+			///
+			/// # Panics
+			/// Panics if `divisor` is zero.
+			#[inline]
+			pub const fn new(divisor: $u) -> Self {
+				assert!(divisor != 0, "attempt to divide by zero");
+				// `ceil(log2(divisor))`
+				let shift = $u::BITS - (divisor - 1).leading_zeros();
+				let magic = ((1 as $wide) << $u::BITS)
+					* (((1 as $wide) << shift) - divisor as $wide)
+					/ divisor as $wide
+					+ 1;
+				Self {
+					divisor,
+					magic: magic as $u,
+					shift,
+				}
+			}
+
+			/// Returns the divisor.
+			#[inline]
+			pub const fn divisor(&self) -> $u {
+				self.divisor
+			}
+		}
+
+		impl Divisor<$i> {
+			/// Precomputes the magic numbers for dividing by `divisor`.
+			///
+			/// # Panics
+			/// Panics if `divisor` is zero.
+			#[inline]
+			pub const fn new(divisor: $i) -> Self {
+				assert!(divisor != 0, "attempt to divide by zero");
+				let abs = divisor.unsigned_abs();
+				// `max(ceil(log2(abs)), 1)`
+				let shift = $u::BITS - (abs - 1).leading_zeros();
+				let shift = if shift == 0 { 1 } else { shift };
+				let magic = (((1 as $wide) << ($u::BITS + shift - 1)) / abs as $wide + 1)
+					.wrapping_sub((1 as $wide) << $u::BITS);
+				Self {
+					divisor,
+					magic: magic as $i,
+					shift: shift - 1,
+				}
+			}
+
+			/// Returns the divisor.
+			#[inline]
+			pub const fn divisor(&self) -> $i {
+				self.divisor
+			}
+		}
+	};
+}
+
+impl_divisor!(u32, i32, u64);
+impl_divisor!(u64, i64, u128);
+
 mod seal {
 	pub trait Seal {}
 }
@@ -401,6 +573,367 @@ fn sqrt_f64(a: f64) -> f64 {
 	})
 }
 
+#[inline(always)]
+fn floor_f32(a: f32) -> f32 {
+	match_cfg!(match cfg!() {
+		const { feature = "std" } => f32::floor(a),
+		_ => libm::floorf(a),
+	})
+}
+
+#[inline(always)]
+fn floor_f64(a: f64) -> f64 {
+	match_cfg!(match cfg!() {
+		const { feature = "std" } => f64::floor(a),
+		_ => libm::floor(a),
+	})
+}
+
+#[inline(always)]
+fn ceil_f32(a: f32) -> f32 {
+	match_cfg!(match cfg!() {
+		const { feature = "std" } => f32::ceil(a),
+		_ => libm::ceilf(a),
+	})
+}
+
+#[inline(always)]
+fn ceil_f64(a: f64) -> f64 {
+	match_cfg!(match cfg!() {
+		const { feature = "std" } => f64::ceil(a),
+		_ => libm::ceil(a),
+	})
+}
+
+#[inline(always)]
+fn trunc_f32(a: f32) -> f32 {
+	match_cfg!(match cfg!() {
+		const { feature = "std" } => f32::trunc(a),
+		_ => libm::truncf(a),
+	})
+}
+
+#[inline(always)]
+fn trunc_f64(a: f64) -> f64 {
+	match_cfg!(match cfg!() {
+		const { feature = "std" } => f64::trunc(a),
+		_ => libm::trunc(a),
+	})
+}
+
+#[inline(always)]
+fn round_ties_even_f32(a: f32) -> f32 {
+	match_cfg!(match cfg!() {
+		const { feature = "std" } => f32::round_ties_even(a),
+		_ => libm::rintf(a),
+	})
+}
+
+#[inline(always)]
+fn round_ties_even_f64(a: f64) -> f64 {
+	match_cfg!(match cfg!() {
+		const { feature = "std" } => f64::round_ties_even(a),
+		_ => libm::rint(a),
+	})
+}
+
+#[inline(always)]
+fn average_u8(a: u8, b: u8) -> u8 {
+	((a as u16 + b as u16 + 1) >> 1) as u8
+}
+
+#[inline(always)]
+fn average_u16(a: u16, b: u16) -> u16 {
+	((a as u32 + b as u32 + 1) >> 1) as u16
+}
+
+#[inline(always)]
+fn mul_high_u16(a: u16, b: u16) -> u16 {
+	((a as u32 * b as u32) >> 16) as u16
+}
+
+#[inline(always)]
+fn mul_high_i16(a: i16, b: i16) -> i16 {
+	((a as i32 * b as i32) >> 16) as i16
+}
+
+// the only product that can overflow is `MIN * MIN`, which saturates to `MAX`
+
+#[inline(always)]
+fn mul_round_q15(a: i16, b: i16) -> i16 {
+	Ord::min((a as i32 * b as i32 + (1 << 14)) >> 15, i16::MAX as i32) as i16
+}
+
+#[inline(always)]
+fn mul_round_q31(a: i32, b: i32) -> i32 {
+	Ord::min((a as i64 * b as i64 + (1 << 30)) >> 31, i32::MAX as i64) as i32
+}
+
+/// Computes the reciprocal of each lane of `a` with `recip`, which only needs to handle inputs
+/// whose reciprocal is a normal number. The hardware estimates treat subnormal inputs as zero and
+/// flush subnormal results to zero, so the other inputs are scaled by a power of two first, and the
+/// result is scaled back.
+#[cfg(any(target_arch = "x86", target_arch = "x86_64", target_arch = "aarch64"))]
+#[inline(always)]
+fn recip_in_range_f32s<S: Simd>(
+	simd: S,
+	a: S::f32s,
+	recip: impl FnOnce(S::f32s) -> S::f32s,
+) -> S::f32s {
+	let abs = simd.abs_f32s(a);
+	let small = simd.less_than_f32s(abs, simd.splat_f32s(f32::from_bits(0x01000000)));
+	let large = simd.greater_than_f32s(abs, simd.splat_f32s(f32::from_bits(0x7e000000)));
+	let scale = simd.select_f32s(
+		small,
+		simd.splat_f32s(f32::from_bits(0x4b800000)),
+		simd.select_f32s(
+			large,
+			simd.splat_f32s(f32::from_bits(0x33800000)),
+			simd.splat_f32s(1.0),
+		),
+	);
+	simd.mul_f32s(recip(simd.mul_f32s(a, scale)), scale)
+}
+
+/// Computes the reciprocal square root of each lane of `a` with `rsqrt`, which only needs to handle
+/// normal inputs. Subnormal inputs are scaled by `2^24` first, and the result by `2^12`.
+#[cfg(any(target_arch = "x86", target_arch = "x86_64", target_arch = "aarch64"))]
+#[inline(always)]
+fn rsqrt_in_range_f32s<S: Simd>(
+	simd: S,
+	a: S::f32s,
+	rsqrt: impl FnOnce(S::f32s) -> S::f32s,
+) -> S::f32s {
+	let small = simd.less_than_f32s(a, simd.splat_f32s(f32::MIN_POSITIVE));
+	let one = simd.splat_f32s(1.0);
+	let a = simd.mul_f32s(
+		a,
+		simd.select_f32s(small, simd.splat_f32s(f32::from_bits(0x4b800000)), one),
+	);
+	simd.mul_f32s(
+		rsqrt(a),
+		simd.select_f32s(small, simd.splat_f32s(f32::from_bits(0x45800000)), one),
+	)
+}
+
+/// Converts the half precision values in the low bits of each lane of `h` to single precision.
+/// NaNs are quieted, which matches the hardware conversions.
+#[inline(always)]
+fn f16_to_f32_bits<S: Simd>(simd: S, h: S::u32s) -> S::u32s {
+	let sign = simd.shl_const_u32s::<16>(simd.and_u32s(h, simd.splat_u32s(0x8000)));
+	let h = simd.and_u32s(h, simd.splat_u32s(0x7fff));
+	let x = simd.shl_const_u32s::<13>(h);
+
+	// multiplying by 2^112 fixes the exponent bias, and normalizes subnormals
+	let finite = simd.transmute_u32s_f32s(simd.mul_f32s(
+		simd.transmute_f32s_u32s(x),
+		simd.splat_f32s(f32::from_bits(0x77800000)),
+	));
+	let inf = simd.splat_u32s(0x7f800000);
+	let nan = simd.or_u32s(x, simd.splat_u32s(0x7fc00000));
+
+	let x = simd.select_u32s(
+		simd.greater_than_or_equal_u32s(h, simd.splat_u32s(0x7c00)),
+		simd.select_u32s(simd.equal_u32s(h, simd.splat_u32s(0x7c00)), inf, nan),
+		finite,
+	);
+	simd.or_u32s(x, sign)
+}
+
+/// Converts the single precision values in `x` to half precision, with ties rounded to even. NaNs
+/// are quieted and their payload is truncated, which matches the hardware conversions.
+#[inline(always)]
+fn f32_to_f16_bits<S: Simd>(simd: S, x: S::u32s) -> S::u32s {
+	let sign = simd.and_u32s(x, simd.splat_u32s(0x80000000));
+	let x = simd.xor_u32s(x, sign);
+
+	let nan = simd.or_u32s(
+		simd.splat_u32s(0x7e00),
+		simd.and_u32s(simd.shr_const_u32s::<13>(x), simd.splat_u32s(0x3ff)),
+	);
+	let overflow = simd.select_u32s(
+		simd.greater_than_u32s(x, simd.splat_u32s(0x7f800000)),
+		nan,
+		simd.splat_u32s(0x7c00),
+	);
+
+	// adding 0.5 rounds the value to a multiple of 2^-24, which is then stored in the low bits
+	let half = simd.splat_f32s(0.5);
+	let subnormal = simd.sub_u32s(
+		simd.transmute_u32s_f32s(simd.add_f32s(simd.transmute_f32s_u32s(x), half)),
+		simd.transmute_u32s_f32s(half),
+	);
+
+	// rebias the exponent, then round the mantissa, letting the carry propagate to the exponent
+	let odd = simd.and_u32s(simd.shr_const_u32s::<13>(x), simd.splat_u32s(1));
+	let normal = simd.add_u32s(x, simd.splat_u32s(((15 - 127) << 23) as u32 + 0xfff));
+	let normal = simd.shr_const_u32s::<13>(simd.add_u32s(normal, odd));
+
+	let y = simd.select_u32s(
+		simd.less_than_u32s(x, simd.splat_u32s(113 << 23)),
+		subnormal,
+		normal,
+	);
+	let y = simd.select_u32s(
+		simd.greater_than_or_equal_u32s(x, simd.splat_u32s(143 << 23)),
+		overflow,
+		y,
+	);
+	simd.or_u32s(y, simd.shr_const_u32s::<16>(sign))
+}
+
+/// Converts the single precision values in `x` to bfloat16, with ties rounded to even. NaNs are
+/// quieted.
+#[inline(always)]
+fn f32_to_bf16_bits<S: Simd>(simd: S, x: S::u32s) -> S::u32s {
+	let odd = simd.and_u32s(simd.shr_const_u32s::<16>(x), simd.splat_u32s(1));
+	let rounded = simd.add_u32s(simd.add_u32s(x, simd.splat_u32s(0x7fff)), odd);
+	let rounded = simd.shr_const_u32s::<16>(rounded);
+	let nan = simd.or_u32s(simd.shr_const_u32s::<16>(x), simd.splat_u32s(0x40));
+
+	simd.select_u32s(
+		simd.greater_than_u32s(
+			simd.and_u32s(x, simd.splat_u32s(0x7fffffff)),
+			simd.splat_u32s(0x7f800000),
+		),
+		nan,
+		rounded,
+	)
+}
+
+/// Returns whether lane `i` of `mask` is enabled, where `mask` is a mask for a register of type
+/// `R` with lanes of type `T`, stored either as one full lane or as one bit per element.
+#[inline(always)]
+fn is_lane_enabled<T, R, M: NoUninit>(mask: &M, i: usize) -> bool {
+	let bytes = bytemuck::bytes_of(mask);
+	if core::mem::size_of::<M>() == core::mem::size_of::<R>() {
+		bytes[i * core::mem::size_of::<T>()] != 0
+	} else {
+		(bytes[i / 8] >> (i % 8)) & 1 != 0
+	}
+}
+
+/// Returns a mask for a register of type `R` with lanes of type `T`, whose lane `i` is enabled if
+/// bit `i` of `bits` is set.
+#[inline(always)]
+fn mask_from_bitmask<T, R, M: CheckedBitPattern + NoUninit>(bits: u64) -> M {
+	let mut bytes = [0u8; MAX_REGISTER_BYTES];
+	let lane = core::mem::size_of::<T>();
+	for i in 0..core::mem::size_of::<R>() / lane {
+		if (bits >> i) & 1 != 0 {
+			if core::mem::size_of::<M>() == core::mem::size_of::<R>() {
+				bytes[i * lane..(i + 1) * lane].fill(!0);
+			} else {
+				bytes[i / 8] |= 1 << (i % 8);
+			}
+		}
+	}
+	checked::pod_read_unaligned(&bytes[..core::mem::size_of::<M>()])
+}
+
+#[inline(always)]
+fn compress_store_fallback<T: Pod, R: Pod, M: NoUninit>(
+	mask: M,
+	values: R,
+	out: &mut [T],
+) -> usize {
+	let values = bytemuck::cast_slice::<R, T>(core::slice::from_ref(&values));
+	let count = (0..values.len())
+		.filter(|&i| is_lane_enabled::<T, R, M>(&mask, i))
+		.count();
+	assert!(count <= out.len());
+
+	let mut n = 0;
+	for (i, &x) in values.iter().enumerate() {
+		if is_lane_enabled::<T, R, M>(&mask, i) {
+			out[n] = x;
+			n += 1;
+		}
+	}
+	count
+}
+
+#[inline(always)]
+fn expand_load_fallback<T: Pod, R: Pod, M: NoUninit>(mask: M, values: &[T]) -> R {
+	let mut out = R::zeroed();
+	let lanes = bytemuck::cast_slice_mut::<R, T>(core::slice::from_mut(&mut out));
+	let count = (0..lanes.len())
+		.filter(|&i| is_lane_enabled::<T, R, M>(&mask, i))
+		.count();
+	assert!(count <= values.len());
+
+	let mut n = 0;
+	for (i, x) in lanes.iter_mut().enumerate() {
+		if is_lane_enabled::<T, R, M>(&mask, i) {
+			*x = values[n];
+			n += 1;
+		}
+	}
+	out
+}
+
+#[inline(always)]
+fn select_fallback<T: Pod, R: Pod, M: NoUninit>(mask: M, if_true: R, if_false: R) -> R {
+	let mut out = if_false;
+	let if_true = bytemuck::cast_slice::<R, T>(core::slice::from_ref(&if_true));
+	let lanes = bytemuck::cast_slice_mut::<R, T>(core::slice::from_mut(&mut out));
+	for (i, x) in lanes.iter_mut().enumerate() {
+		if is_lane_enabled::<T, R, M>(&mask, i) {
+			*x = if_true[i];
+		}
+	}
+	out
+}
+
+/// Returns, for each mask of the `N / LANE` lanes of a register made of `N` units, the indices
+/// that move the enabled lanes to the start of the register. Unused indices are set to `0x80`.
+const fn compress_idx<const N: usize, const LANE: usize, const MASKS: usize>() -> [[u8; N]; MASKS] {
+	let mut table = [[0x80u8; N]; MASKS];
+	let mut mask = 0;
+	while mask < MASKS {
+		let mut n = 0;
+		let mut i = 0;
+		while i < N / LANE {
+			if (mask >> i) & 1 != 0 {
+				let mut j = 0;
+				while j < LANE {
+					table[mask][n * LANE + j] = (i * LANE + j) as u8;
+					j += 1;
+				}
+				n += 1;
+			}
+			i += 1;
+		}
+		mask += 1;
+	}
+	table
+}
+
+/// Returns, for each mask of the `N / LANE` lanes of a register made of `N` units, the indices
+/// that move the lanes at the start of the register to the enabled lanes. The indices of disabled
+/// lanes are set to `0x80`.
+const fn expand_idx<const N: usize, const LANE: usize, const MASKS: usize>() -> [[u8; N]; MASKS] {
+	let mut table = [[0x80u8; N]; MASKS];
+	let mut mask = 0;
+	while mask < MASKS {
+		let mut n = 0;
+		let mut i = 0;
+		while i < N / LANE {
+			if (mask >> i) & 1 != 0 {
+				let mut j = 0;
+				while j < LANE {
+					table[mask][i * LANE + j] = (n * LANE + j) as u8;
+					j += 1;
+				}
+				n += 1;
+			}
+			i += 1;
+		}
+		mask += 1;
+	}
+	table
+}
+
 // a0,0 ... a0,m-1
 // ...
 // an-1,0 ... an-1,m-1
@@ -537,6 +1070,724 @@ macro_rules! transmute_cmp {
 	};
 }
 
+macro_rules! define_shift {
+	($func: ident, $op: ident, $dir: literal, $ty: ident, $amount: ident) => {
+		paste! {
+			#[doc = concat!("Shifts the bits of each lane of `a` ", $dir, " by `AMOUNT`.")]
+			///
+			/// `AMOUNT` must be less than the bit width of the lane type.
+			#[inline(always)]
+			fn [<$func _const_ $ty s>]<const AMOUNT: u32>(self, a: Self::[<$ty s>]) -> Self::[<$ty s>] {
+				const { assert!(AMOUNT < $ty::BITS) };
+				self.[<wrapping_ $func _ $ty s>](a, AMOUNT)
+			}
+
+			#[doc = concat!("Shifts the bits of each lane of `a` ", $dir, " by `amount`, modulo the bit width of the lane type.")]
+			#[inline(always)]
+			fn [<wrapping_ $func _ $ty s>](self, a: Self::[<$ty s>], amount: u32) -> Self::[<$ty s>] {
+				self.[<wrapping_dyn_ $func _ $ty s>](a, self.[<splat_ $amount s>](amount as $amount))
+			}
+
+			#[doc = concat!("Shifts the bits of each lane of `a` ", $dir, " by the corresponding lane of `amount`, modulo the bit width of the lane type.")]
+			#[inline(always)]
+			fn [<wrapping_dyn_ $func _ $ty s>](self, a: Self::[<$ty s>], amount: Self::[<$amount s>]) -> Self::[<$ty s>] {
+				let mut a = a;
+				let amount = bytemuck::cast_slice::<Self::[<$amount s>], $amount>(core::slice::from_ref(&amount));
+				let lanes = bytemuck::cast_slice_mut::<Self::[<$ty s>], $ty>(core::slice::from_mut(&mut a));
+				for (x, &amount) in lanes.iter_mut().zip(amount) {
+					*x = x.[<wrapping_ $op>](amount as u32);
+				}
+				a
+			}
+		}
+	};
+	($func: ident, $op: ident, $dir: literal, $($ty: ident => $amount: ident),*) => {
+		$(define_shift!($func, $op, $dir, $ty, $amount);)*
+	};
+}
+
+macro_rules! transmute_shift {
+	($func: ident, $ty: ident, $to: ident) => {
+		paste! {
+			#[inline(always)]
+			fn [<$func _const_ $ty s>]<const AMOUNT: u32>(self, a: Self::[<$ty s>]) -> Self::[<$ty s>] {
+				self.[<transmute_ $ty s_ $to s>](self.[<$func _const_ $to s>]::<AMOUNT>(self.[<transmute_ $to s_ $ty s>](a)))
+			}
+
+			#[inline(always)]
+			fn [<wrapping_ $func _ $ty s>](self, a: Self::[<$ty s>], amount: u32) -> Self::[<$ty s>] {
+				self.[<transmute_ $ty s_ $to s>](self.[<wrapping_ $func _ $to s>](self.[<transmute_ $to s_ $ty s>](a), amount))
+			}
+
+			#[inline(always)]
+			fn [<wrapping_dyn_ $func _ $ty s>](self, a: Self::[<$ty s>], amount: Self::[<$to s>]) -> Self::[<$ty s>] {
+				self.[<transmute_ $ty s_ $to s>](self.[<wrapping_dyn_ $func _ $to s>](self.[<transmute_ $to s_ $ty s>](a), amount))
+			}
+		}
+	};
+	($func: ident, $($ty: ident => $to: ident),*) => {
+		$(transmute_shift!($func, $ty, $to);)*
+	};
+}
+
+macro_rules! define_div {
+	($u: ident, $i: ident) => {
+		paste! {
+			/// Divides each lane of `a` by `divisor`, rounding towards zero.
+			#[inline(always)]
+			fn [<div_ $u s>](self, a: Self::[<$u s>], divisor: &Divisor<$u>) -> Self::[<$u s>] {
+				let shift = divisor.shift;
+				let q = self.[<widening_mul_ $u s>](a, self.[<splat_ $u s>](divisor.magic)).1;
+				let t = self.[<wrapping_shr_ $u s>](self.[<sub_ $u s>](a, q), Ord::min(shift, 1));
+				self.[<wrapping_shr_ $u s>](self.[<add_ $u s>](q, t), shift.saturating_sub(1))
+			}
+
+			/// Computes the remainder of the division of each lane of `a` by `divisor`.
+			#[inline(always)]
+			fn [<rem_ $u s>](self, a: Self::[<$u s>], divisor: &Divisor<$u>) -> Self::[<$u s>] {
+				let q = self.[<div_ $u s>](a, divisor);
+				self.[<sub_ $u s>](a, self.[<mul_ $u s>](q, self.[<splat_ $u s>](divisor.divisor)))
+			}
+
+			#[doc = concat!("Divides each lane of `a` by `divisor`, rounding towards zero. The quotient wraps around on overflow, like [`", stringify!($i), "::wrapping_div`].")]
+			#[inline(always)]
+			fn [<div_ $i s>](self, a: Self::[<$i s>], divisor: &Divisor<$i>) -> Self::[<$i s>] {
+				let magic = divisor.magic;
+				let sign = self.[<sra_const_ $i s>]::<{ $i::BITS - 1 }>(a);
+
+				// the signed high half is derived from the unsigned one, by subtracting the
+				// other factor for each negative factor
+				let hi = self.[<transmute_ $i s_ $u s>](
+					self.[<widening_mul_ $u s>](
+						self.[<transmute_ $u s_ $i s>](a),
+						self.[<splat_ $u s>](magic as $u),
+					)
+					.1,
+				);
+				let hi = self.[<sub_ $i s>](hi, self.[<and_ $i s>](sign, self.[<splat_ $i s>](magic)));
+				let hi = if magic < 0 { self.[<sub_ $i s>](hi, a) } else { hi };
+
+				let q = self.[<wrapping_sra_ $i s>](self.[<add_ $i s>](a, hi), divisor.shift);
+				let q = self.[<sub_ $i s>](q, sign);
+				if divisor.divisor < 0 {
+					self.[<sub_ $i s>](self.[<splat_ $i s>](0), q)
+				} else {
+					q
+				}
+			}
+
+			#[doc = concat!("Computes the remainder of the division of each lane of `a` by `divisor`, with the sign of `a`. The remainder of `MIN / -1` is zero, like [`", stringify!($i), "::wrapping_rem`].")]
+			#[inline(always)]
+			fn [<rem_ $i s>](self, a: Self::[<$i s>], divisor: &Divisor<$i>) -> Self::[<$i s>] {
+				let q = self.[<div_ $i s>](a, divisor);
+				self.[<sub_ $i s>](a, self.[<mul_ $i s>](q, self.[<splat_ $i s>](divisor.divisor)))
+			}
+		}
+	};
+	($($u: ident => $i: ident),*) => {
+		$(define_div!($u, $i);)*
+	};
+}
+
+macro_rules! define_lane_unop {
+	($func: ident, $doc: literal, $ty: ident, $scalar: ident) => {
+		paste! {
+			#[doc = $doc]
+			#[inline(always)]
+			fn [<$func _ $ty s>](self, a: Self::[<$ty s>]) -> Self::[<$ty s>] {
+				let mut a = a;
+				for x in bytemuck::cast_slice_mut::<Self::[<$ty s>], $ty>(core::slice::from_mut(&mut a)) {
+					*x = $scalar(*x);
+				}
+				a
+			}
+		}
+	};
+	($func: ident, $doc: literal, $($ty: ident => $scalar: ident),*) => {
+		$(define_lane_unop!($func, $doc, $ty, $scalar);)*
+	};
+}
+
+macro_rules! define_lane_binop {
+	($func: ident, $doc: literal, $ty: ident, $scalar: path) => {
+		paste! {
+			#[doc = $doc]
+			#[inline(always)]
+			fn [<$func _ $ty s>](self, a: Self::[<$ty s>], b: Self::[<$ty s>]) -> Self::[<$ty s>] {
+				let mut a = a;
+				let b = bytemuck::cast_slice::<Self::[<$ty s>], $ty>(core::slice::from_ref(&b));
+				for (x, &y) in bytemuck::cast_slice_mut::<Self::[<$ty s>], $ty>(core::slice::from_mut(&mut a)).iter_mut().zip(b) {
+					*x = $scalar(*x, y);
+				}
+				a
+			}
+		}
+	};
+	($func: ident, $doc: literal, $($ty: ident => $scalar: path),*) => {
+		$(define_lane_binop!($func, $doc, $ty, $scalar);)*
+	};
+}
+
+macro_rules! define_bit_count {
+	($func: ident, $doc: literal, $($ty: ident),*) => {
+		paste! {$(
+			#[doc = $doc]
+			#[inline(always)]
+			fn [<$func _ $ty s>](self, a: Self::[<$ty s>]) -> Self::[<$ty s>] {
+				let mut a = a;
+				for x in bytemuck::cast_slice_mut::<Self::[<$ty s>], $ty>(core::slice::from_mut(&mut a)) {
+					*x = x.$func() as $ty;
+				}
+				a
+			}
+		)*}
+	};
+}
+
+macro_rules! define_int_reduce {
+	($func: ident, $op: ident, $doc: literal, $($ty: ident),*) => {
+		paste! {$(
+			#[doc = $doc]
+			#[inline(always)]
+			fn [<reduce_ $func _ $ty s>](self, a: Self::[<$ty s>]) -> $ty {
+				let lanes = bytemuck::cast_slice::<Self::[<$ty s>], $ty>(core::slice::from_ref(&a));
+				let mut acc = lanes[0];
+				for &x in &lanes[1..] {
+					acc = acc.$op(x);
+				}
+				acc
+			}
+		)*}
+	};
+}
+
+macro_rules! define_ieee_min_max {
+	($($ty: ident => $mask: ident),*) => {
+		paste! {$(
+			/// Computes the elementwise minimum of each lane of `a` and `b`, returning nan if either
+			/// lane is nan, and treating `-0.0` as less than `+0.0`.
+			///
+			/// Unlike the `min_*` functions, whose nan handling depends on the backend, the result is
+			/// the same on every backend.
+			#[inline(always)]
+			fn [<min_nan_propagating_ $ty s>](self, a: Self::[<$ty s>], b: Self::[<$ty s>]) -> Self::[<$ty s>] {
+				let ordered = self.[<and_ $mask s>](self.[<equal_ $ty s>](a, a), self.[<equal_ $ty s>](b, b));
+				let min = self.[<select_ $ty s>](self.[<less_than_ $ty s>](a, b), a, b);
+				// `-0.0` and `+0.0` compare equal, so merge their sign bits
+				let min = self.[<select_ $ty s>](self.[<equal_ $ty s>](a, b), self.[<or_ $ty s>](a, b), min);
+				self.[<select_ $ty s>](ordered, min, self.[<add_ $ty s>](a, b))
+			}
+
+			/// Computes the elementwise maximum of each lane of `a` and `b`, returning nan if either
+			/// lane is nan, and treating `+0.0` as greater than `-0.0`.
+			///
+			/// Unlike the `max_*` functions, whose nan handling depends on the backend, the result is
+			/// the same on every backend.
+			#[inline(always)]
+			fn [<max_nan_propagating_ $ty s>](self, a: Self::[<$ty s>], b: Self::[<$ty s>]) -> Self::[<$ty s>] {
+				let ordered = self.[<and_ $mask s>](self.[<equal_ $ty s>](a, a), self.[<equal_ $ty s>](b, b));
+				let max = self.[<select_ $ty s>](self.[<greater_than_ $ty s>](a, b), a, b);
+				let max = self.[<select_ $ty s>](self.[<equal_ $ty s>](a, b), self.[<and_ $ty s>](a, b), max);
+				self.[<select_ $ty s>](ordered, max, self.[<add_ $ty s>](a, b))
+			}
+
+			/// Computes the elementwise minimum of each lane of `a` and `b` following IEEE 754-2019
+			/// `minimumNumber`: if exactly one lane is nan, the other one is returned, and `-0.0` is
+			/// treated as less than `+0.0`.
+			#[inline(always)]
+			fn [<minimum_number_ $ty s>](self, a: Self::[<$ty s>], b: Self::[<$ty s>]) -> Self::[<$ty s>] {
+				// `less_than` is false if either lane is nan, which selects `b`
+				let min = self.[<select_ $ty s>](self.[<less_than_ $ty s>](a, b), a, b);
+				let min = self.[<select_ $ty s>](self.[<equal_ $ty s>](a, b), self.[<or_ $ty s>](a, b), min);
+				self.[<select_ $ty s>](self.[<equal_ $ty s>](b, b), min, a)
+			}
+
+			/// Computes the elementwise maximum of each lane of `a` and `b` following IEEE 754-2019
+			/// `maximumNumber`: if exactly one lane is nan, the other one is returned, and `+0.0` is
+			/// treated as greater than `-0.0`.
+			#[inline(always)]
+			fn [<maximum_number_ $ty s>](self, a: Self::[<$ty s>], b: Self::[<$ty s>]) -> Self::[<$ty s>] {
+				let max = self.[<select_ $ty s>](self.[<greater_than_ $ty s>](a, b), a, b);
+				let max = self.[<select_ $ty s>](self.[<equal_ $ty s>](a, b), self.[<and_ $ty s>](a, b), max);
+				self.[<select_ $ty s>](self.[<equal_ $ty s>](b, b), max, a)
+			}
+
+			/// Returns the minimum of the lanes of `a`, or nan if any lane is nan.
+			#[inline(always)]
+			fn [<reduce_min_nan_propagating_ $ty s>](self, a: Self::[<$ty s>]) -> $ty {
+				let lanes = bytemuck::cast_slice::<Self::[<$ty s>], $ty>(core::slice::from_ref(&a));
+				lanes[1..].iter().fold(lanes[0], |acc, &x| Scalar.[<min_nan_propagating_ $ty s>](acc, x))
+			}
+
+			/// Returns the maximum of the lanes of `a`, or nan if any lane is nan.
+			#[inline(always)]
+			fn [<reduce_max_nan_propagating_ $ty s>](self, a: Self::[<$ty s>]) -> $ty {
+				let lanes = bytemuck::cast_slice::<Self::[<$ty s>], $ty>(core::slice::from_ref(&a));
+				lanes[1..].iter().fold(lanes[0], |acc, &x| Scalar.[<max_nan_propagating_ $ty s>](acc, x))
+			}
+
+			/// Returns the minimum of the lanes of `a`, ignoring nan lanes. Returns nan only if every
+			/// lane is nan.
+			#[inline(always)]
+			fn [<reduce_minimum_number_ $ty s>](self, a: Self::[<$ty s>]) -> $ty {
+				let lanes = bytemuck::cast_slice::<Self::[<$ty s>], $ty>(core::slice::from_ref(&a));
+				lanes[1..].iter().fold(lanes[0], |acc, &x| Scalar.[<minimum_number_ $ty s>](acc, x))
+			}
+
+			/// Returns the maximum of the lanes of `a`, ignoring nan lanes. Returns nan only if every
+			/// lane is nan.
+			#[inline(always)]
+			fn [<reduce_maximum_number_ $ty s>](self, a: Self::[<$ty s>]) -> $ty {
+				let lanes = bytemuck::cast_slice::<Self::[<$ty s>], $ty>(core::slice::from_ref(&a));
+				lanes[1..].iter().fold(lanes[0], |acc, &x| Scalar.[<maximum_number_ $ty s>](acc, x))
+			}
+		)*}
+	};
+}
+
+// these work on the bit representation of the lanes, so they give the same answer on every
+// backend regardless of how the floating point comparisons treat subnormals
+macro_rules! define_float_class {
+	($($ty: ident => $uint: ident => $mask: ident),*) => {
+		paste! {$(
+			/// Returns a mask of the lanes of `a` that are nan.
+			#[inline(always)]
+			fn [<is_nan_ $ty s>](self, a: Self::[<$ty s>]) -> Self::[<$mask s>] {
+				let abs = self.[<and_ $uint s>](self.[<transmute_ $uint s_ $ty s>](a), self.[<splat_ $uint s>]($uint::MAX >> 1));
+				self.[<greater_than_ $uint s>](abs, self.[<splat_ $uint s>]($ty::INFINITY.to_bits()))
+			}
+
+			/// Returns a mask of the lanes of `a` that are not nan.
+			#[inline(always)]
+			fn [<is_not_nan_ $ty s>](self, a: Self::[<$ty s>]) -> Self::[<$mask s>] {
+				let abs = self.[<and_ $uint s>](self.[<transmute_ $uint s_ $ty s>](a), self.[<splat_ $uint s>]($uint::MAX >> 1));
+				self.[<less_than_or_equal_ $uint s>](abs, self.[<splat_ $uint s>]($ty::INFINITY.to_bits()))
+			}
+
+			/// Returns a mask of the lanes of `a` that are neither infinite nor nan.
+			#[inline(always)]
+			fn [<is_finite_ $ty s>](self, a: Self::[<$ty s>]) -> Self::[<$mask s>] {
+				let abs = self.[<and_ $uint s>](self.[<transmute_ $uint s_ $ty s>](a), self.[<splat_ $uint s>]($uint::MAX >> 1));
+				self.[<less_than_ $uint s>](abs, self.[<splat_ $uint s>]($ty::INFINITY.to_bits()))
+			}
+
+			/// Returns a mask of the lanes of `a` that are positive or negative infinity.
+			#[inline(always)]
+			fn [<is_infinite_ $ty s>](self, a: Self::[<$ty s>]) -> Self::[<$mask s>] {
+				let abs = self.[<and_ $uint s>](self.[<transmute_ $uint s_ $ty s>](a), self.[<splat_ $uint s>]($uint::MAX >> 1));
+				self.[<equal_ $uint s>](abs, self.[<splat_ $uint s>]($ty::INFINITY.to_bits()))
+			}
+
+			/// Returns a mask of the lanes of `a` that are subnormal.
+			#[inline(always)]
+			fn [<is_subnormal_ $ty s>](self, a: Self::[<$ty s>]) -> Self::[<$mask s>] {
+				let abs = self.[<and_ $uint s>](self.[<transmute_ $uint s_ $ty s>](a), self.[<splat_ $uint s>]($uint::MAX >> 1));
+				// zero wraps around to the largest value, so only nonzero values below the smallest
+				// normal number pass the comparison
+				self.[<less_than_ $uint s>](
+					self.[<sub_ $uint s>](abs, self.[<splat_ $uint s>](1)),
+					self.[<splat_ $uint s>]($ty::MIN_POSITIVE.to_bits() - 1),
+				)
+			}
+
+			/// Returns a mask of the lanes of `a` whose sign bit is set, including `-0.0` and nans
+			/// with a negative sign.
+			#[inline(always)]
+			fn [<sign_bit_ $ty s>](self, a: Self::[<$ty s>]) -> Self::[<$mask s>] {
+				self.[<greater_than_ $uint s>](self.[<transmute_ $uint s_ $ty s>](a), self.[<splat_ $uint s>]($uint::MAX >> 1))
+			}
+
+			/// Returns the magnitude of each lane of `magnitude` with the sign of the corresponding
+			/// lane of `sign`.
+			#[inline(always)]
+			fn [<copysign_ $ty s>](self, magnitude: Self::[<$ty s>], sign: Self::[<$ty s>]) -> Self::[<$ty s>] {
+				self.[<transmute_ $ty s_ $uint s>](self.[<or_ $uint s>](
+					self.[<and_ $uint s>](self.[<transmute_ $uint s_ $ty s>](magnitude), self.[<splat_ $uint s>]($uint::MAX >> 1)),
+					self.[<and_ $uint s>](self.[<transmute_ $uint s_ $ty s>](sign), self.[<splat_ $uint s>](!($uint::MAX >> 1))),
+				))
+			}
+
+			/// Returns `1.0` with the sign of each lane of `a`, or nan if the lane is nan.
+			#[inline(always)]
+			fn [<signum_ $ty s>](self, a: Self::[<$ty s>]) -> Self::[<$ty s>] {
+				let one = self.[<copysign_ $ty s>](self.[<splat_ $ty s>](1.0), a);
+				self.[<select_ $ty s>](self.[<is_nan_ $ty s>](a), a, one)
+			}
+		)*}
+	};
+}
+
+macro_rules! define_convert {
+	($doc: literal, $from: ident, $to: ident) => {
+		paste! {
+			#[doc = $doc]
+			#[inline(always)]
+			fn [<convert_ $from s_ $to s>](self, a: Self::[<$from s>]) -> Self::[<$to s>] {
+				let mut b = self.[<splat_ $to s>](0 as $to);
+				let a = bytemuck::cast_slice::<Self::[<$from s>], $from>(core::slice::from_ref(&a));
+				let lanes = bytemuck::cast_slice_mut::<Self::[<$to s>], $to>(core::slice::from_mut(&mut b));
+				for (y, &x) in lanes.iter_mut().zip(a) {
+					*y = x as $to;
+				}
+				b
+			}
+		}
+	};
+	($doc: literal, $($from: ident => $to: ident),*) => {
+		$(define_convert!($doc, $from, $to);)*
+	};
+}
+
+macro_rules! define_widen {
+	($from: ident, $to: ident) => {
+		paste! {
+			#[doc = concat!("Converts the lanes in the lower half of `a` to `", stringify!($to), "`.")]
+			#[inline(always)]
+			fn [<widen_low_ $from s>](self, a: Self::[<$from s>]) -> Self::[<$to s>] {
+				let mut b = self.[<splat_ $to s>](0 as $to);
+				let a = bytemuck::cast_slice::<Self::[<$from s>], $from>(core::slice::from_ref(&a));
+				let lanes = bytemuck::cast_slice_mut::<Self::[<$to s>], $to>(core::slice::from_mut(&mut b));
+				for (y, &x) in lanes.iter_mut().zip(a) {
+					*y = x as $to;
+				}
+				b
+			}
+
+			#[doc = concat!("Converts the lanes in the upper half of `a` to `", stringify!($to), "`.")]
+			#[inline(always)]
+			fn [<widen_high_ $from s>](self, a: Self::[<$from s>]) -> Self::[<$to s>] {
+				let mut b = self.[<splat_ $to s>](0 as $to);
+				let a = bytemuck::cast_slice::<Self::[<$from s>], $from>(core::slice::from_ref(&a));
+				let lanes = bytemuck::cast_slice_mut::<Self::[<$to s>], $to>(core::slice::from_mut(&mut b));
+				let a = &a[lanes.len()..];
+				for (y, &x) in lanes.iter_mut().zip(a) {
+					*y = x as $to;
+				}
+				b
+			}
+		}
+	};
+	($($from: ident => $to: ident),*) => {
+		$(define_widen!($from, $to);)*
+	};
+}
+
+macro_rules! define_narrow {
+	(@convert as, $x: expr, $from: ident, $to: ident) => {
+		$x as $to
+	};
+	(@convert clamp, $x: expr, $from: ident, $to: ident) => {
+		$x.clamp($to::MIN as $from, $to::MAX as $from) as $to
+	};
+	($func: ident, $mode: tt, $doc: literal, $from: ident, $to: ident) => {
+		paste! {
+			#[doc = concat!("Converts the lanes of `a` followed by the lanes of `b` to `", stringify!($to), "`, ", $doc, ".")]
+			#[inline(always)]
+			fn [<$func _ $from s>](self, a: Self::[<$from s>], b: Self::[<$from s>]) -> Self::[<$to s>] {
+				let mut c = self.[<splat_ $to s>](0 as $to);
+				let a = bytemuck::cast_slice::<Self::[<$from s>], $from>(core::slice::from_ref(&a));
+				let b = bytemuck::cast_slice::<Self::[<$from s>], $from>(core::slice::from_ref(&b));
+				let lanes = bytemuck::cast_slice_mut::<Self::[<$to s>], $to>(core::slice::from_mut(&mut c));
+				for (y, &x) in lanes.iter_mut().zip(a.iter().chain(b)) {
+					*y = define_narrow!(@convert $mode, x, $from, $to);
+				}
+				c
+			}
+		}
+	};
+	($func: ident, $mode: tt, $doc: literal, $($from: ident => $to: ident),*) => {
+		$(define_narrow!($func, $mode, $doc, $from, $to);)*
+	};
+}
+
+macro_rules! define_gather_scatter {
+	(@safe $bits: literal, $ty: ident) => {
+		paste! {
+			/// Returns a vector whose lane `i` is `slice[idx[i]]`.
+			///
+			/// # Panics
+			///
+			/// Panics if any index is out of bounds.
+			#[inline(always)]
+			fn [<gather_ $ty s>](self, slice: &[$ty], idx: Self::[<u $bits s>]) -> Self::[<$ty s>] {
+				let len = slice.len() as u64;
+				let lanes = bytemuck::cast_slice::<Self::[<u $bits s>], [<u $bits>]>(core::slice::from_ref(&idx));
+				assert!(lanes.iter().all(|&i| (i as u64) < len));
+				unsafe { self.[<gather_ptr_ $ty s>](slice.as_ptr(), idx) }
+			}
+			/// Returns a vector whose lane `i` is `slice[idx[i]]` if the lane is enabled in the mask, and
+			/// zero otherwise.
+			///
+			/// # Panics
+			///
+			/// Panics if the index of any enabled lane is out of bounds.
+			#[inline(always)]
+			fn [<mask_gather_ $ty s>](
+				self,
+				mask: MemMask<Self::[<m $bits s>]>,
+				slice: &[$ty],
+				idx: Self::[<u $bits s>],
+			) -> Self::[<$ty s>] {
+				let len = slice.len() as u64;
+				let enabled = self.[<select_u $bits s>](mask.mask(), self.[<splat_u $bits s>](!0), self.[<splat_u $bits s>](0));
+				let enabled = bytemuck::cast_slice::<Self::[<u $bits s>], [<u $bits>]>(core::slice::from_ref(&enabled));
+				let lanes = bytemuck::cast_slice::<Self::[<u $bits s>], [<u $bits>]>(core::slice::from_ref(&idx));
+				assert!(enabled.iter().zip(lanes).all(|(&m, &i)| m == 0 || (i as u64) < len));
+				unsafe { self.[<mask_gather_ptr_ $ty s>](mask, slice.as_ptr(), idx) }
+			}
+			/// Writes lane `i` of `values` to `slice[idx[i]]`. Lanes are written in increasing order,
+			/// so if several lanes share an index, the last one is stored.
+			///
+			/// # Panics
+			///
+			/// Panics if any index is out of bounds.
+			#[inline(always)]
+			fn [<scatter_ $ty s>](self, slice: &mut [$ty], idx: Self::[<u $bits s>], values: Self::[<$ty s>]) {
+				let len = slice.len() as u64;
+				let lanes = bytemuck::cast_slice::<Self::[<u $bits s>], [<u $bits>]>(core::slice::from_ref(&idx));
+				assert!(lanes.iter().all(|&i| (i as u64) < len));
+				unsafe { self.[<scatter_ptr_ $ty s>](slice.as_mut_ptr(), idx, values) }
+			}
+			/// Writes lane `i` of `values` to `slice[idx[i]]` for each lane enabled in the mask. Lanes
+			/// are written in increasing order, so if several lanes share an index, the last one is
+			/// stored.
+			///
+			/// # Panics
+			///
+			/// Panics if the index of any enabled lane is out of bounds.
+			#[inline(always)]
+			fn [<mask_scatter_ $ty s>](
+				self,
+				mask: MemMask<Self::[<m $bits s>]>,
+				slice: &mut [$ty],
+				idx: Self::[<u $bits s>],
+				values: Self::[<$ty s>],
+			) {
+				let len = slice.len() as u64;
+				let enabled = self.[<select_u $bits s>](mask.mask(), self.[<splat_u $bits s>](!0), self.[<splat_u $bits s>](0));
+				let enabled = bytemuck::cast_slice::<Self::[<u $bits s>], [<u $bits>]>(core::slice::from_ref(&enabled));
+				let lanes = bytemuck::cast_slice::<Self::[<u $bits s>], [<u $bits>]>(core::slice::from_ref(&idx));
+				assert!(enabled.iter().zip(lanes).all(|(&m, &i)| m == 0 || (i as u64) < len));
+				unsafe { self.[<mask_scatter_ptr_ $ty s>](mask, slice.as_mut_ptr(), idx, values) }
+			}
+		}
+	};
+	($bits: literal, $ty: ident) => {
+		paste! {
+			/// # Safety
+			///
+			/// The addresses `ptr.add(idx[i])` have the same restrictions as [`core::ptr::read`].
+			#[inline(always)]
+			unsafe fn [<gather_ptr_ $ty s>](self, ptr: *const $ty, idx: Self::[<u $bits s>]) -> Self::[<$ty s>] {
+				let mut x = self.[<splat_ $ty s>](0 as $ty);
+				let idx = bytemuck::cast_slice::<Self::[<u $bits s>], [<u $bits>]>(core::slice::from_ref(&idx));
+				let lanes = bytemuck::cast_slice_mut::<Self::[<$ty s>], $ty>(core::slice::from_mut(&mut x));
+				for (x, &i) in lanes.iter_mut().zip(idx) {
+					*x = ptr.add(i as usize).read();
+				}
+				x
+			}
+			/// # Safety
+			///
+			/// The addresses `ptr.add(idx[i])` corresponding to enabled lanes in the mask have the
+			/// same restrictions as [`core::ptr::read`].
+			#[inline(always)]
+			unsafe fn [<mask_gather_ptr_ $ty s>](
+				self,
+				mask: MemMask<Self::[<m $bits s>]>,
+				ptr: *const $ty,
+				idx: Self::[<u $bits s>],
+			) -> Self::[<$ty s>] {
+				let mut x = self.[<splat_ $ty s>](0 as $ty);
+				let enabled = self.[<select_u $bits s>](mask.mask(), self.[<splat_u $bits s>](!0), self.[<splat_u $bits s>](0));
+				let enabled = bytemuck::cast_slice::<Self::[<u $bits s>], [<u $bits>]>(core::slice::from_ref(&enabled));
+				let idx = bytemuck::cast_slice::<Self::[<u $bits s>], [<u $bits>]>(core::slice::from_ref(&idx));
+				let lanes = bytemuck::cast_slice_mut::<Self::[<$ty s>], $ty>(core::slice::from_mut(&mut x));
+				for ((x, &m), &i) in lanes.iter_mut().zip(enabled).zip(idx) {
+					if m != 0 {
+						*x = ptr.add(i as usize).read();
+					}
+				}
+				x
+			}
+			/// # Safety
+			///
+			/// The addresses `ptr.add(idx[i])` have the same restrictions as [`core::ptr::write`].
+			#[inline(always)]
+			unsafe fn [<scatter_ptr_ $ty s>](self, ptr: *mut $ty, idx: Self::[<u $bits s>], values: Self::[<$ty s>]) {
+				let idx = bytemuck::cast_slice::<Self::[<u $bits s>], [<u $bits>]>(core::slice::from_ref(&idx));
+				let values = bytemuck::cast_slice::<Self::[<$ty s>], $ty>(core::slice::from_ref(&values));
+				for (&x, &i) in values.iter().zip(idx) {
+					ptr.add(i as usize).write(x);
+				}
+			}
+			/// # Safety
+			///
+			/// The addresses `ptr.add(idx[i])` corresponding to enabled lanes in the mask have the
+			/// same restrictions as [`core::ptr::write`].
+			#[inline(always)]
+			unsafe fn [<mask_scatter_ptr_ $ty s>](
+				self,
+				mask: MemMask<Self::[<m $bits s>]>,
+				ptr: *mut $ty,
+				idx: Self::[<u $bits s>],
+				values: Self::[<$ty s>],
+			) {
+				let enabled = self.[<select_u $bits s>](mask.mask(), self.[<splat_u $bits s>](!0), self.[<splat_u $bits s>](0));
+				let enabled = bytemuck::cast_slice::<Self::[<u $bits s>], [<u $bits>]>(core::slice::from_ref(&enabled));
+				let idx = bytemuck::cast_slice::<Self::[<u $bits s>], [<u $bits>]>(core::slice::from_ref(&idx));
+				let values = bytemuck::cast_slice::<Self::[<$ty s>], $ty>(core::slice::from_ref(&values));
+				for ((&x, &m), &i) in values.iter().zip(enabled).zip(idx) {
+					if m != 0 {
+						ptr.add(i as usize).write(x);
+					}
+				}
+			}
+		}
+		define_gather_scatter!(@safe $bits, $ty);
+	};
+	($bits: literal, $ty: ident => $base: ident) => {
+		paste! {
+			/// # Safety
+			///
+			/// The addresses `ptr.add(idx[i])` have the same restrictions as [`core::ptr::read`].
+			#[inline(always)]
+			unsafe fn [<gather_ptr_ $ty s>](self, ptr: *const $ty, idx: Self::[<u $bits s>]) -> Self::[<$ty s>] {
+				self.[<transmute_ $ty s_ $base s>](self.[<gather_ptr_ $base s>](ptr as *const $base, idx))
+			}
+			/// # Safety
+			///
+			/// The addresses `ptr.add(idx[i])` corresponding to enabled lanes in the mask have the
+			/// same restrictions as [`core::ptr::read`].
+			#[inline(always)]
+			unsafe fn [<mask_gather_ptr_ $ty s>](
+				self,
+				mask: MemMask<Self::[<m $bits s>]>,
+				ptr: *const $ty,
+				idx: Self::[<u $bits s>],
+			) -> Self::[<$ty s>] {
+				self.[<transmute_ $ty s_ $base s>](self.[<mask_gather_ptr_ $base s>](mask, ptr as *const $base, idx))
+			}
+			/// # Safety
+			///
+			/// The addresses `ptr.add(idx[i])` have the same restrictions as [`core::ptr::write`].
+			#[inline(always)]
+			unsafe fn [<scatter_ptr_ $ty s>](self, ptr: *mut $ty, idx: Self::[<u $bits s>], values: Self::[<$ty s>]) {
+				self.[<scatter_ptr_ $base s>](ptr as *mut $base, idx, self.[<transmute_ $base s_ $ty s>](values))
+			}
+			/// # Safety
+			///
+			/// The addresses `ptr.add(idx[i])` corresponding to enabled lanes in the mask have the
+			/// same restrictions as [`core::ptr::write`].
+			#[inline(always)]
+			unsafe fn [<mask_scatter_ptr_ $ty s>](
+				self,
+				mask: MemMask<Self::[<m $bits s>]>,
+				ptr: *mut $ty,
+				idx: Self::[<u $bits s>],
+				values: Self::[<$ty s>],
+			) {
+				self.[<mask_scatter_ptr_ $base s>](mask, ptr as *mut $base, idx, self.[<transmute_ $base s_ $ty s>](values))
+			}
+		}
+		define_gather_scatter!(@safe $bits, $ty);
+	};
+}
+
+macro_rules! define_mask_query {
+	($($bits: literal),*) => {
+		paste! {$(
+			/// Returns a bitmask whose bit `i` is set if lane `i` of `mask` is enabled.
+			#[inline(always)]
+			fn [<to_bitmask_m $bits s>](self, mask: Self::[<m $bits s>]) -> u64 {
+				let mut bits = 0u64;
+				for i in 0..Self::[<U $bits _LANES>] {
+					bits |= (is_lane_enabled::<[<u $bits>], Self::[<u $bits s>], _>(&mask, i) as u64) << i;
+				}
+				bits
+			}
+			/// Returns a mask whose lane `i` is enabled if bit `i` of `bits` is set. Bits past the
+			/// number of lanes are ignored.
+			#[allow(clippy::wrong_self_convention)]
+			#[inline(always)]
+			fn [<from_bitmask_m $bits s>](self, bits: u64) -> Self::[<m $bits s>] {
+				mask_from_bitmask::<[<u $bits>], Self::[<u $bits s>], _>(bits)
+			}
+			/// Returns `true` if any lane of `mask` is enabled.
+			#[inline(always)]
+			fn [<any_m $bits s>](self, mask: Self::[<m $bits s>]) -> bool {
+				self.[<to_bitmask_m $bits s>](mask) != 0
+			}
+			/// Returns `true` if every lane of `mask` is enabled.
+			#[inline(always)]
+			fn [<all_m $bits s>](self, mask: Self::[<m $bits s>]) -> bool {
+				self.[<to_bitmask_m $bits s>](mask) == u64::MAX >> (64 - Self::[<U $bits _LANES>])
+			}
+			/// Returns `true` if no lane of `mask` is enabled.
+			#[inline(always)]
+			fn [<none_m $bits s>](self, mask: Self::[<m $bits s>]) -> bool {
+				!self.[<any_m $bits s>](mask)
+			}
+			/// Returns the number of enabled lanes in `mask`.
+			#[inline(always)]
+			fn [<count_true_m $bits s>](self, mask: Self::[<m $bits s>]) -> usize {
+				self.[<to_bitmask_m $bits s>](mask).count_ones() as usize
+			}
+		)*}
+	};
+}
+
+macro_rules! define_compress_expand {
+	($bits: literal, $ty: ident) => {
+		paste! {
+			/// Writes the lanes of `values` that are enabled in the mask to the start of `out`, in
+			/// order, and returns how many were written.
+			///
+			/// # Panics
+			///
+			/// Panics if `out` is shorter than the number of enabled lanes.
+			#[inline(always)]
+			fn [<compress_store_ $ty s>](self, mask: Self::[<m $bits s>], values: Self::[<$ty s>], out: &mut [$ty]) -> usize {
+				compress_store_fallback(mask, values, out)
+			}
+			/// Returns a vector whose lanes enabled in the mask are filled, in order, with the
+			/// elements at the start of `values`, and whose other lanes are zero.
+			///
+			/// # Panics
+			///
+			/// Panics if `values` is shorter than the number of enabled lanes.
+			#[inline(always)]
+			fn [<expand_load_ $ty s>](self, mask: Self::[<m $bits s>], values: &[$ty]) -> Self::[<$ty s>] {
+				expand_load_fallback(mask, values)
+			}
+		}
+	};
+	($bits: literal, $ty: ident => $base: ident) => {
+		paste! {
+			/// Writes the lanes of `values` that are enabled in the mask to the start of `out`, in
+			/// order, and returns how many were written.
+			///
+			/// # Panics
+			///
+			/// Panics if `out` is shorter than the number of enabled lanes.
+			#[inline(always)]
+			fn [<compress_store_ $ty s>](self, mask: Self::[<m $bits s>], values: Self::[<$ty s>], out: &mut [$ty]) -> usize {
+				self.[<compress_store_ $base s>](mask, cast(values), bytemuck::cast_slice_mut(out))
+			}
+			/// Returns a vector whose lanes enabled in the mask are filled, in order, with the
+			/// elements at the start of `values`, and whose other lanes are zero.
+			///
+			/// # Panics
+			///
+			/// Panics if `values` is shorter than the number of enabled lanes.
+			#[inline(always)]
+			fn [<expand_load_ $ty s>](self, mask: Self::[<m $bits s>], values: &[$ty]) -> Self::[<$ty s>] {
+				cast(self.[<expand_load_ $base s>](mask, bytemuck::cast_slice(values)))
+			}
+		}
+	};
+}
+
 macro_rules! define_splat {
 	($ty: ty) => {
 		paste! {
@@ -638,6 +1889,11 @@ pub trait Simd: Seal + Debug + Copy + Send + Sync + 'static {
 	type i64s: Debug + Copy + Send + Sync + Pod + 'static;
 	type u64s: Debug + Copy + Send + Sync + Pod + 'static;
 
+	/// Register type holding [`f16`] lanes.
+	type f16s: Debug + Copy + Send + Sync + Pod + 'static;
+	/// Register type holding [`bf16`] lanes.
+	type bf16s: Debug + Copy + Send + Sync + Pod + 'static;
+
 	/// Contains the square of the norm in both the real and imaginary components.
 	fn abs2_c32s(self, a: Self::c32s) -> Self::c32s;
 
@@ -676,22 +1932,280 @@ pub trait Simd: Seal + Debug + Copy + Send + Sync + 'static {
 	transmute_binop!(or, m8 => u8, i8 => u8, m16 => u16, i16 => u16, m32 => u32, i32 => u32, m64 => u64, i64 => u64, f32 => u32, f64 => u64);
 	transmute_binop!(xor, m8 => u8, i8 => u8, m16 => u16, i16 => u16, m32 => u32, i32 => u32, m64 => u64, i64 => u64, f32 => u32, f64 => u64);
 
-	transmute_binop!(add, i8 => u8, i16 => u16, i32 => u32, i64 => u64);
-	transmute_cmp!(equal, m8 => u8 => m8, i8 => u8 => m8, m16 => u16 => m16, i16 => u16 => m16, m32 => u32 => m32, i32 => u32 => m32, m64 => u64 => m64, i64 => u64 => m64);
+	transmute_binop!(add, i8 => u8, i16 => u16, i32 => u32, i64 => u64);
+	transmute_cmp!(equal, m8 => u8 => m8, i8 => u8 => m8, m16 => u16 => m16, i16 => u16 => m16, m32 => u32 => m32, i32 => u32 => m32, m64 => u64 => m64, i64 => u64 => m64);
+
+	define_binop_all!(min, f32, f64, u8, i8, u16, i16, u32, i32, u64, i64);
+	define_binop_all!(max, f32, f64, u8, i8, u16, i16, u32, i32, u64, i64);
+	define_ieee_min_max!(f32 => m32, f64 => m64);
+	define_float_class!(f32 => u32 => m32, f64 => u64 => m64);
+
+	define_lane_binop!(
+		saturating_add,
+		"Adds the elements of each lane of `a` and `b`, with saturation.",
+		u8 => u8::saturating_add,
+		i8 => i8::saturating_add,
+		u16 => u16::saturating_add,
+		i16 => i16::saturating_add
+	);
+	define_lane_binop!(
+		saturating_sub,
+		"Subtracts the elements of each lane of `a` and `b`, with saturation.",
+		u8 => u8::saturating_sub,
+		i8 => i8::saturating_sub,
+		u16 => u16::saturating_sub,
+		i16 => i16::saturating_sub
+	);
+	define_lane_binop!(
+		average,
+		"Computes the average of each lane of `a` and `b`, rounding halfway values up.",
+		u8 => average_u8,
+		u16 => average_u16
+	);
+	define_lane_binop!(
+		mul_high,
+		"Multiplies the elements of each lane of `a` and `b`, and returns the high half of the full-width product.",
+		u16 => mul_high_u16,
+		i16 => mul_high_i16
+	);
+	define_lane_binop!(
+		mul,
+		"Multiplies the elements of each lane of `a` and `b`, wrapping around on overflow.",
+		u8 => u8::wrapping_mul
+	);
+	transmute_binop!(mul, i8 => u8);
+	define_lane_binop!(
+		mul_round_q15,
+		"Multiplies the Q15 fixed-point elements of each lane of `a` and `b`, rounding to nearest with ties up, and saturating `-1.0 * -1.0` to the largest representable value.",
+		i16 => mul_round_q15
+	);
+	define_lane_binop!(
+		mul_round_q31,
+		"Multiplies the Q31 fixed-point elements of each lane of `a` and `b`, rounding to nearest with ties up, and saturating `-1.0 * -1.0` to the largest representable value.",
+		i32 => mul_round_q31
+	);
+
+	define_unop_all!(neg, c32, c64);
+	define_unop_all!(not, m8, u8, m16, u16, m32, u32, m64, u64);
+
+	transmute_unop!(not, i8 => u8, i16 => u16, i32 => u32, i64 => u64, f32 => u32, f64 => u64);
+
+	define_bit_count!(
+		count_ones,
+		"Returns the number of ones in the binary representation of each lane of `a`.",
+		u8,
+		u16,
+		u32,
+		u64
+	);
+	define_bit_count!(
+		leading_zeros,
+		"Returns the number of leading zeros in the binary representation of each lane of `a`.",
+		u8,
+		u16,
+		u32,
+		u64
+	);
+	define_bit_count!(
+		trailing_zeros,
+		"Returns the number of trailing zeros in the binary representation of each lane of `a`.",
+		u8,
+		u16,
+		u32,
+		u64
+	);
+	transmute_unop!(count_ones, i8 => u8, i16 => u16, i32 => u32, i64 => u64);
+	transmute_unop!(leading_zeros, i8 => u8, i16 => u16, i32 => u32, i64 => u64);
+	transmute_unop!(trailing_zeros, i8 => u8, i16 => u16, i32 => u32, i64 => u64);
+
+	split_slice!(
+		u8, i8, u16, i16, u32, i32, u64, i64, c32, f32, c64, f64, f16, bf16
+	);
+	define_splat!(u8, i8, u16, i16, u32, i32, u64, i64, c32, f32, c64, f64);
+
+	fn sqrt_f32s(self, a: Self::f32s) -> Self::f32s;
+	fn sqrt_f64s(self, a: Self::f64s) -> Self::f64s;
+
+	/// Computes an approximation of the reciprocal of each lane of `a`, with a relative error of
+	/// at most `2^-8`. Subnormal inputs may be treated as zero, and subnormal results may be
+	/// flushed to zero.
+	///
+	/// The precision depends on the backend and may be as low as 8 bits, see
+	/// [`Simd::recip_approx_refined_f32s`] for a version with consistent precision.
+	#[inline(always)]
+	fn recip_approx_f32s(self, a: Self::f32s) -> Self::f32s {
+		self.div_f32s(self.splat_f32s(1.0), a)
+	}
+
+	/// Computes an approximation of the reciprocal of the square root of each lane of `a`, with a
+	/// relative error of at most `2^-8`. Subnormal inputs may be treated as zero.
+	///
+	/// The precision depends on the backend and may be as low as 8 bits, see
+	/// [`Simd::rsqrt_approx_refined_f32s`] for a version with consistent precision.
+	#[inline(always)]
+	fn rsqrt_approx_f32s(self, a: Self::f32s) -> Self::f32s {
+		self.div_f32s(self.splat_f32s(1.0), self.sqrt_f32s(a))
+	}
+
+	/// Computes an approximation of the reciprocal of each lane of `a`, refined with
+	/// Newton-Raphson steps to a relative error of at most `2^-21`. Subnormal inputs and results
+	/// are supported, and subnormal results may additionally be off by the smallest subnormal.
+	#[inline(always)]
+	fn recip_approx_refined_f32s(self, a: Self::f32s) -> Self::f32s {
+		self.div_f32s(self.splat_f32s(1.0), a)
+	}
+
+	/// Computes an approximation of the reciprocal of the square root of each lane of `a`, refined
+	/// with Newton-Raphson steps to a relative error of at most `2^-21`, including for subnormal
+	/// inputs.
+	#[inline(always)]
+	fn rsqrt_approx_refined_f32s(self, a: Self::f32s) -> Self::f32s {
+		self.div_f32s(self.splat_f32s(1.0), self.sqrt_f32s(a))
+	}
+
+	define_lane_unop!(floor, "Rounds each lane of `a` to the nearest integer towards negative infinity.", f32 => floor_f32, f64 => floor_f64);
+	define_lane_unop!(ceil, "Rounds each lane of `a` to the nearest integer towards positive infinity.", f32 => ceil_f32, f64 => ceil_f64);
+	define_lane_unop!(trunc, "Rounds each lane of `a` to the nearest integer towards zero.", f32 => trunc_f32, f64 => trunc_f64);
+	define_lane_unop!(
+		round_ties_even,
+		"Rounds each lane of `a` to the nearest integer. If two values are equally close, the even value is returned.",
+		f32 => round_ties_even_f32,
+		f64 => round_ties_even_f64
+	);
+
+	/// Rounds each lane of `a` to the nearest integer. If two values are equally close, the one
+	/// further away from zero is returned.
+	#[inline]
+	fn round_ties_away_f32s(self, a: Self::f32s) -> Self::f32s {
+		let t = self.trunc_f32s(a);
+		let half = self
+			.greater_than_or_equal_f32s(self.abs_f32s(self.sub_f32s(a, t)), self.splat_f32s(0.5));
+		let step = self.select_f32s(half, self.splat_f32s(1.0), self.splat_f32s(0.0));
+		self.add_f32s(
+			t,
+			self.or_f32s(step, self.and_f32s(a, self.splat_f32s(-0.0))),
+		)
+	}
+	/// Rounds each lane of `a` to the nearest integer. If two values are equally close, the one
+	/// further away from zero is returned.
+	#[inline]
+	fn round_ties_away_f64s(self, a: Self::f64s) -> Self::f64s {
+		let t = self.trunc_f64s(a);
+		let half = self
+			.greater_than_or_equal_f64s(self.abs_f64s(self.sub_f64s(a, t)), self.splat_f64s(0.5));
+		let step = self.select_f64s(half, self.splat_f64s(1.0), self.splat_f64s(0.0));
+		self.add_f64s(
+			t,
+			self.or_f64s(step, self.and_f64s(a, self.splat_f64s(-0.0))),
+		)
+	}
+
+	define_convert!(
+		"Converts each lane of `a` to an integer, rounding towards zero. Out of range values are \
+		 saturated and NaN is converted to zero, matching the semantics of `as`.",
+		f32 => i32,
+		f32 => u32,
+		f64 => i64,
+		f64 => u64
+	);
+	define_convert!(
+		"Converts each lane of `a` to the nearest representable floating point value, matching the \
+		 semantics of `as`.",
+		i32 => f32,
+		u32 => f32,
+		i64 => f64,
+		u64 => f64
+	);
 
-	define_binop_all!(min, f32, f64, u8, i8, u16, i16, u32, i32, u64, i64);
-	define_binop_all!(max, f32, f64, u8, i8, u16, i16, u32, i32, u64, i64);
+	/// Converts each lane of `a` to the nearest integer, with ties rounded to even. Out of range
+	/// values are saturated and NaN is converted to zero.
+	#[inline]
+	fn convert_round_f32s_i32s(self, a: Self::f32s) -> Self::i32s {
+		self.convert_f32s_i32s(self.round_ties_even_f32s(a))
+	}
+	/// Converts each lane of `a` to the nearest integer, with ties rounded to even. Out of range
+	/// values are saturated and NaN is converted to zero.
+	#[inline]
+	fn convert_round_f32s_u32s(self, a: Self::f32s) -> Self::u32s {
+		self.convert_f32s_u32s(self.round_ties_even_f32s(a))
+	}
+	/// Converts each lane of `a` to the nearest integer, with ties rounded to even. Out of range
+	/// values are saturated and NaN is converted to zero.
+	#[inline]
+	fn convert_round_f64s_i64s(self, a: Self::f64s) -> Self::i64s {
+		self.convert_f64s_i64s(self.round_ties_even_f64s(a))
+	}
+	/// Converts each lane of `a` to the nearest integer, with ties rounded to even. Out of range
+	/// values are saturated and NaN is converted to zero.
+	#[inline]
+	fn convert_round_f64s_u64s(self, a: Self::f64s) -> Self::u64s {
+		self.convert_f64s_u64s(self.round_ties_even_f64s(a))
+	}
+
+	define_widen!(u8 => u16, i8 => i16, u16 => u32, i16 => i32, u32 => u64, i32 => i64, f32 => f64);
+	define_narrow!(narrow, as, "keeping the low bits of each lane", u16 => u8, i16 => i8, u32 => u16, i32 => i16, u64 => u32, i64 => i32);
+	define_narrow!(narrow, as, "rounding to the nearest representable value", f64 => f32);
+	define_narrow!(
+		narrow_saturating,
+		clamp,
+		"saturating values that are out of range",
+		u16 => u8,
+		i16 => i8,
+		u32 => u16,
+		i32 => i16,
+		u64 => u32,
+		i64 => i32
+	);
+	define_narrow!(
+		narrow_saturating_unsigned,
+		clamp,
+		"saturating values that are out of range",
+		i16 => u8,
+		i32 => u16,
+		i64 => u32
+	);
 
-	define_unop_all!(neg, c32, c64);
-	define_unop_all!(not, m8, u8, m16, u16, m32, u32, m64, u64);
+	/// Converts the lanes of `a` to single precision, returning the lanes in the lower half of `a`
+	/// followed by the lanes in the upper half. The conversion is exact.
+	#[inline]
+	fn convert_f16s_f32s(self, a: Self::f16s) -> (Self::f32s, Self::f32s) {
+		let a: Self::u16s = cast(a);
+		(
+			self.transmute_f32s_u32s(f16_to_f32_bits(self, self.widen_low_u16s(a))),
+			self.transmute_f32s_u32s(f16_to_f32_bits(self, self.widen_high_u16s(a))),
+		)
+	}
 
-	transmute_unop!(not, i8 => u8, i16 => u16, i32 => u32, i64 => u64, f32 => u32, f64 => u64);
+	/// Converts the lanes of `a` followed by the lanes of `b` to half precision, rounding to the
+	/// nearest representable value with ties to even.
+	#[inline]
+	fn convert_f32s_f16s(self, a: Self::f32s, b: Self::f32s) -> Self::f16s {
+		cast(self.narrow_u32s(
+			f32_to_f16_bits(self, self.transmute_u32s_f32s(a)),
+			f32_to_f16_bits(self, self.transmute_u32s_f32s(b)),
+		))
+	}
 
-	split_slice!(u8, i8, u16, i16, u32, i32, u64, i64, c32, f32, c64, f64);
-	define_splat!(u8, i8, u16, i16, u32, i32, u64, i64, c32, f32, c64, f64);
+	/// Converts the lanes of `a` to single precision, returning the lanes in the lower half of `a`
+	/// followed by the lanes in the upper half. The conversion is exact.
+	#[inline]
+	fn convert_bf16s_f32s(self, a: Self::bf16s) -> (Self::f32s, Self::f32s) {
+		let a: Self::u16s = cast(a);
+		(
+			self.transmute_f32s_u32s(self.shl_const_u32s::<16>(self.widen_low_u16s(a))),
+			self.transmute_f32s_u32s(self.shl_const_u32s::<16>(self.widen_high_u16s(a))),
+		)
+	}
 
-	fn sqrt_f32s(self, a: Self::f32s) -> Self::f32s;
-	fn sqrt_f64s(self, a: Self::f64s) -> Self::f64s;
+	/// Converts the lanes of `a` followed by the lanes of `b` to bfloat16, rounding to the
+	/// nearest representable value with ties to even.
+	#[inline]
+	fn convert_f32s_bf16s(self, a: Self::f32s, b: Self::f32s) -> Self::bf16s {
+		cast(self.narrow_u32s(
+			f32_to_bf16_bits(self, self.transmute_u32s_f32s(a)),
+			f32_to_bf16_bits(self, self.transmute_u32s_f32s(b)),
+		))
+	}
 
 	fn conj_c32s(self, a: Self::c32s) -> Self::c32s;
 	fn conj_c64s(self, a: Self::c64s) -> Self::c64s;
@@ -746,10 +2260,10 @@ pub trait Simd: Seal + Debug + Copy + Send + Sync + 'static {
 			i
 		} else if const { core::mem::size_of::<Self::m8s>() == core::mem::size_of::<u8>() } {
 			let mask: u8 = bytemuck::cast(mask);
-			mask.leading_zeros() as usize
+			(mask.trailing_zeros() as usize).min(Self::U8_LANES)
 		} else if const { core::mem::size_of::<Self::m8s>() == core::mem::size_of::<u16>() } {
 			let mask: u16 = bytemuck::cast(mask);
-			mask.leading_zeros() as usize
+			(mask.trailing_zeros() as usize).min(Self::U8_LANES)
 		} else {
 			panic!()
 		}
@@ -770,10 +2284,10 @@ pub trait Simd: Seal + Debug + Copy + Send + Sync + 'static {
 			i
 		} else if const { core::mem::size_of::<Self::m16s>() == core::mem::size_of::<u8>() } {
 			let mask: u8 = bytemuck::cast(mask);
-			mask.leading_zeros() as usize
+			(mask.trailing_zeros() as usize).min(Self::U16_LANES)
 		} else if const { core::mem::size_of::<Self::m16s>() == core::mem::size_of::<u16>() } {
 			let mask: u16 = bytemuck::cast(mask);
-			mask.leading_zeros() as usize
+			(mask.trailing_zeros() as usize).min(Self::U16_LANES)
 		} else {
 			panic!()
 		}
@@ -794,10 +2308,10 @@ pub trait Simd: Seal + Debug + Copy + Send + Sync + 'static {
 			i
 		} else if const { core::mem::size_of::<Self::m32s>() == core::mem::size_of::<u8>() } {
 			let mask: u8 = bytemuck::cast(mask);
-			mask.leading_zeros() as usize
+			(mask.trailing_zeros() as usize).min(Self::U32_LANES)
 		} else if const { core::mem::size_of::<Self::m32s>() == core::mem::size_of::<u16>() } {
 			let mask: u16 = bytemuck::cast(mask);
-			mask.leading_zeros() as usize
+			(mask.trailing_zeros() as usize).min(Self::U32_LANES)
 		} else {
 			panic!()
 		}
@@ -818,15 +2332,17 @@ pub trait Simd: Seal + Debug + Copy + Send + Sync + 'static {
 			i
 		} else if const { core::mem::size_of::<Self::m64s>() == core::mem::size_of::<u8>() } {
 			let mask: u8 = bytemuck::cast(mask);
-			mask.leading_zeros() as usize
+			(mask.trailing_zeros() as usize).min(Self::U64_LANES)
 		} else if const { core::mem::size_of::<Self::m64s>() == core::mem::size_of::<u16>() } {
 			let mask: u16 = bytemuck::cast(mask);
-			mask.leading_zeros() as usize
+			(mask.trailing_zeros() as usize).min(Self::U64_LANES)
 		} else {
 			panic!()
 		}
 	}
 
+	define_mask_query!(8, 16, 32, 64);
+
 	#[inline(always)]
 	fn interleave_shfl_f32s<T: Interleave>(self, values: T) -> T {
 		unsafe { interleave_fallback::<f32, Self::f32s, T>(values) }
@@ -1101,6 +2617,24 @@ pub trait Simd: Seal + Debug + Copy + Send + Sync + 'static {
 		values: Self::u64s,
 	);
 
+	define_gather_scatter!(32, u32);
+	define_gather_scatter!(32, i32 => u32);
+	define_gather_scatter!(32, f32 => u32);
+	define_gather_scatter!(64, u64);
+	define_gather_scatter!(64, i64 => u64);
+	define_gather_scatter!(64, f64 => u64);
+
+	define_compress_expand!(8, u8);
+	define_compress_expand!(8, i8 => u8);
+	define_compress_expand!(16, u16);
+	define_compress_expand!(16, i16 => u16);
+	define_compress_expand!(32, u32);
+	define_compress_expand!(32, i32 => u32);
+	define_compress_expand!(32, f32 => u32);
+	define_compress_expand!(64, u64);
+	define_compress_expand!(64, i64 => u64);
+	define_compress_expand!(64, f64 => u64);
+
 	fn mul_add_c32s(self, a: Self::c32s, b: Self::c32s, c: Self::c32s) -> Self::c32s;
 	fn mul_add_c64s(self, a: Self::c64s, b: Self::c64s, c: Self::c64s) -> Self::c64s;
 	/// Computes `a * b + c`
@@ -1136,6 +2670,95 @@ pub trait Simd: Seal + Debug + Copy + Send + Sync + 'static {
 		self.mul_add_f64s(self.neg_f64s(a), b, c)
 	}
 
+	/// Computes `a * b - c`
+	#[inline(always)]
+	fn mul_sub_e_f32s(self, a: Self::f32s, b: Self::f32s, c: Self::f32s) -> Self::f32s {
+		self.mul_add_e_f32s(a, b, self.neg_f32s(c))
+	}
+	/// Computes `a * b - c`
+	#[inline(always)]
+	fn mul_sub_e_f64s(self, a: Self::f64s, b: Self::f64s, c: Self::f64s) -> Self::f64s {
+		self.mul_add_e_f64s(a, b, self.neg_f64s(c))
+	}
+	/// Computes `-a * b - c`
+	#[inline(always)]
+	fn negate_mul_sub_e_f32s(self, a: Self::f32s, b: Self::f32s, c: Self::f32s) -> Self::f32s {
+		self.mul_add_e_f32s(self.neg_f32s(a), b, self.neg_f32s(c))
+	}
+	/// Computes `-a * b - c`
+	#[inline(always)]
+	fn negate_mul_sub_e_f64s(self, a: Self::f64s, b: Self::f64s, c: Self::f64s) -> Self::f64s {
+		self.mul_add_e_f64s(self.neg_f64s(a), b, self.neg_f64s(c))
+	}
+	/// Computes `a * b + c` in the even lanes and `a * b - c` in the odd lanes
+	#[inline(always)]
+	fn mul_addsub_e_f32s(self, a: Self::f32s, b: Self::f32s, c: Self::f32s) -> Self::f32s {
+		let sign = cast(self.splat_c32s(c32::new(0.0, -0.0)));
+		self.mul_add_e_f32s(a, b, self.xor_f32s(c, sign))
+	}
+	/// Computes `a * b + c` in the even lanes and `a * b - c` in the odd lanes
+	#[inline(always)]
+	fn mul_addsub_e_f64s(self, a: Self::f64s, b: Self::f64s, c: Self::f64s) -> Self::f64s {
+		let sign = cast(self.splat_c64s(c64::new(0.0, -0.0)));
+		self.mul_add_e_f64s(a, b, self.xor_f64s(c, sign))
+	}
+	/// Computes `a * b - c` in the even lanes and `a * b + c` in the odd lanes
+	#[inline(always)]
+	fn mul_subadd_e_f32s(self, a: Self::f32s, b: Self::f32s, c: Self::f32s) -> Self::f32s {
+		let sign = cast(self.splat_c32s(c32::new(-0.0, 0.0)));
+		self.mul_add_e_f32s(a, b, self.xor_f32s(c, sign))
+	}
+	/// Computes `a * b - c` in the even lanes and `a * b + c` in the odd lanes
+	#[inline(always)]
+	fn mul_subadd_e_f64s(self, a: Self::f64s, b: Self::f64s, c: Self::f64s) -> Self::f64s {
+		let sign = cast(self.splat_c64s(c64::new(-0.0, 0.0)));
+		self.mul_add_e_f64s(a, b, self.xor_f64s(c, sign))
+	}
+	/// Computes `a * b - c`
+	#[inline(always)]
+	fn mul_sub_f32s(self, a: Self::f32s, b: Self::f32s, c: Self::f32s) -> Self::f32s {
+		self.mul_add_f32s(a, b, self.neg_f32s(c))
+	}
+	/// Computes `a * b - c`
+	#[inline(always)]
+	fn mul_sub_f64s(self, a: Self::f64s, b: Self::f64s, c: Self::f64s) -> Self::f64s {
+		self.mul_add_f64s(a, b, self.neg_f64s(c))
+	}
+	/// Computes `-a * b - c`
+	#[inline(always)]
+	fn negate_mul_sub_f32s(self, a: Self::f32s, b: Self::f32s, c: Self::f32s) -> Self::f32s {
+		self.mul_add_f32s(self.neg_f32s(a), b, self.neg_f32s(c))
+	}
+	/// Computes `-a * b - c`
+	#[inline(always)]
+	fn negate_mul_sub_f64s(self, a: Self::f64s, b: Self::f64s, c: Self::f64s) -> Self::f64s {
+		self.mul_add_f64s(self.neg_f64s(a), b, self.neg_f64s(c))
+	}
+	/// Computes `a * b + c` in the even lanes and `a * b - c` in the odd lanes
+	#[inline(always)]
+	fn mul_addsub_f32s(self, a: Self::f32s, b: Self::f32s, c: Self::f32s) -> Self::f32s {
+		let sign = cast(self.splat_c32s(c32::new(0.0, -0.0)));
+		self.mul_add_f32s(a, b, self.xor_f32s(c, sign))
+	}
+	/// Computes `a * b + c` in the even lanes and `a * b - c` in the odd lanes
+	#[inline(always)]
+	fn mul_addsub_f64s(self, a: Self::f64s, b: Self::f64s, c: Self::f64s) -> Self::f64s {
+		let sign = cast(self.splat_c64s(c64::new(0.0, -0.0)));
+		self.mul_add_f64s(a, b, self.xor_f64s(c, sign))
+	}
+	/// Computes `a * b - c` in the even lanes and `a * b + c` in the odd lanes
+	#[inline(always)]
+	fn mul_subadd_f32s(self, a: Self::f32s, b: Self::f32s, c: Self::f32s) -> Self::f32s {
+		let sign = cast(self.splat_c32s(c32::new(-0.0, 0.0)));
+		self.mul_add_f32s(a, b, self.xor_f32s(c, sign))
+	}
+	/// Computes `a * b - c` in the even lanes and `a * b + c` in the odd lanes
+	#[inline(always)]
+	fn mul_subadd_f64s(self, a: Self::f64s, b: Self::f64s, c: Self::f64s) -> Self::f64s {
+		let sign = cast(self.splat_c64s(c64::new(-0.0, 0.0)));
+		self.mul_add_f64s(a, b, self.xor_f64s(c, sign))
+	}
+
 	/// Computes `a * b`
 	#[inline]
 	fn mul_e_c32s(self, a: Self::c32s, b: Self::c32s) -> Self::c32s {
@@ -1310,6 +2933,48 @@ pub trait Simd: Seal + Debug + Copy + Send + Sync + 'static {
 
 	fn reduce_sum_f32s(self, a: Self::f32s) -> f32;
 	fn reduce_sum_f64s(self, a: Self::f64s) -> f64;
+
+	/// Returns the sum of the lanes of `a`, accumulated without overflow.
+	#[inline(always)]
+	fn reduce_sum_u8s(self, a: Self::u8s) -> u64 {
+		let lanes = bytemuck::cast_slice::<Self::u8s, u8>(core::slice::from_ref(&a));
+		lanes.iter().map(|&x| x as u64).sum()
+	}
+	define_int_reduce!(
+		sum,
+		wrapping_add,
+		"Returns the wrapping sum of the lanes of `a`.",
+		u32,
+		i32,
+		u64,
+		i64
+	);
+	define_int_reduce!(
+		min,
+		min,
+		"Returns the minimum of the lanes of `a`.",
+		u8,
+		i8,
+		u16,
+		i16,
+		u32,
+		i32,
+		u64,
+		i64
+	);
+	define_int_reduce!(
+		max,
+		max,
+		"Returns the maximum of the lanes of `a`.",
+		u8,
+		i8,
+		u16,
+		i16,
+		u32,
+		i32,
+		u64,
+		i64
+	);
 	#[inline(always)]
 	fn rotate_left_c32s(self, a: Self::c32s, amount: usize) -> Self::c32s {
 		self.rotate_right_c32s(a, amount.wrapping_neg())
@@ -1366,6 +3031,18 @@ pub trait Simd: Seal + Debug + Copy + Send + Sync + 'static {
 	fn rotate_right_u32s(self, a: Self::u32s, amount: usize) -> Self::u32s;
 	fn rotate_right_u64s(self, a: Self::u64s, amount: usize) -> Self::u64s;
 
+	/// Returns a register whose lane `i` is `table[idx[i]]`, or `0` if `idx[i]` is out of range
+	/// for the register.
+	#[inline]
+	fn table_lookup_u8s(self, table: Self::u8s, idx: Self::u8s) -> Self::u8s {
+		let mut out = idx;
+		let table = bytemuck::cast_slice::<Self::u8s, u8>(core::slice::from_ref(&table));
+		for x in bytemuck::cast_slice_mut::<Self::u8s, u8>(core::slice::from_mut(&mut out)) {
+			*x = table.get(*x as usize).copied().unwrap_or(0);
+		}
+		out
+	}
+
 	#[inline]
 	fn select_f32s(
 		self,
@@ -1418,11 +3095,68 @@ pub trait Simd: Seal + Debug + Copy + Send + Sync + 'static {
 			self.transmute_u64s_i64s(if_false),
 		))
 	}
+	#[inline]
+	fn select_i8s(self, mask: Self::m8s, if_true: Self::i8s, if_false: Self::i8s) -> Self::i8s {
+		self.transmute_i8s_u8s(self.select_u8s(
+			mask,
+			self.transmute_u8s_i8s(if_true),
+			self.transmute_u8s_i8s(if_false),
+		))
+	}
+	#[inline]
+	fn select_i16s(
+		self,
+		mask: Self::m16s,
+		if_true: Self::i16s,
+		if_false: Self::i16s,
+	) -> Self::i16s {
+		self.transmute_i16s_u16s(self.select_u16s(
+			mask,
+			self.transmute_u16s_i16s(if_true),
+			self.transmute_u16s_i16s(if_false),
+		))
+	}
+	#[inline]
+	fn select_u8s(self, mask: Self::m8s, if_true: Self::u8s, if_false: Self::u8s) -> Self::u8s {
+		select_fallback::<u8, _, _>(mask, if_true, if_false)
+	}
+	#[inline]
+	fn select_u16s(
+		self,
+		mask: Self::m16s,
+		if_true: Self::u16s,
+		if_false: Self::u16s,
+	) -> Self::u16s {
+		select_fallback::<u16, _, _>(mask, if_true, if_false)
+	}
 	fn select_u32s(self, mask: Self::m32s, if_true: Self::u32s, if_false: Self::u32s)
 	-> Self::u32s;
 	fn select_u64s(self, mask: Self::m64s, if_true: Self::u64s, if_false: Self::u64s)
 	-> Self::u64s;
 
+	/// Selects the real and imaginary parts of each complex number independently. Use the same
+	/// mask value for both parts to select whole complex numbers.
+	#[inline]
+	fn select_c32s(
+		self,
+		mask: Self::m32s,
+		if_true: Self::c32s,
+		if_false: Self::c32s,
+	) -> Self::c32s {
+		cast(self.select_f32s(mask, cast(if_true), cast(if_false)))
+	}
+	/// Selects the real and imaginary parts of each complex number independently. Use the same
+	/// mask value for both parts to select whole complex numbers.
+	#[inline]
+	fn select_c64s(
+		self,
+		mask: Self::m64s,
+		if_true: Self::c64s,
+		if_false: Self::c64s,
+	) -> Self::c64s {
+		cast(self.select_f64s(mask, cast(if_true), cast(if_false)))
+	}
+
 	fn swap_re_im_c32s(self, a: Self::c32s) -> Self::c32s;
 	fn swap_re_im_c64s(self, a: Self::c64s) -> Self::c64s;
 
@@ -1527,8 +3261,61 @@ pub trait Simd: Seal + Debug + Copy + Send + Sync + 'static {
 
 	fn vectorize<Op: WithSimd>(self, op: Op) -> Op::Output;
 	fn widening_mul_u32s(self, a: Self::u32s, b: Self::u32s) -> (Self::u32s, Self::u32s);
-	fn wrapping_dyn_shl_u32s(self, a: Self::u32s, amount: Self::u32s) -> Self::u32s;
-	fn wrapping_dyn_shr_u32s(self, a: Self::u32s, amount: Self::u32s) -> Self::u32s;
+	/// Multiplies the elements of each lane of `a` and `b`, and returns separately the low and
+	/// high bits of the result.
+	#[inline(always)]
+	fn widening_mul_u64s(self, a: Self::u64s, b: Self::u64s) -> (Self::u64s, Self::u64s) {
+		let mut lo = a;
+		let mut hi = b;
+		let a = bytemuck::cast_slice::<Self::u64s, u64>(core::slice::from_ref(&a));
+		let b = bytemuck::cast_slice::<Self::u64s, u64>(core::slice::from_ref(&b));
+		let lo_lanes = bytemuck::cast_slice_mut::<Self::u64s, u64>(core::slice::from_mut(&mut lo));
+		let hi_lanes = bytemuck::cast_slice_mut::<Self::u64s, u64>(core::slice::from_mut(&mut hi));
+		for i in 0..a.len() {
+			let c = a[i] as u128 * b[i] as u128;
+			(lo_lanes[i], hi_lanes[i]) = (c as u64, (c >> 64) as u64);
+		}
+		(lo, hi)
+	}
+	/// Multiplies the elements of each lane of `a` and `b`, and returns the high half of the
+	/// full-width product.
+	#[inline(always)]
+	fn mul_high_u64s(self, a: Self::u64s, b: Self::u64s) -> Self::u64s {
+		self.widening_mul_u64s(a, b).1
+	}
+
+	define_shift!(shl, shl, "to the left, while shifting in zeros,", u8 => u8, u16 => u16, u32 => u32, u64 => u64);
+	define_shift!(shr, shr, "to the right, while shifting in zeros,", u8 => u8, u16 => u16, u32 => u32, u64 => u64);
+	define_shift!(sra, shr, "to the right, while shifting in sign bits,", i8 => u8, i16 => u16, i32 => u32, i64 => u64);
+	transmute_shift!(shl, i8 => u8, i16 => u16, i32 => u32, i64 => u64);
+
+	define_div!(u32 => i32, u64 => i64);
+
+	/// Shifts the bits of each lane of `a` to the left by `amount`, saturating to the minimum or
+	/// maximum value if the result does not fit in the lane.
+	#[inline(always)]
+	fn saturating_shl_i16s(self, a: Self::i16s, amount: u32) -> Self::i16s {
+		let saturated = self.xor_i16s(self.splat_i16s(i16::MAX), self.sra_const_i16s::<15>(a));
+		if amount >= 16 {
+			return self.select_i16s(self.equal_i16s(a, self.splat_i16s(0)), a, saturated);
+		}
+		let shifted = self.wrapping_shl_i16s(a, amount);
+		let exact = self.equal_i16s(self.wrapping_sra_i16s(shifted, amount), a);
+		self.select_i16s(exact, shifted, saturated)
+	}
+
+	/// Shifts the bits of each lane of `a` to the left by `amount`, saturating to the minimum or
+	/// maximum value if the result does not fit in the lane.
+	#[inline(always)]
+	fn saturating_shl_i32s(self, a: Self::i32s, amount: u32) -> Self::i32s {
+		let saturated = self.xor_i32s(self.splat_i32s(i32::MAX), self.sra_const_i32s::<31>(a));
+		if amount >= 32 {
+			return self.select_i32s(self.equal_i32s(a, self.splat_i32s(0)), a, saturated);
+		}
+		let shifted = self.wrapping_shl_i32s(a, amount);
+		let exact = self.equal_i32s(self.wrapping_sra_i32s(shifted, amount), a);
+		self.select_i32s(exact, shifted, saturated)
+	}
 }
 
 pub trait PortableSimd: Simd {}
@@ -1759,8 +3546,10 @@ macro_rules! scalar_simd {
 		impl Simd for $ty {
 			type m8s = $m8s;
 			type m16s = $m16s;
+			type bf16s = $u16s;
 			type c32s = $f32s;
 			type c64s = $f64s;
+			type f16s = $u16s;
 			type f32s = $f32s;
 			type f64s = $f64s;
 			type i16s = $i16s;
@@ -2634,8 +4423,10 @@ macro_rules! splat_primitive {
 
 impl Seal for Scalar {}
 impl Simd for Scalar {
+	type bf16s = bf16;
 	type c32s = c32;
 	type c64s = c64;
+	type f16s = f16;
 	type f32s = f32;
 	type f64s = f64;
 	type i16s = i16;
@@ -2788,6 +4579,46 @@ impl Simd for Scalar {
 		if mask { 0 } else { 1 }
 	}
 
+	#[inline(always)]
+	fn to_bitmask_m8s(self, mask: Self::m8s) -> u64 {
+		mask as u64
+	}
+
+	#[inline(always)]
+	fn to_bitmask_m16s(self, mask: Self::m16s) -> u64 {
+		mask as u64
+	}
+
+	#[inline(always)]
+	fn to_bitmask_m32s(self, mask: Self::m32s) -> u64 {
+		mask as u64
+	}
+
+	#[inline(always)]
+	fn to_bitmask_m64s(self, mask: Self::m64s) -> u64 {
+		mask as u64
+	}
+
+	#[inline(always)]
+	fn from_bitmask_m8s(self, bits: u64) -> Self::m8s {
+		bits & 1 != 0
+	}
+
+	#[inline(always)]
+	fn from_bitmask_m16s(self, bits: u64) -> Self::m16s {
+		bits & 1 != 0
+	}
+
+	#[inline(always)]
+	fn from_bitmask_m32s(self, bits: u64) -> Self::m32s {
+		bits & 1 != 0
+	}
+
+	#[inline(always)]
+	fn from_bitmask_m64s(self, bits: u64) -> Self::m64s {
+		bits & 1 != 0
+	}
+
 	#[inline(always)]
 	unsafe fn mask_load_ptr_c32s(self, mask: MemMask<Self::m32s>, ptr: *const c32) -> Self::c32s {
 		if mask.mask { *ptr } else { core::mem::zeroed() }
@@ -2939,6 +4770,46 @@ impl Simd for Scalar {
 		fma_f64(-a, b, c)
 	}
 
+	#[inline]
+	fn mul_addsub_e_f32s(self, a: Self::f32s, b: Self::f32s, c: Self::f32s) -> Self::f32s {
+		a * b + c
+	}
+
+	#[inline]
+	fn mul_addsub_e_f64s(self, a: Self::f64s, b: Self::f64s, c: Self::f64s) -> Self::f64s {
+		a * b + c
+	}
+
+	#[inline]
+	fn mul_addsub_f32s(self, a: Self::f32s, b: Self::f32s, c: Self::f32s) -> Self::f32s {
+		fma_f32(a, b, c)
+	}
+
+	#[inline]
+	fn mul_addsub_f64s(self, a: Self::f64s, b: Self::f64s, c: Self::f64s) -> Self::f64s {
+		fma_f64(a, b, c)
+	}
+
+	#[inline]
+	fn mul_subadd_e_f32s(self, a: Self::f32s, b: Self::f32s, c: Self::f32s) -> Self::f32s {
+		a * b - c
+	}
+
+	#[inline]
+	fn mul_subadd_e_f64s(self, a: Self::f64s, b: Self::f64s, c: Self::f64s) -> Self::f64s {
+		a * b - c
+	}
+
+	#[inline]
+	fn mul_subadd_f32s(self, a: Self::f32s, b: Self::f32s, c: Self::f32s) -> Self::f32s {
+		fma_f32(a, b, -c)
+	}
+
+	#[inline]
+	fn mul_subadd_f64s(self, a: Self::f64s, b: Self::f64s, c: Self::f64s) -> Self::f64s {
+		fma_f64(a, b, -c)
+	}
+
 	#[inline]
 	fn mul_c32s(self, a: Self::c32s, b: Self::c32s) -> Self::c32s {
 		let re = fma_f32(a.re, b.re, -(a.im * b.im));
@@ -3101,6 +4972,21 @@ impl Simd for Scalar {
 		a
 	}
 
+	#[inline]
+	fn select_u8s(self, mask: Self::m8s, if_true: Self::u8s, if_false: Self::u8s) -> Self::u8s {
+		if mask { if_true } else { if_false }
+	}
+
+	#[inline]
+	fn select_u16s(
+		self,
+		mask: Self::m16s,
+		if_true: Self::u16s,
+		if_false: Self::u16s,
+	) -> Self::u16s {
+		if mask { if_true } else { if_false }
+	}
+
 	#[inline]
 	fn select_u32s(
 		self,
@@ -3121,6 +5007,26 @@ impl Simd for Scalar {
 		if mask { if_true } else { if_false }
 	}
 
+	#[inline]
+	fn select_c32s(
+		self,
+		mask: Self::m32s,
+		if_true: Self::c32s,
+		if_false: Self::c32s,
+	) -> Self::c32s {
+		if mask { if_true } else { if_false }
+	}
+
+	#[inline]
+	fn select_c64s(
+		self,
+		mask: Self::m64s,
+		if_true: Self::c64s,
+		if_false: Self::c64s,
+	) -> Self::c64s {
+		if mask { if_true } else { if_false }
+	}
+
 	#[inline]
 	fn swap_re_im_c32s(self, a: Self::c32s) -> Self::c32s {
 		c32 { re: a.im, im: a.re }
@@ -3244,19 +5150,45 @@ unsafe fn rsplit_mut_slice<T, U>(slice: &mut [T]) -> (&mut [T], &mut [U]) {
 	)
 }
 
+/// Returns the level named by the `PULP_ARCH` environment variable, which is only read and parsed
+/// once. Unknown names are ignored.
+#[cfg(feature = "std")]
+fn arch_override() -> Option<ArchLevel> {
+	static LEVEL: std::sync::OnceLock<Option<ArchLevel>> = std::sync::OnceLock::new();
+	*LEVEL.get_or_init(|| ArchLevel::from_name(&std::env::var("PULP_ARCH").ok()?))
+}
+
 match_cfg!(
 	item,
 	match cfg!() {
 		const { any(target_arch = "x86", target_arch = "x86_64") } => {
-			pub use x86::Arch;
+			pub use x86::{Arch, ArchLevel};
 		},
 		const { target_arch = "aarch64" } => {
-			pub use aarch64::Arch;
+			pub use aarch64::{Arch, ArchLevel};
 		},
 		const { target_arch = "wasm32" } => {
-			pub use wasm::Arch;
+			pub use wasm::{Arch, ArchLevel};
 		},
 		_ => {
+			#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+			#[non_exhaustive]
+			pub enum ArchLevel {
+				Scalar,
+			}
+
+			impl ArchLevel {
+				#[inline]
+				pub fn from_name(name: &str) -> Option<Self> {
+					name.trim().eq_ignore_ascii_case("scalar").then_some(Self::Scalar)
+				}
+
+				#[inline]
+				pub fn name(self) -> &'static str {
+					"scalar"
+				}
+			}
+
 			#[derive(Debug, Clone, Copy)]
 			#[non_exhaustive]
 			pub enum Arch {
@@ -3269,6 +5201,22 @@ match_cfg!(
 					Self::Scalar
 				}
 
+				#[inline(always)]
+				pub fn with_max_level(level: ArchLevel) -> Self {
+					let _ = level;
+					Self::Scalar
+				}
+
+				#[inline(always)]
+				pub fn from_name(name: &str) -> Option<Self> {
+					ArchLevel::from_name(name).map(Self::with_max_level)
+				}
+
+				#[inline(always)]
+				pub fn level(self) -> ArchLevel {
+					ArchLevel::Scalar
+				}
+
 				#[inline(always)]
 				pub fn dispatch<Op: WithSimd>(self, op: Op) -> Op::Output {
 					op.with_simd(Scalar)
@@ -3411,16 +5359,68 @@ macro_rules! inherit_x2 {
 
     ($base: expr, splat, {$(
         $(#[$attr: meta])*
-        $(unsafe $($placeholder: lifetime)?)?
-        fn $func: ident ($self: ident
-            $(,$arg: ident: $ty: ty)* $(,)?
-        ) $(-> $ret: ty)?;
+        $(unsafe $($placeholder: lifetime)?)?
+        fn $func: ident ($self: ident
+            $(,$arg: ident: $ty: ty)* $(,)?
+        ) $(-> $ret: ty)?;
+    )*}) => {
+        $(
+            $(#[$attr])*
+            #[inline(always)]
+            $(unsafe $($placeholder)?)? fn $func ($self, $($arg: $ty,)*) $(-> $ret)? {
+                cast!([($base).$func ($($arg,)*), ($base).$func ($($arg,)*)])
+            }
+        )*
+    };
+
+    ($base: expr, first, {$(
+        $(#[$attr: meta])*
+        $(unsafe $($placeholder: lifetime)?)?
+        fn $func: ident ($self: ident
+            ,$first: ident: $first_ty: ty
+            $(,$arg: ident: $ty: ty)* $(,)?
+        ) $(-> $ret: ty)?;
+    )*}) => {
+        $(
+            $(#[$attr])*
+            #[inline(always)]
+            $(unsafe $($placeholder)?)? fn $func ($self, $first: $first_ty, $($arg: $ty,)*) $(-> $ret)? {
+            	let $first: [_; 2] = cast!($first);
+                cast!([($base).$func ($first[0], $($arg,)*), ($base).$func ($first[1], $($arg,)*)])
+            }
+        )*
+    };
+
+    ($base: expr, widen, {$(
+        fn $low: ident ($self: ident, $a: ident: $ty: ty) -> $ret: ty;
+        fn $high: ident ($self_high: ident, $a_high: ident: $ty_high: ty) -> $ret_high: ty;
+    )*}) => {
+        $(
+            #[inline(always)]
+            fn $low ($self, $a: $ty) -> $ret {
+                let $a: [_; 2] = cast!($a);
+                cast!([($base).$low($a[0]), ($base).$high($a[0])])
+            }
+
+            #[inline(always)]
+            fn $high ($self_high, $a_high: $ty_high) -> $ret_high {
+                let $a_high: [_; 2] = cast!($a_high);
+                cast!([($base).$low($a_high[1]), ($base).$high($a_high[1])])
+            }
+        )*
+    };
+
+    ($base: expr, narrow, {$(
+        $(#[$attr: meta])*
+        fn $func: ident ($self: ident, $a: ident: $ty: ty, $b: ident: $ty_b: ty $(,)?) -> $ret: ty;
     )*}) => {
         $(
             $(#[$attr])*
             #[inline(always)]
-            $(unsafe $($placeholder)?)? fn $func ($self, $($arg: $ty,)*) $(-> $ret)? {
-                cast!([($base).$func ($($arg,)*), ($base).$func ($($arg,)*)])
+            fn $func ($self, $a: $ty, $b: $ty_b) -> $ret {
+                let $a: [_; 2] = cast!($a);
+                let $b: [_; 2] = cast!($b);
+                cast!([($base).$func($a[0], $a[1]), ($base).$func($b[0], $b[1])])
             }
         )*
     };
@@ -3459,6 +5459,9 @@ pub mod wasm;
 /// Low level aarch64 API.
 pub mod aarch64;
 
+pub mod math;
+pub mod testing;
+
 /// Mask type with 8 bits. Its bit pattern is either all ones or all zeros. Unsafe code must not
 /// depend on this, however.
 #[derive(Copy, Clone, PartialEq, Eq, Default)]
@@ -5109,6 +7112,11 @@ unsafe impl Pod for m16 {}
 unsafe impl Pod for m32 {}
 unsafe impl Pod for m64 {}
 
+unsafe impl Zeroable for f16 {}
+unsafe impl Pod for f16 {}
+unsafe impl Zeroable for bf16 {}
+unsafe impl Pod for bf16 {}
+
 unsafe impl Zeroable for b8 {}
 unsafe impl Pod for b8 {}
 unsafe impl Zeroable for b16 {}
@@ -5256,11 +7264,1509 @@ pub const fn iota_64<T: Interleave, const N: usize>() -> [MaybeUninit<T>; N] {
 	iota!(T, N, u64)
 }
 
-#[cfg(target_arch = "x86_64")]
 #[cfg(test)]
 mod tests {
+	extern crate alloc;
+
 	use super::*;
+	use alloc::vec::Vec;
+	use core::iter::zip;
+	use rand::random;
+
+	// runs the generic `check` function on every backend available on this machine
+	macro_rules! check_all_simd {
+		($check: ident) => {{
+			#[derive(Clone)]
+			struct Check;
+			impl WithSimd for Check {
+				type Output = ();
+
+				#[inline(always)]
+				fn with_simd<S: Simd>(self, simd: S) {
+					$check(simd)
+				}
+			}
+			crate::testing::for_each_available_simd(Check, |_, ()| {});
+		}};
+	}
+
+	fn check_generic_shifts<S: Simd>(simd: S) {
+		macro_rules! check {
+			($func: ident, $op: ident, $ty: ident, $amount: ident) => {
+				paste! {
+					for _ in 0..16 {
+						let mut a = simd.[<splat_ $ty s>](0);
+						let mut amount = simd.[<splat_ $amount s>](0);
+						bytemuck::cast_slice_mut::<_, $ty>(core::slice::from_mut(&mut a)).fill_with(random);
+						bytemuck::cast_slice_mut::<_, $amount>(core::slice::from_mut(&mut amount)).fill_with(random);
+
+						let a_lanes = bytemuck::cast_slice::<_, $ty>(core::slice::from_ref(&a));
+						let amount_lanes = bytemuck::cast_slice::<_, $amount>(core::slice::from_ref(&amount));
+
+						let c = simd.[<$func _const_ $ty s>]::<3>(a);
+						for (&x, &y) in zip(a_lanes, bytemuck::cast_slice::<_, $ty>(core::slice::from_ref(&c))) {
+							assert_eq!(x.[<wrapping_ $op>](3), y);
+						}
+
+						for n in 0..2 * $ty::BITS + 1 {
+							let c = simd.[<wrapping_ $func _ $ty s>](a, n);
+							for (&x, &y) in zip(a_lanes, bytemuck::cast_slice::<_, $ty>(core::slice::from_ref(&c))) {
+								assert_eq!(x.[<wrapping_ $op>](n), y);
+							}
+						}
+
+						let c = simd.[<wrapping_dyn_ $func _ $ty s>](a, amount);
+						for ((&x, &n), &y) in zip(zip(a_lanes, amount_lanes), bytemuck::cast_slice::<_, $ty>(core::slice::from_ref(&c))) {
+							assert_eq!(x.[<wrapping_ $op>](n as u32), y);
+						}
+					}
+				}
+			};
+		}
+
+		check!(shl, shl, u8, u8);
+		check!(shl, shl, i8, u8);
+		check!(shr, shr, u8, u8);
+		check!(sra, shr, i8, u8);
+		check!(shl, shl, u16, u16);
+		check!(shl, shl, i16, u16);
+		check!(shr, shr, u16, u16);
+		check!(sra, shr, i16, u16);
+		check!(shl, shl, u32, u32);
+		check!(shl, shl, i32, u32);
+		check!(shr, shr, u32, u32);
+		check!(sra, shr, i32, u32);
+		check!(shl, shl, u64, u64);
+		check!(shl, shl, i64, u64);
+		check!(shr, shr, u64, u64);
+		check!(sra, shr, i64, u64);
+	}
+
+	#[test]
+	fn test_generic_shift() {
+		check_all_simd!(check_generic_shifts);
+	}
+
+	fn check_rounding<S: Simd>(simd: S) {
+		macro_rules! check {
+			($ty: ident, $values: expr) => {
+				paste! {
+					let values: &[$ty] = $values;
+					for offset in 0..values.len() {
+						let mut a = simd.[<splat_ $ty s>](0.0);
+						let lanes = bytemuck::cast_slice_mut::<_, $ty>(core::slice::from_mut(&mut a));
+						for (i, x) in lanes.iter_mut().enumerate() {
+							*x = values[(i + offset) % values.len()];
+						}
+						let a_lanes = bytemuck::cast_slice::<_, $ty>(core::slice::from_ref(&a));
+
+						for (result, scalar) in [
+							(simd.[<floor_ $ty s>](a), $ty::floor as fn($ty) -> $ty),
+							(simd.[<ceil_ $ty s>](a), $ty::ceil),
+							(simd.[<trunc_ $ty s>](a), $ty::trunc),
+							(simd.[<round_ties_even_ $ty s>](a), $ty::round_ties_even),
+							(simd.[<round_ties_away_ $ty s>](a), $ty::round),
+						] {
+							for (&x, &y) in zip(a_lanes, bytemuck::cast_slice::<_, $ty>(core::slice::from_ref(&result))) {
+								let expected = scalar(x);
+								assert!(expected.to_bits() == y.to_bits() || (expected.is_nan() && y.is_nan()));
+							}
+						}
+					}
+				}
+			};
+		}
+
+		check!(
+			f32,
+			&[
+				0.5,
+				-0.5,
+				1.5,
+				-1.5,
+				2.5,
+				-2.5,
+				0.4,
+				-0.4,
+				0.6,
+				-0.6,
+				0.0,
+				-0.0,
+				0.49999997,
+				8388607.5,
+				-8388607.5,
+				16777216.0,
+				1e30,
+				-1e30,
+				f32::INFINITY,
+				f32::NEG_INFINITY,
+				f32::NAN,
+				f32::MIN_POSITIVE,
+				-f32::MIN_POSITIVE,
+			]
+		);
+		check!(
+			f64,
+			&[
+				0.5,
+				-0.5,
+				1.5,
+				-1.5,
+				2.5,
+				-2.5,
+				0.4,
+				-0.4,
+				0.6,
+				-0.6,
+				0.0,
+				-0.0,
+				0.49999999999999994,
+				4503599627370495.5,
+				-4503599627370495.5,
+				9007199254740992.0,
+				1e300,
+				-1e300,
+				f64::INFINITY,
+				f64::NEG_INFINITY,
+				f64::NAN,
+				f64::MIN_POSITIVE,
+				-f64::MIN_POSITIVE,
+			]
+		);
+	}
+
+	#[test]
+	fn test_rounding() {
+		check_all_simd!(check_rounding);
+	}
+
+	fn check_convert<S: Simd>(simd: S) {
+		macro_rules! check {
+			($from: ident => $to: ident, $values: expr) => {
+				paste! {
+					let values: &[$from] = $values;
+					for offset in 0..values.len() {
+						let mut a = simd.[<splat_ $from s>](0 as $from);
+						let lanes = bytemuck::cast_slice_mut::<_, $from>(core::slice::from_mut(&mut a));
+						for (i, x) in lanes.iter_mut().enumerate() {
+							*x = values[(i + offset) % values.len()];
+						}
+						let a_lanes = bytemuck::cast_slice::<_, $from>(core::slice::from_ref(&a));
+
+						let b = simd.[<convert_ $from s_ $to s>](a);
+						for (&x, &y) in zip(a_lanes, bytemuck::cast_slice::<_, $to>(core::slice::from_ref(&b))) {
+							assert_eq!(x as $to, y);
+						}
+					}
+				}
+			};
+			($from: ident => $to: ident) => {
+				paste! {
+					for _ in 0..16 {
+						let mut a = simd.[<splat_ $from s>](0);
+						bytemuck::cast_slice_mut::<_, $from>(core::slice::from_mut(&mut a)).fill_with(|| random::<$from>() >> (random::<u32>() % $from::BITS));
+						let a_lanes = bytemuck::cast_slice::<_, $from>(core::slice::from_ref(&a));
+
+						let b = simd.[<convert_ $from s_ $to s>](a);
+						for (&x, &y) in zip(a_lanes, bytemuck::cast_slice::<_, $to>(core::slice::from_ref(&b))) {
+							assert_eq!(x as $to, y);
+						}
+					}
+				}
+			};
+			(round $from: ident => $to: ident, $values: expr) => {
+				paste! {
+					let values: &[$from] = $values;
+					for offset in 0..values.len() {
+						let mut a = simd.[<splat_ $from s>](0 as $from);
+						let lanes = bytemuck::cast_slice_mut::<_, $from>(core::slice::from_mut(&mut a));
+						for (i, x) in lanes.iter_mut().enumerate() {
+							*x = values[(i + offset) % values.len()];
+						}
+						let a_lanes = bytemuck::cast_slice::<_, $from>(core::slice::from_ref(&a));
+
+						let b = simd.[<convert_round_ $from s_ $to s>](a);
+						for (&x, &y) in zip(a_lanes, bytemuck::cast_slice::<_, $to>(core::slice::from_ref(&b))) {
+							assert_eq!(x.round_ties_even() as $to, y);
+						}
+					}
+				}
+			};
+		}
+
+		let f32s: &[f32] = &[
+			0.0,
+			-0.0,
+			0.5,
+			-0.5,
+			1.5,
+			-1.5,
+			2.5,
+			-0.9,
+			1e-30,
+			123456.7,
+			-123456.7,
+			2147483520.0,
+			2147483648.0,
+			-2147483648.0,
+			-2147483904.0,
+			4294967040.0,
+			4294967296.0,
+			1e30,
+			-1e30,
+			f32::INFINITY,
+			f32::NEG_INFINITY,
+			f32::NAN,
+		];
+		let f64s: &[f64] = &[
+			0.0,
+			-0.0,
+			0.5,
+			-0.5,
+			1.5,
+			-1.5,
+			2.5,
+			-0.9,
+			1e-300,
+			123456789.7,
+			-123456789.7,
+			4294967296.5,
+			-4294967296.5,
+			4503599627370497.0,
+			9223372036854774784.0,
+			9223372036854775808.0,
+			-9223372036854775808.0,
+			-9223372036854777856.0,
+			18446744073709549568.0,
+			18446744073709551616.0,
+			1e300,
+			-1e300,
+			f64::INFINITY,
+			f64::NEG_INFINITY,
+			f64::NAN,
+		];
+
+		check!(f32 => i32, f32s);
+		check!(f32 => u32, f32s);
+		check!(f64 => i64, f64s);
+		check!(f64 => u64, f64s);
+		check!(round f32 => i32, f32s);
+		check!(round f32 => u32, f32s);
+		check!(round f64 => i64, f64s);
+		check!(round f64 => u64, f64s);
+
+		check!(i32 => f32);
+		check!(u32 => f32);
+		check!(i64 => f64);
+		check!(u64 => f64);
+		check!(i32 => f32, &[0, 1, -1, i32::MIN, i32::MAX, 16777217, -16777217]);
+		check!(u32 => f32, &[0, 1, u32::MAX, 16777217, 0x8000_0080, 0x8000_0180, 0xFFFF_FF80]);
+		check!(i64 => f64, &[0, 1, -1, i64::MIN, i64::MAX, (1 << 53) + 1, -(1 << 53) - 1, (1 << 62) + (1 << 9) + 1]);
+		check!(u64 => f64, &[0, 1, u64::MAX, (1 << 53) + 1, (1 << 63) + (1 << 10), (1 << 63) + (1 << 11) + (1 << 10), (1 << 63) + (1 << 10) + 1]);
+	}
+
+	#[test]
+	fn test_convert() {
+		check_all_simd!(check_convert);
+	}
+
+	fn check_widen_narrow<S: Simd>(simd: S) {
+		macro_rules! check {
+			(widen $from: ident => $to: ident) => {
+				paste! {
+					let mut a = simd.[<splat_ $from s>](0 as $from);
+					bytemuck::cast_slice_mut::<_, $from>(core::slice::from_mut(&mut a)).fill_with(|| random::<u64>() as $from);
+					let a_lanes = bytemuck::cast_slice::<_, $from>(core::slice::from_ref(&a));
+
+					let lo = simd.[<widen_low_ $from s>](a);
+					let hi = simd.[<widen_high_ $from s>](a);
+					let lo = bytemuck::cast_slice::<_, $to>(core::slice::from_ref(&lo));
+					let hi = bytemuck::cast_slice::<_, $to>(core::slice::from_ref(&hi));
+					for (&x, &y) in zip(a_lanes, lo.iter().chain(hi)) {
+						assert_eq!(x as $to, y);
+					}
+				}
+			};
+			(narrow $func: ident, $from: ident => $to: ident, $convert: expr) => {
+				paste! {
+					let mut a = simd.[<splat_ $from s>](0 as $from);
+					let mut b = simd.[<splat_ $from s>](0 as $from);
+					bytemuck::cast_slice_mut::<_, $from>(core::slice::from_mut(&mut a)).fill_with(|| random::<$from>() >> (random::<u32>() % $from::BITS));
+					bytemuck::cast_slice_mut::<_, $from>(core::slice::from_mut(&mut b)).fill_with(random);
+					let a_lanes = bytemuck::cast_slice::<_, $from>(core::slice::from_ref(&a));
+					let b_lanes = bytemuck::cast_slice::<_, $from>(core::slice::from_ref(&b));
+
+					let c = simd.[<$func _ $from s>](a, b);
+					for (&x, &y) in zip(a_lanes.iter().chain(b_lanes), bytemuck::cast_slice::<_, $to>(core::slice::from_ref(&c))) {
+						let convert: fn($from) -> $to = $convert;
+						assert_eq!(convert(x), y);
+					}
+				}
+			};
+			(narrow $from: ident => $to: ident) => {
+				for _ in 0..16 {
+					check!(narrow narrow, $from => $to, |x| x as $to);
+					check!(narrow narrow_saturating, $from => $to, |x| x.clamp($to::MIN as $from, $to::MAX as $from) as $to);
+				}
+			};
+		}
+
+		for _ in 0..16 {
+			check!(widen u8 => u16);
+			check!(widen i8 => i16);
+			check!(widen u16 => u32);
+			check!(widen i16 => i32);
+			check!(widen u32 => u64);
+			check!(widen i32 => i64);
+		}
+		check!(narrow u16 => u8);
+		check!(narrow i16 => i8);
+		check!(narrow u32 => u16);
+		check!(narrow i32 => i16);
+		check!(narrow u64 => u32);
+		check!(narrow i64 => i32);
+		for _ in 0..16 {
+			check!(narrow narrow_saturating_unsigned, i16 => u8, |x| x.clamp(0, u8::MAX as i16) as u8);
+			check!(narrow narrow_saturating_unsigned, i32 => u16, |x| x.clamp(0, u16::MAX as i32) as u16);
+			check!(narrow narrow_saturating_unsigned, i64 => u32, |x| x.clamp(0, u32::MAX as i64) as u32);
+		}
+
+		let mut a = simd.splat_f32s(0.0);
+		for (i, x) in bytemuck::cast_slice_mut::<_, f32>(core::slice::from_mut(&mut a))
+			.iter_mut()
+			.enumerate()
+		{
+			*x = i as f32 + 0.5;
+		}
+		let lo = simd.widen_low_f32s(a);
+		let hi = simd.widen_high_f32s(a);
+		let a_lanes = bytemuck::cast_slice::<_, f32>(core::slice::from_ref(&a));
+		let wide = bytemuck::cast_slice::<_, f64>(core::slice::from_ref(&lo))
+			.iter()
+			.chain(bytemuck::cast_slice::<_, f64>(core::slice::from_ref(&hi)));
+		for (&x, &y) in zip(a_lanes, wide) {
+			assert_eq!(x as f64, y);
+		}
+		let c = simd.narrow_f64s(hi, lo);
+		for (&x, &y) in zip(
+			bytemuck::cast_slice::<_, f64>(core::slice::from_ref(&hi))
+				.iter()
+				.chain(bytemuck::cast_slice::<_, f64>(core::slice::from_ref(&lo))),
+			bytemuck::cast_slice::<_, f32>(core::slice::from_ref(&c)),
+		) {
+			assert_eq!(x as f32, y);
+		}
+	}
+
+	#[test]
+	fn test_widen_narrow() {
+		check_all_simd!(check_widen_narrow);
+	}
+
+	fn check_int_reduce<S: Simd>(simd: S) {
+		macro_rules! random_lanes {
+			($ty: ident) => {
+				paste! {{
+					let mut a = simd.[<splat_ $ty s>](0);
+					bytemuck::cast_slice_mut::<_, $ty>(core::slice::from_mut(&mut a)).fill_with(random);
+					a
+				}}
+			};
+		}
+		macro_rules! check {
+			(sum $($ty: ident),*) => {
+				paste! {$(
+					let a = random_lanes!($ty);
+					let lanes = bytemuck::cast_slice::<_, $ty>(core::slice::from_ref(&a));
+					assert_eq!(simd.[<reduce_sum_ $ty s>](a), lanes.iter().fold(0, |acc: $ty, &x| acc.wrapping_add(x)));
+				)*}
+			};
+			(min_max $($ty: ident),*) => {
+				paste! {$(
+					let a = random_lanes!($ty);
+					let lanes = bytemuck::cast_slice::<_, $ty>(core::slice::from_ref(&a));
+					assert_eq!(simd.[<reduce_min_ $ty s>](a), *lanes.iter().min().unwrap());
+					assert_eq!(simd.[<reduce_max_ $ty s>](a), *lanes.iter().max().unwrap());
+				)*}
+			};
+		}
+
+		for _ in 0..16 {
+			let a = random_lanes!(u8);
+			let lanes = bytemuck::cast_slice::<_, u8>(core::slice::from_ref(&a));
+			assert_eq!(
+				simd.reduce_sum_u8s(a),
+				lanes.iter().map(|&x| x as u64).sum::<u64>()
+			);
+			assert_eq!(
+				simd.reduce_sum_u8s(simd.splat_u8s(u8::MAX)),
+				u8::MAX as u64 * S::U8_LANES as u64
+			);
+
+			check!(sum u32, i32, u64, i64);
+			check!(min_max u8, i8, u16, i16, u32, i32, u64, i64);
+		}
+	}
+
+	#[test]
+	fn test_int_reduce() {
+		check_all_simd!(check_int_reduce);
+	}
+
+	fn check_bit_count<S: Simd>(simd: S) {
+		macro_rules! check {
+			($($ty: ident),*) => {
+				paste! {$(
+					let mut a = simd.[<splat_ $ty s>](0);
+					// shift the random bits around so that the zero counts are spread out, including zero lanes
+					bytemuck::cast_slice_mut::<_, $ty>(core::slice::from_mut(&mut a))
+						.fill_with(|| (random::<$ty>() >> (random::<u32>() % $ty::BITS)).wrapping_shl(random::<u32>() % (2 * $ty::BITS)));
+					let lanes = bytemuck::cast_slice::<_, $ty>(core::slice::from_ref(&a));
+
+					let count_ones = simd.[<count_ones_ $ty s>](a);
+					let leading_zeros = simd.[<leading_zeros_ $ty s>](a);
+					let trailing_zeros = simd.[<trailing_zeros_ $ty s>](a);
+					for (i, &x) in lanes.iter().enumerate() {
+						assert_eq!(bytemuck::cast_slice::<_, $ty>(core::slice::from_ref(&count_ones))[i], x.count_ones() as $ty);
+						assert_eq!(bytemuck::cast_slice::<_, $ty>(core::slice::from_ref(&leading_zeros))[i], x.leading_zeros() as $ty);
+						assert_eq!(bytemuck::cast_slice::<_, $ty>(core::slice::from_ref(&trailing_zeros))[i], x.trailing_zeros() as $ty);
+					}
+				)*}
+			};
+		}
+
+		for _ in 0..16 {
+			check!(u8, u16, u32, u64);
+		}
+	}
+
+	#[test]
+	fn test_bit_count() {
+		check_all_simd!(check_bit_count);
+	}
+
+	fn check_saturating<S: Simd>(simd: S) {
+		macro_rules! check {
+			($func: ident, $ty: ident, $scalar: expr) => {
+				paste! {
+					let mut a = simd.[<splat_ $ty s>](0);
+					let mut b = simd.[<splat_ $ty s>](0);
+					bytemuck::cast_slice_mut::<_, $ty>(core::slice::from_mut(&mut a)).fill_with(random);
+					bytemuck::cast_slice_mut::<_, $ty>(core::slice::from_mut(&mut b)).fill_with(random);
+					let c = simd.[<$func _ $ty s>](a, b);
+					for ((&x, &y), &z) in zip(
+						zip(
+							bytemuck::cast_slice::<_, $ty>(core::slice::from_ref(&a)),
+							bytemuck::cast_slice::<_, $ty>(core::slice::from_ref(&b)),
+						),
+						bytemuck::cast_slice::<_, $ty>(core::slice::from_ref(&c)),
+					) {
+						let scalar: fn($ty, $ty) -> $ty = $scalar;
+						assert_eq!(scalar(x, y), z);
+					}
+				}
+			};
+		}
+
+		for _ in 0..16 {
+			check!(saturating_add, u8, u8::saturating_add);
+			check!(saturating_add, i8, i8::saturating_add);
+			check!(saturating_add, u16, u16::saturating_add);
+			check!(saturating_add, i16, i16::saturating_add);
+			check!(saturating_sub, u8, u8::saturating_sub);
+			check!(saturating_sub, i8, i8::saturating_sub);
+			check!(saturating_sub, u16, u16::saturating_sub);
+			check!(saturating_sub, i16, i16::saturating_sub);
+			check!(average, u8, |x, y| ((x as u32 + y as u32).div_ceil(2))
+				as u8);
+			check!(average, u16, |x, y| ((x as u32 + y as u32).div_ceil(2))
+				as u16);
+		}
+	}
+
+	#[test]
+	fn test_saturating() {
+		check_all_simd!(check_saturating);
+	}
+
+	fn check_table_lookup<S: Simd>(simd: S) {
+		for _ in 0..16 {
+			let mut table = simd.splat_u8s(0);
+			let mut idx = simd.splat_u8s(0);
+			bytemuck::cast_slice_mut::<_, u8>(core::slice::from_mut(&mut table)).fill_with(random);
+			for (i, x) in bytemuck::cast_slice_mut::<_, u8>(core::slice::from_mut(&mut idx))
+				.iter_mut()
+				.enumerate()
+			{
+				// mostly in range, with some indices past the end and some with the high bit set
+				*x = match i % 4 {
+					0 => random(),
+					1 => (random::<u8>() as usize % (2 * S::U8_LANES)) as u8,
+					_ => (random::<u8>() as usize % S::U8_LANES) as u8,
+				};
+			}
+
+			let out = simd.table_lookup_u8s(table, idx);
+			let table = bytemuck::cast_slice::<_, u8>(core::slice::from_ref(&table));
+			for (&i, &x) in zip(
+				bytemuck::cast_slice::<_, u8>(core::slice::from_ref(&idx)),
+				bytemuck::cast_slice::<_, u8>(core::slice::from_ref(&out)),
+			) {
+				assert_eq!(x, table.get(i as usize).copied().unwrap_or(0));
+			}
+		}
+	}
+
+	#[test]
+	fn test_table_lookup() {
+		check_all_simd!(check_table_lookup);
+	}
+
+	fn check_compress_expand<S: Simd>(simd: S) {
+		macro_rules! check {
+			($bits: literal, $ty: ident) => {
+				paste! {
+					let mut a = simd.[<splat_u $bits s>](0);
+					let mut b = simd.[<splat_u $bits s>](0);
+					let mut values = simd.[<splat_u $bits s>](0);
+					bytemuck::cast_slice_mut::<_, [<u $bits>]>(core::slice::from_mut(&mut a)).fill_with(random);
+					bytemuck::cast_slice_mut::<_, [<u $bits>]>(core::slice::from_mut(&mut b)).fill_with(random);
+					bytemuck::cast_slice_mut::<_, [<u $bits>]>(core::slice::from_mut(&mut values)).fill_with(random);
+
+					let mask = simd.[<greater_than_u $bits s>](a, b);
+					let enabled = zip(
+						bytemuck::cast_slice::<_, [<u $bits>]>(core::slice::from_ref(&a)),
+						bytemuck::cast_slice::<_, [<u $bits>]>(core::slice::from_ref(&b)),
+					)
+					.map(|(x, y)| x > y)
+					.collect::<Vec<_>>();
+					let lanes = bytemuck::cast_slice::<_, [<u $bits>]>(core::slice::from_ref(&values));
+					let compressed = zip(lanes, &enabled)
+						.filter(|(_, m)| **m)
+						.map(|(&x, _)| x)
+						.collect::<Vec<_>>();
+					let n = compressed.len();
+
+					// the lanes past the enabled ones must be left untouched
+					let init = (0..lanes.len()).map(|_| random::<[<u $bits>]>()).collect::<Vec<_>>();
+					let mut out = init.clone();
+					let count = simd.[<compress_store_ $ty s>](
+						mask,
+						bytemuck::cast(values),
+						bytemuck::cast_slice_mut(&mut out),
+					);
+					assert_eq!(count, n);
+					assert_eq!(out[..n], compressed);
+					assert_eq!(out[n..], init[n..]);
+
+					let expanded = simd.[<expand_load_ $ty s>](mask, bytemuck::cast_slice(&init[..n]));
+					let mut i = 0;
+					for (&x, &m) in zip(
+						bytemuck::cast_slice::<_, [<u $bits>]>(core::slice::from_ref(&expanded)),
+						&enabled,
+					) {
+						if m {
+							assert_eq!(x, init[i]);
+							i += 1;
+						} else {
+							assert_eq!(x, 0);
+						}
+					}
+				}
+			};
+		}
+
+		for _ in 0..16 {
+			check!(8, u8);
+			check!(8, i8);
+			check!(16, u16);
+			check!(16, i16);
+			check!(32, u32);
+			check!(32, i32);
+			check!(32, f32);
+			check!(64, u64);
+			check!(64, i64);
+			check!(64, f64);
+		}
+	}
+
+	#[test]
+	fn test_compress_expand() {
+		check_all_simd!(check_compress_expand);
+	}
+
+	fn check_mask_query<S: Simd>(simd: S) {
+		macro_rules! check {
+			($bits: literal) => {
+				paste! {
+					let lanes = S::[<U $bits _LANES>];
+					let all = u64::MAX >> (64 - lanes);
+
+					let mut a = simd.[<splat_u $bits s>](0);
+					let mut b = simd.[<splat_u $bits s>](0);
+					bytemuck::cast_slice_mut::<_, [<u $bits>]>(core::slice::from_mut(&mut a)).fill_with(random);
+					bytemuck::cast_slice_mut::<_, [<u $bits>]>(core::slice::from_mut(&mut b)).fill_with(random);
+					let mask = simd.[<greater_than_u $bits s>](a, b);
+					let expected = zip(
+						bytemuck::cast_slice::<_, [<u $bits>]>(core::slice::from_ref(&a)),
+						bytemuck::cast_slice::<_, [<u $bits>]>(core::slice::from_ref(&b)),
+					)
+					.enumerate()
+					.fold(0u64, |bits, (i, (x, y))| bits | (((x > y) as u64) << i));
+
+					assert_eq!(simd.[<to_bitmask_m $bits s>](mask), expected);
+					assert_eq!(simd.[<count_true_m $bits s>](mask), expected.count_ones() as usize);
+					assert_eq!(simd.[<any_m $bits s>](mask), expected != 0);
+					assert_eq!(simd.[<none_m $bits s>](mask), expected == 0);
+					assert_eq!(simd.[<all_m $bits s>](mask), expected == all);
+					assert_eq!(
+						simd.[<first_true_m $bits s>](mask),
+						(expected.trailing_zeros() as usize).min(lanes),
+					);
+
+					let bits = random::<u64>();
+					let mask = simd.[<from_bitmask_m $bits s>](bits);
+					assert_eq!(simd.[<to_bitmask_m $bits s>](mask), bits & all);
+
+					let mask = simd.[<from_bitmask_m $bits s>](!0);
+					assert!(simd.[<all_m $bits s>](mask));
+					assert!(simd.[<any_m $bits s>](mask));
+					assert_eq!(simd.[<count_true_m $bits s>](mask), lanes);
+					assert_eq!(simd.[<first_true_m $bits s>](mask), 0);
+
+					let mask = simd.[<from_bitmask_m $bits s>](0);
+					assert!(simd.[<none_m $bits s>](mask));
+					assert!(!simd.[<all_m $bits s>](mask));
+					assert_eq!(simd.[<first_true_m $bits s>](mask), lanes);
+				}
+			};
+		}
+
+		for _ in 0..16 {
+			check!(8);
+			check!(16);
+			check!(32);
+			check!(64);
+		}
+	}
+
+	#[test]
+	fn test_mask_query() {
+		check_all_simd!(check_mask_query);
+	}
+
+	fn check_select<S: Simd>(simd: S) {
+		macro_rules! check {
+			($bits: literal, $($ty: ident),*) => {$(
+				paste! {
+					let lanes = S::[<U $bits _LANES>];
+					let bits = random::<u64>();
+					let mask = simd.[<from_bitmask_m $bits s>](bits);
+
+					let mut a = simd.[<splat_ $ty s>](0 as $ty);
+					let mut b = simd.[<splat_ $ty s>](0 as $ty);
+					bytemuck::cast_slice_mut::<_, $ty>(core::slice::from_mut(&mut a)).fill_with(random);
+					bytemuck::cast_slice_mut::<_, $ty>(core::slice::from_mut(&mut b)).fill_with(random);
+
+					let c = simd.[<select_ $ty s>](mask, a, b);
+					let a = bytemuck::cast_slice::<_, $ty>(core::slice::from_ref(&a));
+					let b = bytemuck::cast_slice::<_, $ty>(core::slice::from_ref(&b));
+					let c = bytemuck::cast_slice::<_, $ty>(core::slice::from_ref(&c));
+					for i in 0..lanes {
+						assert_eq!(c[i], if (bits >> i) & 1 != 0 { a[i] } else { b[i] });
+					}
+				}
+			)*};
+		}
+
+		macro_rules! check_complex {
+			($bits: literal, $ty: ident, $real: ident) => {
+				paste! {
+					let bits = random::<u64>();
+					let mask = simd.[<from_bitmask_m $bits s>](bits);
+
+					let mut a = simd.[<splat_ $ty s>]($ty::new(0.0, 0.0));
+					let mut b = simd.[<splat_ $ty s>]($ty::new(0.0, 0.0));
+					bytemuck::cast_slice_mut::<_, $real>(core::slice::from_mut(&mut a)).fill_with(random);
+					bytemuck::cast_slice_mut::<_, $real>(core::slice::from_mut(&mut b)).fill_with(random);
+
+					let c = simd.[<select_ $ty s>](mask, a, b);
+					let a = bytemuck::cast_slice::<_, $real>(core::slice::from_ref(&a));
+					let b = bytemuck::cast_slice::<_, $real>(core::slice::from_ref(&b));
+					let c = bytemuck::cast_slice::<_, $real>(core::slice::from_ref(&c));
+					for i in 0..c.len() {
+						// `Scalar` uses a single mask lane for both parts of its complex number
+						let lane = i * S::[<$real:upper _LANES>] / c.len();
+						let expected = if (bits >> lane) & 1 != 0 { a[i] } else { b[i] };
+						assert_eq!(c[i].to_bits(), expected.to_bits());
+					}
+				}
+			};
+		}
+
+		for _ in 0..16 {
+			check!(8, u8, i8);
+			check!(16, u16, i16);
+			check!(32, u32, i32);
+			check!(64, u64, i64);
+			check_complex!(32, c32, f32);
+			check_complex!(64, c64, f64);
+		}
+	}
+
+	#[test]
+	fn test_select() {
+		check_all_simd!(check_select);
+	}
+
+	fn check_ieee_min_max<S: Simd>(simd: S) {
+		macro_rules! check {
+			($ty: ident) => {
+				paste! {
+					let values = [$ty::NAN, -0.0, 0.0, 1.0, -1.0, 2.5, $ty::INFINITY, $ty::NEG_INFINITY];
+					let pick = || values[random::<usize>() % values.len()];
+
+					let mut a = simd.[<splat_ $ty s>](0.0);
+					let mut b = simd.[<splat_ $ty s>](0.0);
+					bytemuck::cast_slice_mut::<_, $ty>(core::slice::from_mut(&mut a)).fill_with(pick);
+					bytemuck::cast_slice_mut::<_, $ty>(core::slice::from_mut(&mut b)).fill_with(pick);
+
+					let min = |x: $ty, y: $ty| {
+						if x.is_nan() || y.is_nan() {
+							$ty::NAN
+						} else if x == y {
+							if x.is_sign_negative() { x } else { y }
+						} else {
+							x.min(y)
+						}
+					};
+					let max = |x: $ty, y: $ty| {
+						if x.is_nan() || y.is_nan() {
+							$ty::NAN
+						} else if x == y {
+							if x.is_sign_positive() { x } else { y }
+						} else {
+							x.max(y)
+						}
+					};
+					let min_num = |x: $ty, y: $ty| {
+						if x.is_nan() {
+							y
+						} else if y.is_nan() {
+							x
+						} else {
+							min(x, y)
+						}
+					};
+					let max_num = |x: $ty, y: $ty| {
+						if x.is_nan() {
+							y
+						} else if y.is_nan() {
+							x
+						} else {
+							max(x, y)
+						}
+					};
+					let same = |x: $ty, y: $ty| (x.is_nan() && y.is_nan()) || x.to_bits() == y.to_bits();
+
+					let a_lanes = bytemuck::cast_slice::<_, $ty>(core::slice::from_ref(&a));
+					let b_lanes = bytemuck::cast_slice::<_, $ty>(core::slice::from_ref(&b));
+
+					for (f, target, reduce) in [
+						(
+							S::[<min_nan_propagating_ $ty s>] as fn(S, _, _) -> _,
+							&min as &dyn Fn($ty, $ty) -> $ty,
+							S::[<reduce_min_nan_propagating_ $ty s>] as fn(S, _) -> _,
+						),
+						(
+							S::[<max_nan_propagating_ $ty s>],
+							&max,
+							S::[<reduce_max_nan_propagating_ $ty s>],
+						),
+						(
+							S::[<minimum_number_ $ty s>],
+							&min_num,
+							S::[<reduce_minimum_number_ $ty s>],
+						),
+						(
+							S::[<maximum_number_ $ty s>],
+							&max_num,
+							S::[<reduce_maximum_number_ $ty s>],
+						),
+					] {
+						let c = f(simd, a, b);
+						let c = bytemuck::cast_slice::<_, $ty>(core::slice::from_ref(&c));
+						for i in 0..c.len() {
+							assert!(same(c[i], target(a_lanes[i], b_lanes[i])));
+						}
+
+						let expected = a_lanes[1..].iter().fold(a_lanes[0], |acc, &x| target(acc, x));
+						assert!(same(reduce(simd, a), expected));
+					}
+				}
+			};
+		}
+
+		for _ in 0..64 {
+			check!(f32);
+			check!(f64);
+		}
+	}
+
+	#[test]
+	fn test_ieee_min_max() {
+		check_all_simd!(check_ieee_min_max);
+	}
+
+	fn check_float_class<S: Simd>(simd: S) {
+		macro_rules! check {
+			($ty: ident, $bits: literal) => {
+				paste! {
+					let values = [
+						$ty::NAN,
+						-$ty::NAN,
+						0.0,
+						-0.0,
+						1.5,
+						-2.0,
+						$ty::INFINITY,
+						$ty::NEG_INFINITY,
+						$ty::MIN_POSITIVE,
+						-$ty::MIN_POSITIVE,
+						$ty::from_bits(1),
+						-$ty::from_bits(1),
+						$ty::from_bits($ty::MIN_POSITIVE.to_bits() - 1),
+						$ty::MAX,
+					];
+					let pick = || values[random::<usize>() % values.len()];
+
+					let mut a = simd.[<splat_ $ty s>](0.0);
+					let mut b = simd.[<splat_ $ty s>](0.0);
+					bytemuck::cast_slice_mut::<_, $ty>(core::slice::from_mut(&mut a)).fill_with(pick);
+					bytemuck::cast_slice_mut::<_, $ty>(core::slice::from_mut(&mut b)).fill_with(pick);
+					let a_lanes = bytemuck::cast_slice::<_, $ty>(core::slice::from_ref(&a));
+					let b_lanes = bytemuck::cast_slice::<_, $ty>(core::slice::from_ref(&b));
+
+					let bitmask = |f: fn($ty) -> bool| {
+						a_lanes
+							.iter()
+							.enumerate()
+							.fold(0u64, |bits, (i, &x)| bits | ((f(x) as u64) << i))
+					};
+
+					assert_eq!(simd.[<to_bitmask_m $bits s>](simd.[<is_nan_ $ty s>](a)), bitmask($ty::is_nan));
+					assert_eq!(simd.[<to_bitmask_m $bits s>](simd.[<is_not_nan_ $ty s>](a)), bitmask(|x| !x.is_nan()));
+					assert_eq!(simd.[<to_bitmask_m $bits s>](simd.[<is_finite_ $ty s>](a)), bitmask($ty::is_finite));
+					assert_eq!(simd.[<to_bitmask_m $bits s>](simd.[<is_infinite_ $ty s>](a)), bitmask($ty::is_infinite));
+					assert_eq!(simd.[<to_bitmask_m $bits s>](simd.[<is_subnormal_ $ty s>](a)), bitmask($ty::is_subnormal));
+					assert_eq!(simd.[<to_bitmask_m $bits s>](simd.[<sign_bit_ $ty s>](a)), bitmask($ty::is_sign_negative));
+
+					let c = simd.[<copysign_ $ty s>](a, b);
+					let c = bytemuck::cast_slice::<_, $ty>(core::slice::from_ref(&c));
+					for i in 0..c.len() {
+						assert_eq!(c[i].to_bits(), a_lanes[i].copysign(b_lanes[i]).to_bits());
+					}
+
+					let c = simd.[<signum_ $ty s>](a);
+					let c = bytemuck::cast_slice::<_, $ty>(core::slice::from_ref(&c));
+					for i in 0..c.len() {
+						let expected = a_lanes[i].signum();
+						assert!(c[i].to_bits() == expected.to_bits() || (c[i].is_nan() && expected.is_nan()));
+					}
+				}
+			};
+		}
+
+		for _ in 0..64 {
+			check!(f32, 32);
+			check!(f64, 64);
+		}
+	}
+
+	#[test]
+	fn test_float_class() {
+		check_all_simd!(check_float_class);
+	}
+
+	fn check_mul_sub<S: Simd>(simd: S) {
+		macro_rules! check {
+			($ty: ident) => {
+				paste! {
+					let mut a = simd.[<splat_ $ty s>](0.0);
+					let mut b = simd.[<splat_ $ty s>](0.0);
+					let mut c = simd.[<splat_ $ty s>](0.0);
+					for x in [&mut a, &mut b, &mut c] {
+						bytemuck::cast_slice_mut::<_, $ty>(core::slice::from_mut(x))
+							.fill_with(|| random::<$ty>() * 2.0 - 1.0);
+					}
+					let a_lanes = bytemuck::cast_slice::<_, $ty>(core::slice::from_ref(&a));
+					let b_lanes = bytemuck::cast_slice::<_, $ty>(core::slice::from_ref(&b));
+					let c_lanes = bytemuck::cast_slice::<_, $ty>(core::slice::from_ref(&c));
+
+					for (f, f_e, target) in [
+						(
+							S::[<mul_sub_ $ty s>] as fn(S, _, _, _) -> _,
+							S::[<mul_sub_e_ $ty s>] as fn(S, _, _, _) -> _,
+							(|_, x: $ty, y: $ty, z: $ty| (x, y, -z)) as fn(usize, $ty, $ty, $ty) -> ($ty, $ty, $ty),
+						),
+						(
+							S::[<negate_mul_sub_ $ty s>],
+							S::[<negate_mul_sub_e_ $ty s>],
+							|_, x, y, z| (-x, y, -z),
+						),
+						(
+							S::[<mul_addsub_ $ty s>],
+							S::[<mul_addsub_e_ $ty s>],
+							|i, x, y, z| (x, y, if i % 2 == 0 { z } else { -z }),
+						),
+						(
+							S::[<mul_subadd_ $ty s>],
+							S::[<mul_subadd_e_ $ty s>],
+							|i, x, y, z| (x, y, if i % 2 == 0 { -z } else { z }),
+						),
+					] {
+						let fused = f(simd, a, b, c);
+						let relaxed = f_e(simd, a, b, c);
+						let fused = bytemuck::cast_slice::<_, $ty>(core::slice::from_ref(&fused));
+						let relaxed = bytemuck::cast_slice::<_, $ty>(core::slice::from_ref(&relaxed));
+
+						for i in 0..fused.len() {
+							let (x, y, z) = target(i, a_lanes[i], b_lanes[i], c_lanes[i]);
+							assert_eq!(fused[i], x.mul_add(y, z));
+							assert!(relaxed[i] == x.mul_add(y, z) || relaxed[i] == x * y + z);
+						}
+					}
+				}
+			};
+		}
+
+		for _ in 0..16 {
+			check!(f32);
+			check!(f64);
+		}
+	}
+
+	#[test]
+	fn test_mul_sub() {
+		check_all_simd!(check_mul_sub);
+	}
+
+	fn check_mul_high<S: Simd>(simd: S) {
+		macro_rules! check {
+			($ty: ident, $func: ident, $target: expr) => {
+				paste! {
+					let mut a = simd.[<splat_ $ty s>](0);
+					let mut b = simd.[<splat_ $ty s>](0);
+					bytemuck::cast_slice_mut::<_, $ty>(core::slice::from_mut(&mut a)).fill_with(random);
+					bytemuck::cast_slice_mut::<_, $ty>(core::slice::from_mut(&mut b)).fill_with(random);
+					let c = simd.[<$func _ $ty s>](a, b);
+
+					let a = bytemuck::cast_slice::<_, $ty>(core::slice::from_ref(&a));
+					let b = bytemuck::cast_slice::<_, $ty>(core::slice::from_ref(&b));
+					let c = bytemuck::cast_slice::<_, $ty>(core::slice::from_ref(&c));
+					for i in 0..c.len() {
+						assert_eq!(c[i], $target(a[i], b[i]));
+					}
+				}
+			};
+		}
+
+		for _ in 0..16 {
+			check!(u8, mul, u8::wrapping_mul);
+			check!(i8, mul, i8::wrapping_mul);
+			check!(u16, mul_high, |x, y| ((x as u32 * y as u32) >> 16) as u16);
+			check!(i16, mul_high, |x, y| ((x as i32 * y as i32) >> 16) as i16);
+			check!(u64, mul_high, |x, y| ((x as u128 * y as u128) >> 64) as u64);
+
+			let mut a = simd.splat_u64s(0);
+			let mut b = simd.splat_u64s(0);
+			bytemuck::cast_slice_mut::<_, u64>(core::slice::from_mut(&mut a)).fill_with(random);
+			bytemuck::cast_slice_mut::<_, u64>(core::slice::from_mut(&mut b)).fill_with(random);
+			// make sure the carries out of the middle partial products are exercised
+			bytemuck::cast_slice_mut::<_, u64>(core::slice::from_mut(&mut a))[0] = u64::MAX;
+			bytemuck::cast_slice_mut::<_, u64>(core::slice::from_mut(&mut b))[0] = u64::MAX;
+			let (lo, hi) = simd.widening_mul_u64s(a, b);
+
+			let a = bytemuck::cast_slice::<_, u64>(core::slice::from_ref(&a));
+			let b = bytemuck::cast_slice::<_, u64>(core::slice::from_ref(&b));
+			let lo = bytemuck::cast_slice::<_, u64>(core::slice::from_ref(&lo));
+			let hi = bytemuck::cast_slice::<_, u64>(core::slice::from_ref(&hi));
+			for i in 0..a.len() {
+				let c = a[i] as u128 * b[i] as u128;
+				assert_eq!(lo[i], c as u64);
+				assert_eq!(hi[i], (c >> 64) as u64);
+			}
+		}
+	}
+
+	#[test]
+	fn test_mul_high() {
+		check_all_simd!(check_mul_high);
+	}
+
+	fn check_fixed_point<S: Simd>(simd: S) {
+		macro_rules! check {
+			($ty: ident, $wide: ident, $q: literal) => {
+				paste! {
+					let pick = || match random::<u8>() % 4 {
+						0 => [$ty::MIN, $ty::MAX, -1, 0, 1][random::<usize>() % 5],
+						_ => random::<$ty>(),
+					};
+					let mut a = simd.[<splat_ $ty s>](0);
+					let mut b = simd.[<splat_ $ty s>](0);
+					bytemuck::cast_slice_mut::<_, $ty>(core::slice::from_mut(&mut a)).fill_with(pick);
+					bytemuck::cast_slice_mut::<_, $ty>(core::slice::from_mut(&mut b)).fill_with(pick);
+					bytemuck::cast_slice_mut::<_, $ty>(core::slice::from_mut(&mut a))[0] = $ty::MIN;
+					bytemuck::cast_slice_mut::<_, $ty>(core::slice::from_mut(&mut b))[0] = $ty::MIN;
+
+					let c = simd.[<mul_round_q $q _ $ty s>](a, b);
+					let a_lanes = bytemuck::cast_slice::<_, $ty>(core::slice::from_ref(&a));
+					let b_lanes = bytemuck::cast_slice::<_, $ty>(core::slice::from_ref(&b));
+					let c = bytemuck::cast_slice::<_, $ty>(core::slice::from_ref(&c));
+					for i in 0..c.len() {
+						let expected = (a_lanes[i] as $wide * b_lanes[i] as $wide + (1 << ($q - 1))) >> $q;
+						assert_eq!(c[i], expected.clamp($ty::MIN as $wide, $ty::MAX as $wide) as $ty);
+					}
+
+					let amount = random::<u32>() % ($ty::BITS + 4);
+					let c = simd.[<saturating_shl_ $ty s>](a, amount);
+					let c = bytemuck::cast_slice::<_, $ty>(core::slice::from_ref(&c));
+					for i in 0..c.len() {
+						let x = a_lanes[i];
+						let expected = if x == 0 {
+							0
+						} else if amount >= $ty::BITS {
+							if x < 0 { $ty::MIN } else { $ty::MAX }
+						} else {
+							((x as $wide) << amount).clamp($ty::MIN as $wide, $ty::MAX as $wide) as $ty
+						};
+						assert_eq!(c[i], expected);
+					}
+				}
+			};
+		}
+
+		for _ in 0..64 {
+			check!(i16, i64, 15);
+			check!(i32, i64, 31);
+		}
+	}
+
+	#[test]
+	fn test_fixed_point() {
+		check_all_simd!(check_fixed_point);
+	}
+
+	fn check_recip_approx<S: Simd>(simd: S) {
+		let check =
+			|f: &dyn Fn(S::f32s) -> S::f32s, target: fn(f32) -> f32, tol: f32, x: &[f32]| {
+				let mut a = simd.splat_f32s(1.0);
+				for x in x.chunks(size_of::<S::f32s>() / size_of::<f32>()) {
+					bytemuck::cast_slice_mut::<_, f32>(core::slice::from_mut(&mut a))[..x.len()]
+						.copy_from_slice(x);
+
+					let b = f(a);
+					let a = bytemuck::cast_slice::<_, f32>(core::slice::from_ref(&a));
+					let b = bytemuck::cast_slice::<_, f32>(core::slice::from_ref(&b));
+					for i in 0..a.len() {
+						let expected = target(a[i]);
+						if expected.is_finite() && expected != 0.0 {
+							// subnormal results may be off by the smallest subnormal
+							let err = (b[i] - expected).abs();
+							assert!(err <= tol * expected.abs() + f32::from_bits(1));
+						} else {
+							assert_eq!(b[i].to_bits(), expected.to_bits());
+						}
+					}
+				}
+			};
+
+		let recip = |x: f32| 1.0 / x;
+		let rsqrt = |x: f32| 1.0 / x.sqrt();
+		let approx = 2.0f32.powi(-8);
+		let refined = 2.0f32.powi(-21);
+
+		for _ in 0..256 {
+			let x: [f32; 16] = core::array::from_fn(|_| {
+				let m = 1.0 + random::<f32>();
+				let e = (random::<u32>() % 121) as i32 - 60;
+				let m = if random::<bool>() { -m } else { m };
+				m * 2.0f32.powi(e)
+			});
+			let abs = x.map(f32::abs);
+
+			check(&|a| simd.recip_approx_f32s(a), recip, approx, &x);
+			check(&|a| simd.rsqrt_approx_f32s(a), rsqrt, approx, &abs);
+			check(&|a| simd.recip_approx_refined_f32s(a), recip, refined, &x);
+			check(&|a| simd.rsqrt_approx_refined_f32s(a), rsqrt, refined, &abs);
+
+			// the refined versions also handle subnormal inputs and results
+			let x: [f32; 16] = core::array::from_fn(|_| {
+				f32::from_bits((random::<u32>() % 0x7f800000) | (random::<u32>() & 0x80000000))
+			});
+			let abs = x.map(f32::abs);
+
+			check(&|a| simd.recip_approx_refined_f32s(a), recip, refined, &x);
+			check(&|a| simd.rsqrt_approx_refined_f32s(a), rsqrt, refined, &abs);
+		}
+
+		let special = [
+			0.0,
+			-0.0,
+			f32::INFINITY,
+			1e-38,
+			1e-40,
+			f32::MIN_POSITIVE,
+			f32::from_bits(1),
+			8.5e37,
+			1e38,
+			f32::MAX,
+			f32::NEG_INFINITY,
+		];
+		check(
+			&|a| simd.recip_approx_refined_f32s(a),
+			recip,
+			refined,
+			&special,
+		);
+		check(
+			&|a| simd.recip_approx_refined_f32s(a),
+			recip,
+			refined,
+			&special.map(|x| -x),
+		);
+		check(
+			&|a| simd.rsqrt_approx_refined_f32s(a),
+			rsqrt,
+			refined,
+			&special[..special.len() - 1],
+		);
+	}
+
+	#[test]
+	fn test_recip_approx() {
+		check_all_simd!(check_recip_approx);
+	}
+
+	fn check_divisor<S: Simd>(simd: S) {
+		macro_rules! check {
+			($ty: ident, $divisors: expr) => {
+				paste! {
+					let pick = || match random::<u8>() % 4 {
+						0 => [$ty::MIN, $ty::MAX, 0, 1, $ty::MAX / 2][random::<usize>() % 5],
+						1 => random::<$ty>() >> (random::<u32>() % $ty::BITS),
+						_ => random::<$ty>(),
+					};
+					for d in $divisors.into_iter().chain((0..32).map(|_| pick())).filter(|&d| d != 0) {
+						let divisor = Divisor::<$ty>::new(d);
+						assert_eq!(divisor.divisor(), d);
+
+						let mut a = simd.[<splat_ $ty s>](0);
+						bytemuck::cast_slice_mut::<_, $ty>(core::slice::from_mut(&mut a)).fill_with(pick);
+
+						let q = simd.[<div_ $ty s>](a, &divisor);
+						let r = simd.[<rem_ $ty s>](a, &divisor);
+						let a = bytemuck::cast_slice::<_, $ty>(core::slice::from_ref(&a));
+						let q = bytemuck::cast_slice::<_, $ty>(core::slice::from_ref(&q));
+						let r = bytemuck::cast_slice::<_, $ty>(core::slice::from_ref(&r));
+						for i in 0..a.len() {
+							assert_eq!(q[i], a[i].wrapping_div(d));
+							assert_eq!(r[i], a[i].wrapping_rem(d));
+						}
+					}
+				}
+			};
+		}
+
+		for _ in 0..16 {
+			check!(
+				u32,
+				[
+					1,
+					2,
+					3,
+					7,
+					10,
+					641,
+					1 << 31,
+					(1 << 31) + 1,
+					u32::MAX - 1,
+					u32::MAX
+				]
+			);
+			check!(
+				u64,
+				[
+					1,
+					2,
+					3,
+					7,
+					10,
+					641,
+					1 << 63,
+					(1 << 63) + 1,
+					u64::MAX - 1,
+					u64::MAX
+				]
+			);
+			check!(
+				i32,
+				[
+					1,
+					-1,
+					2,
+					-2,
+					3,
+					-3,
+					7,
+					-7,
+					641,
+					i32::MIN,
+					i32::MIN + 1,
+					i32::MAX
+				]
+			);
+			check!(
+				i64,
+				[
+					1,
+					-1,
+					2,
+					-2,
+					3,
+					-3,
+					7,
+					-7,
+					641,
+					i64::MIN,
+					i64::MIN + 1,
+					i64::MAX
+				]
+			);
+		}
+	}
+
+	#[test]
+	fn test_divisor() {
+		check_all_simd!(check_divisor);
+	}
+
+	fn f16_to_f32_ref(h: u16) -> u32 {
+		let sign = ((h & 0x8000) as u32) << 16;
+		let exp = ((h >> 10) & 0x1f) as i32;
+		let mantissa = (h & 0x3ff) as u32;
+		let abs = match exp {
+			0 => (mantissa as f32 * (-24.0f32).exp2()).to_bits(),
+			31 if mantissa != 0 => 0x7fc00000 | (mantissa << 13),
+			31 => 0x7f800000,
+			_ => (((exp + 127 - 15) as u32) << 23) | (mantissa << 13),
+		};
+		sign | abs
+	}
+
+	fn f32_to_f16_ref(x: u32) -> u16 {
+		let sign = ((x >> 16) & 0x8000) as u16;
+		let f = f32::from_bits(x);
+		if f.is_nan() {
+			return sign | 0x7e00 | ((x >> 13) & 0x3ff) as u16;
+		}
+		let v = f.abs() as f64;
+		if v >= 65520.0 {
+			return sign | 0x7c00;
+		}
+		if v < (-14.0f64).exp2() {
+			return sign | (v * 24.0f64.exp2()).round_ties_even() as u16;
+		}
+		let exp = ((v.to_bits() >> 52) as i32) - 1023;
+		let mantissa = (v * ((10 - exp) as f64).exp2()).round_ties_even() as u32;
+		let (exp, mantissa) = if mantissa == 2048 {
+			(exp + 1, 1024)
+		} else {
+			(exp, mantissa)
+		};
+		sign | (((exp + 15) as u16) << 10) | (mantissa - 1024) as u16
+	}
+
+	fn f32_to_bf16_ref(x: u32) -> u16 {
+		if f32::from_bits(x).is_nan() {
+			return ((x >> 16) | 0x40) as u16;
+		}
+		let low = x & 0xffff;
+		let round_up = low > 0x8000 || (low == 0x8000 && (x >> 16) & 1 == 1);
+		((x >> 16) + round_up as u32) as u16
+	}
+
+	fn check_f16<S: Simd>(simd: S) {
+		let mut lanes = (0..=u16::MAX).collect::<Vec<_>>();
+		lanes.extend((0..4096).map(|_| random::<u16>()));
+
+		for chunk in lanes.chunks(S::F32_LANES) {
+			let mut a = bytemuck::Zeroable::zeroed();
+			let mut b = bytemuck::Zeroable::zeroed();
+			bytemuck::cast_slice_mut::<S::f16s, u16>(core::slice::from_mut(&mut a))
+				.iter_mut()
+				.zip(chunk)
+				.for_each(|(x, &y)| *x = y);
+			bytemuck::cast_slice_mut::<S::bf16s, u16>(core::slice::from_mut(&mut b))
+				.iter_mut()
+				.zip(chunk)
+				.for_each(|(x, &y)| *x = y);
+
+			let (a_lo, a_hi) = simd.convert_f16s_f32s(a);
+			let (b_lo, b_hi) = simd.convert_bf16s_f32s(b);
+			let a_out = [a_lo, a_hi];
+			let b_out = [b_lo, b_hi];
+			for ((&h, &x), &y) in zip(
+				zip(
+					bytemuck::cast_slice::<S::f16s, u16>(core::slice::from_ref(&a)),
+					bytemuck::cast_slice::<S::f32s, u32>(&a_out),
+				),
+				bytemuck::cast_slice::<S::f32s, u32>(&b_out),
+			) {
+				assert_eq!(x, f16_to_f32_ref(h));
+				assert_eq!(y, (h as u32) << 16);
+			}
+		}
+
+		let mut lanes = (0..1 << 16).map(|_| random::<u32>()).collect::<Vec<_>>();
+		for bits in 0..(1u32 << 14) {
+			// values near half precision rounding boundaries
+			lanes.push((bits << 13) + 0x1000);
+			lanes.push((bits << 13) + 0x0fff);
+			lanes.push((bits << 13) + 0x1001);
+			lanes.push((bits << 16) + 0x8000);
+			lanes.push((bits << 17) + 0x8000);
+		}
+		lanes.extend([
+			65504.0f32.to_bits(),
+			65519.99f32.to_bits(),
+			65520.0f32.to_bits(),
+			(-24.0f32).exp2().to_bits(),
+			(-25.0f32).exp2().to_bits(),
+			(1.5 * (-25.0f32).exp2()).to_bits(),
+			(3.0 * (-25.0f32).exp2()).to_bits(),
+			f32::MIN_POSITIVE.to_bits(),
+			1,
+			f32::INFINITY.to_bits(),
+			f32::NEG_INFINITY.to_bits(),
+			0x7f800001,
+			0xffc00001,
+		]);
+
+		for chunk in lanes.chunks(2 * S::F32_LANES) {
+			let mut x: [S::f32s; 2] = bytemuck::Zeroable::zeroed();
+			bytemuck::cast_slice_mut::<S::f32s, u32>(&mut x)
+				.iter_mut()
+				.zip(chunk)
+				.for_each(|(x, &y)| *x = y);
+
+			let a = simd.convert_f32s_f16s(x[0], x[1]);
+			let b = simd.convert_f32s_bf16s(x[0], x[1]);
+			let lanes = if S::F32_LANES == 1 {
+				&chunk[..1]
+			} else {
+				chunk
+			};
+			for ((&x, &h), &b) in zip(
+				zip(
+					lanes,
+					bytemuck::cast_slice::<S::f16s, u16>(core::slice::from_ref(&a)),
+				),
+				bytemuck::cast_slice::<S::bf16s, u16>(core::slice::from_ref(&b)),
+			) {
+				assert_eq!(h, f32_to_f16_ref(x), "{x:#x}");
+				assert_eq!(b, f32_to_bf16_ref(x), "{x:#x}");
+			}
+		}
+
+		assert_eq!(f16::from_f32(1.0).to_bits(), 0x3c00);
+		assert_eq!(f16::from_bits(0xc000).to_f32(), -2.0);
+		assert_eq!(crate::bf16::from_f32(1.0).to_bits(), 0x3f80);
+		assert_eq!(crate::bf16::from_bits(0xc000).to_f32(), -2.0);
+		assert_eq!(f16::from_f32(-2.0), f16::from_bits(0xc000));
+		assert_eq!(crate::bf16::from_f32(1.0), crate::bf16::from_bits(0x3f80));
+		assert_eq!(f16::from_bits(0x8000), f16::from_bits(0x0000));
+		assert_ne!(f16::from_bits(0x7e00), f16::from_bits(0x7e00));
+	}
+
+	#[test]
+	fn test_f16() {
+		check_all_simd!(check_f16);
+	}
+
+	fn check_gather_scatter<S: Simd>(simd: S) {
+		macro_rules! check {
+			($bits: literal, $ty: ident) => {
+				paste! {
+					let table: [$ty; 64] = core::array::from_fn(|_| random::<[<u $bits>]>() as $ty);
+					let mut idx = simd.[<splat_u $bits s>](0);
+					bytemuck::cast_slice_mut::<_, [<u $bits>]>(core::slice::from_mut(&mut idx)).fill_with(|| random::<[<u $bits>]>() % 64);
+					let idx_lanes = bytemuck::cast_slice::<_, [<u $bits>]>(core::slice::from_ref(&idx));
+					let mut r = simd.[<splat_u $bits s>](0);
+					bytemuck::cast_slice_mut::<_, [<u $bits>]>(core::slice::from_mut(&mut r)).fill_with(random);
+					let mask = simd.[<equal_u $bits s>](simd.[<and_u $bits s>](r, simd.[<splat_u $bits s>](1)), simd.[<splat_u $bits s>](0));
+					let enabled = simd.[<select_u $bits s>](mask, simd.[<splat_u $bits s>](1), simd.[<splat_u $bits s>](0));
+					let enabled = bytemuck::cast_slice::<_, [<u $bits>]>(core::slice::from_ref(&enabled));
+
+					let x = simd.[<gather_ $ty s>](&table, idx);
+					for (&x, &i) in zip(bytemuck::cast_slice::<_, $ty>(core::slice::from_ref(&x)), idx_lanes) {
+						assert_eq!(x.to_ne_bytes(), table[i as usize].to_ne_bytes());
+					}
+
+					let x = simd.[<mask_gather_ $ty s>](mask.into(), &table, idx);
+					for ((&x, &i), &m) in zip(zip(bytemuck::cast_slice::<_, $ty>(core::slice::from_ref(&x)), idx_lanes), enabled) {
+						let expected = if m != 0 { table[i as usize] } else { 0 as $ty };
+						assert_eq!(x.to_ne_bytes(), expected.to_ne_bytes());
+					}
+
+					let mut values = simd.[<splat_ $ty s>](0 as $ty);
+					bytemuck::cast_slice_mut::<_, $ty>(core::slice::from_mut(&mut values)).fill_with(|| random::<[<u $bits>]>() as $ty);
+					let values_lanes = bytemuck::cast_slice::<_, $ty>(core::slice::from_ref(&values));
+
+					let mut target = table;
+					let mut expected = table;
+					simd.[<scatter_ $ty s>](&mut target, idx, values);
+					for (&x, &i) in zip(values_lanes, idx_lanes) {
+						expected[i as usize] = x;
+					}
+					assert_eq!(bytemuck::cast::<_, [[<u $bits>]; 64]>(target), bytemuck::cast::<_, [[<u $bits>]; 64]>(expected));
+
+					let mut target = table;
+					let mut expected = table;
+					simd.[<mask_scatter_ $ty s>](mask.into(), &mut target, idx, values);
+					for ((&x, &i), &m) in zip(zip(values_lanes, idx_lanes), enabled) {
+						if m != 0 {
+							expected[i as usize] = x;
+						}
+					}
+					assert_eq!(bytemuck::cast::<_, [[<u $bits>]; 64]>(target), bytemuck::cast::<_, [[<u $bits>]; 64]>(expected));
+				}
+			};
+		}
+
+		for _ in 0..16 {
+			check!(32, u32);
+			check!(32, i32);
+			check!(32, f32);
+			check!(64, u64);
+			check!(64, i64);
+			check!(64, f64);
+		}
+	}
+
+	#[test]
+	fn test_gather_scatter() {
+		check_all_simd!(check_gather_scatter);
+	}
+
+	#[test]
+	#[should_panic]
+	fn test_gather_out_of_bounds() {
+		let table = [0u32; 4];
+		Scalar.gather_u32s(&table, 4);
+	}
 
+	#[cfg(target_arch = "x86_64")]
 	#[test]
 	fn test_interleave() {
 		if let Some(simd) = x86::V3::try_new() {
@@ -0,0 +1,1209 @@
+//! Vectorized elementary functions, generic over [`Simd`].
+//!
+//! Every function is written only in terms of the [`Simd`] trait, so it is available on all
+//! backends. The documented error bounds are in units in the last place (ULP), apply to finite
+//! inputs, and hold on every backend. Special values (infinities, NaNs, signed zeros) follow the
+//! conventions of the C standard library.
+
+// the polynomial coefficients are kept as they were published
+#![allow(clippy::excessive_precision)]
+
+use crate::Simd;
+
+// 1.5 * 2^23 and 1.5 * 2^52. adding these to a float of small enough magnitude rounds it to an
+// integer, which is then stored in the low bits of the mantissa.
+const ROUND_F32: f32 = 12582912.0;
+const ROUND_F64: f64 = 6755399441055744.0;
+
+#[inline(always)]
+fn polynomial_f32s<S: Simd>(simd: S, x: S::f32s, coeffs: &[f32]) -> S::f32s {
+	let mut acc = simd.splat_f32s(coeffs[0]);
+	for &c in &coeffs[1..] {
+		acc = simd.mul_add_e_f32s(acc, x, simd.splat_f32s(c));
+	}
+	acc
+}
+
+#[inline(always)]
+fn polynomial_f64s<S: Simd>(simd: S, x: S::f64s, coeffs: &[f64]) -> S::f64s {
+	let mut acc = simd.splat_f64s(coeffs[0]);
+	for &c in &coeffs[1..] {
+		acc = simd.mul_add_e_f64s(acc, x, simd.splat_f64s(c));
+	}
+	acc
+}
+
+#[inline(always)]
+fn neg_f32s<S: Simd>(simd: S, x: S::f32s) -> S::f32s {
+	simd.xor_f32s(x, simd.splat_f32s(-0.0))
+}
+
+#[inline(always)]
+fn neg_f64s<S: Simd>(simd: S, x: S::f64s) -> S::f64s {
+	simd.xor_f64s(x, simd.splat_f64s(-0.0))
+}
+
+/// Returns `p * 2^n` for an integer `n` in `[-150, 129]`, where `p * 2^n` does not underflow
+/// before the last multiplication.
+#[inline(always)]
+fn scale_f32s<S: Simd>(simd: S, p: S::f32s, n: S::i32s) -> S::f32s {
+	// split the exponent in two so that both factors are normal
+	let n0 = simd.sra_const_i32s::<1>(n);
+	let n1 = simd.sub_i32s(n, n0);
+	let bias = simd.splat_i32s(127);
+	let s0 = simd.transmute_f32s_u32s(
+		simd.shl_const_u32s::<23>(simd.transmute_u32s_i32s(simd.add_i32s(n0, bias))),
+	);
+	let s1 = simd.transmute_f32s_u32s(
+		simd.shl_const_u32s::<23>(simd.transmute_u32s_i32s(simd.add_i32s(n1, bias))),
+	);
+	simd.mul_f32s(simd.mul_f32s(p, s0), s1)
+}
+
+/// Returns `p * 2^n` for an integer `n` in `[-1077, 1026]`.
+#[inline(always)]
+fn scale_f64s<S: Simd>(simd: S, p: S::f64s, n: S::i64s) -> S::f64s {
+	let n0 = simd.sra_const_i64s::<1>(n);
+	let n1 = simd.sub_i64s(n, n0);
+	let bias = simd.splat_i64s(1023);
+	let s0 = simd.transmute_f64s_u64s(
+		simd.shl_const_u64s::<52>(simd.transmute_u64s_i64s(simd.add_i64s(n0, bias))),
+	);
+	let s1 = simd.transmute_f64s_u64s(
+		simd.shl_const_u64s::<52>(simd.transmute_u64s_i64s(simd.add_i64s(n1, bias))),
+	);
+	simd.mul_f64s(simd.mul_f64s(p, s0), s1)
+}
+
+/// Computes `e^x` for each lane of `x`.
+///
+/// Maximum error: 1.5 ULP.
+#[inline(always)]
+pub fn exp_f32s<S: Simd>(simd: S, x: S::f32s) -> S::f32s {
+	const LN2_HI: f32 = 0.693359375;
+	const LN2_LO: f32 = -2.12194440e-4;
+
+	// outside of this range, the result is either zero or infinity
+	let a = simd.min_f32s(
+		simd.max_f32s(x, simd.splat_f32s(-104.0)),
+		simd.splat_f32s(89.0),
+	);
+
+	let round = simd.splat_f32s(ROUND_F32);
+	let t = simd.mul_add_e_f32s(a, simd.splat_f32s(core::f32::consts::LOG2_E), round);
+	let n = simd.sub_f32s(t, round);
+	let r = simd.mul_add_f32s(n, simd.splat_f32s(-LN2_HI), a);
+	let r = simd.mul_add_f32s(n, simd.splat_f32s(-LN2_LO), r);
+
+	let p = polynomial_f32s(
+		simd,
+		r,
+		&[
+			1.9875691500e-4,
+			1.3981999507e-3,
+			8.3334519073e-3,
+			4.1665795894e-2,
+			1.6666665459e-1,
+			5.0000001201e-1,
+		],
+	);
+	let p = simd.mul_add_e_f32s(
+		p,
+		simd.mul_f32s(r, r),
+		simd.add_f32s(r, simd.splat_f32s(1.0)),
+	);
+
+	let n = simd.transmute_i32s_u32s(
+		simd.sub_u32s(simd.transmute_u32s_f32s(t), simd.transmute_u32s_f32s(round)),
+	);
+	let y = scale_f32s(simd, p, n);
+
+	simd.select_f32s(simd.equal_f32s(x, x), y, x)
+}
+
+#[inline(always)]
+fn exp_f64s_impl<S: Simd>(simd: S, x: S::f64s, x_lo: S::f64s) -> S::f64s {
+	const LN2_HI: f64 = 6.93147180369123816490e-01;
+	const LN2_LO: f64 = 1.90821492927058770002e-10;
+
+	// outside of this range, the result is either zero or infinity
+	let a = simd.min_f64s(
+		simd.max_f64s(x, simd.splat_f64s(-746.0)),
+		simd.splat_f64s(710.0),
+	);
+
+	let round = simd.splat_f64s(ROUND_F64);
+	let t = simd.mul_add_e_f64s(a, simd.splat_f64s(core::f64::consts::LOG2_E), round);
+	let n = simd.sub_f64s(t, round);
+
+	let hi = simd.mul_add_f64s(n, simd.splat_f64s(-LN2_HI), a);
+	let lo = simd.mul_add_f64s(n, simd.splat_f64s(LN2_LO), neg_f64s(simd, x_lo));
+	let r = simd.sub_f64s(hi, lo);
+
+	let z = simd.mul_f64s(r, r);
+	let c = simd.mul_f64s(
+		z,
+		polynomial_f64s(
+			simd,
+			z,
+			&[
+				4.13813679705723846039e-08,
+				-1.65339022054652515390e-06,
+				6.61375632143793436117e-05,
+				-2.77777777770155933842e-03,
+				1.66666666666666019037e-01,
+			],
+		),
+	);
+	let c = simd.sub_f64s(r, c);
+	// 1 - ((lo - r * c / (2 - c)) - hi)
+	let p = simd.div_f64s(simd.mul_f64s(r, c), simd.sub_f64s(simd.splat_f64s(2.0), c));
+	let p = simd.sub_f64s(
+		simd.splat_f64s(1.0),
+		simd.sub_f64s(simd.sub_f64s(lo, p), hi),
+	);
+
+	let n = simd.transmute_i64s_u64s(
+		simd.sub_u64s(simd.transmute_u64s_f64s(t), simd.transmute_u64s_f64s(round)),
+	);
+	let y = scale_f64s(simd, p, n);
+
+	simd.select_f64s(simd.equal_f64s(x, x), y, x)
+}
+
+/// Computes `e^x` for each lane of `x`.
+///
+/// Maximum error: 1 ULP.
+#[inline(always)]
+pub fn exp_f64s<S: Simd>(simd: S, x: S::f64s) -> S::f64s {
+	exp_f64s_impl(simd, x, simd.splat_f64s(0.0))
+}
+
+/// Splits positive finite lanes of `x` into `k` and `f` such that `x = 2^k * (1 + f)`, with
+/// `1 + f` in `[sqrt(2)/2, sqrt(2))`.
+#[inline(always)]
+fn log_reduce_f32s<S: Simd>(simd: S, x: S::f32s) -> (S::f32s, S::f32s) {
+	let subnormal = simd.less_than_f32s(x, simd.splat_f32s(f32::MIN_POSITIVE));
+	let x = simd.select_f32s(subnormal, simd.mul_f32s(x, simd.splat_f32s(33554432.0)), x);
+
+	// shift the mantissa so that the split happens at sqrt(2) instead of 2
+	let ix = simd.add_u32s(
+		simd.transmute_u32s_f32s(x),
+		simd.splat_u32s(0x3f800000 - 0x3f3504f3),
+	);
+	let k = simd.shr_const_u32s::<23>(ix);
+	let ix = simd.add_u32s(
+		simd.and_u32s(ix, simd.splat_u32s(0x007fffff)),
+		simd.splat_u32s(0x3f3504f3),
+	);
+	let f = simd.sub_f32s(simd.transmute_f32s_u32s(ix), simd.splat_f32s(1.0));
+
+	let k = simd.sub_f32s(
+		simd.transmute_f32s_u32s(simd.add_u32s(k, simd.splat_u32s(ROUND_F32.to_bits()))),
+		simd.splat_f32s(ROUND_F32 + 127.0),
+	);
+	let k = simd.sub_f32s(
+		k,
+		simd.select_f32s(subnormal, simd.splat_f32s(25.0), simd.splat_f32s(0.0)),
+	);
+	(k, f)
+}
+
+/// Splits positive finite lanes of `x` into `k` and `f` such that `x = 2^k * (1 + f)`, with
+/// `1 + f` in `[sqrt(2)/2, sqrt(2))`.
+#[inline(always)]
+fn log_reduce_f64s<S: Simd>(simd: S, x: S::f64s) -> (S::f64s, S::f64s) {
+	let subnormal = simd.less_than_f64s(x, simd.splat_f64s(f64::MIN_POSITIVE));
+	let x = simd.select_f64s(
+		subnormal,
+		simd.mul_f64s(x, simd.splat_f64s(18014398509481984.0)),
+		x,
+	);
+
+	let ix = simd.add_u64s(
+		simd.transmute_u64s_f64s(x),
+		simd.splat_u64s((0x3ff00000 - 0x3fe6a09e) << 32),
+	);
+	let k = simd.shr_const_u64s::<52>(ix);
+	let ix = simd.add_u64s(
+		simd.and_u64s(ix, simd.splat_u64s(0x000fffff_ffffffff)),
+		simd.splat_u64s(0x3fe6a09e << 32),
+	);
+	let f = simd.sub_f64s(simd.transmute_f64s_u64s(ix), simd.splat_f64s(1.0));
+
+	let k = simd.sub_f64s(
+		simd.transmute_f64s_u64s(simd.add_u64s(k, simd.splat_u64s(ROUND_F64.to_bits()))),
+		simd.splat_f64s(ROUND_F64 + 1023.0),
+	);
+	let k = simd.sub_f64s(
+		k,
+		simd.select_f64s(subnormal, simd.splat_f64s(54.0), simd.splat_f64s(0.0)),
+	);
+	(k, f)
+}
+
+/// Returns `s = f / (2 + f)`, `0.5 * f^2`, and `R` such that `log(1 + f) = f - (0.5 * f^2 - s *
+/// (0.5 * f^2 + R))`.
+#[inline(always)]
+fn log1p_kernel_f32s<S: Simd>(simd: S, f: S::f32s) -> (S::f32s, S::f32s, S::f32s) {
+	let s = simd.div_f32s(f, simd.add_f32s(simd.splat_f32s(2.0), f));
+	let z = simd.mul_f32s(s, s);
+	let r = simd.mul_f32s(
+		z,
+		polynomial_f32s(
+			simd,
+			z,
+			&[0.24279078841, 0.28498786688, 0.40000972152, 0.66666662693],
+		),
+	);
+	let hfsq = simd.mul_f32s(simd.splat_f32s(0.5), simd.mul_f32s(f, f));
+	(s, hfsq, r)
+}
+
+#[inline(always)]
+fn log1p_kernel_f64s<S: Simd>(simd: S, f: S::f64s) -> (S::f64s, S::f64s, S::f64s) {
+	let s = simd.div_f64s(f, simd.add_f64s(simd.splat_f64s(2.0), f));
+	let z = simd.mul_f64s(s, s);
+	let r = simd.mul_f64s(
+		z,
+		polynomial_f64s(
+			simd,
+			z,
+			&[
+				1.479819860511658591e-01,
+				1.531383769920937332e-01,
+				1.818357216161805012e-01,
+				2.222219843214978396e-01,
+				2.857142874366239149e-01,
+				3.999999999940941908e-01,
+				6.666666666666735130e-01,
+			],
+		),
+	);
+	let hfsq = simd.mul_f64s(simd.splat_f64s(0.5), simd.mul_f64s(f, f));
+	(s, hfsq, r)
+}
+
+/// Handles the lanes of `x` that are not positive and finite.
+#[inline(always)]
+fn log_special_f32s<S: Simd>(simd: S, x: S::f32s, y: S::f32s) -> S::f32s {
+	let valid = simd.and_m32s(
+		simd.greater_than_f32s(x, simd.splat_f32s(0.0)),
+		simd.less_than_f32s(x, simd.splat_f32s(f32::INFINITY)),
+	);
+	let special = simd.select_f32s(
+		simd.equal_f32s(x, simd.splat_f32s(0.0)),
+		simd.splat_f32s(f32::NEG_INFINITY),
+		simd.select_f32s(
+			simd.less_than_f32s(x, simd.splat_f32s(0.0)),
+			simd.splat_f32s(f32::NAN),
+			x,
+		),
+	);
+	simd.select_f32s(valid, y, special)
+}
+
+#[inline(always)]
+fn log_special_f64s<S: Simd>(simd: S, x: S::f64s, y: S::f64s) -> S::f64s {
+	let valid = simd.and_m64s(
+		simd.greater_than_f64s(x, simd.splat_f64s(0.0)),
+		simd.less_than_f64s(x, simd.splat_f64s(f64::INFINITY)),
+	);
+	let special = simd.select_f64s(
+		simd.equal_f64s(x, simd.splat_f64s(0.0)),
+		simd.splat_f64s(f64::NEG_INFINITY),
+		simd.select_f64s(
+			simd.less_than_f64s(x, simd.splat_f64s(0.0)),
+			simd.splat_f64s(f64::NAN),
+			x,
+		),
+	);
+	simd.select_f64s(valid, y, special)
+}
+
+/// Computes the natural logarithm of each lane of `x`.
+///
+/// Maximum error: 1 ULP.
+#[inline(always)]
+pub fn ln_f32s<S: Simd>(simd: S, x: S::f32s) -> S::f32s {
+	const LN2_HI: f32 = 6.9313812256e-01;
+	const LN2_LO: f32 = 9.0580006145e-06;
+
+	let (k, f) = log_reduce_f32s(simd, x);
+	let (s, hfsq, r) = log1p_kernel_f32s(simd, f);
+
+	// s * (hfsq + r) + k * LN2_LO - hfsq + f + k * LN2_HI
+	let y = simd.mul_add_e_f32s(
+		k,
+		simd.splat_f32s(LN2_LO),
+		simd.mul_f32s(s, simd.add_f32s(hfsq, r)),
+	);
+	let y = simd.add_f32s(simd.sub_f32s(y, hfsq), f);
+	let y = simd.mul_add_e_f32s(k, simd.splat_f32s(LN2_HI), y);
+
+	log_special_f32s(simd, x, y)
+}
+
+/// Computes the natural logarithm of each lane of `x`.
+///
+/// Maximum error: 1 ULP.
+#[inline(always)]
+pub fn ln_f64s<S: Simd>(simd: S, x: S::f64s) -> S::f64s {
+	const LN2_HI: f64 = 6.93147180369123816490e-01;
+	const LN2_LO: f64 = 1.90821492927058770002e-10;
+
+	let (k, f) = log_reduce_f64s(simd, x);
+	let (s, hfsq, r) = log1p_kernel_f64s(simd, f);
+
+	let y = simd.mul_add_e_f64s(
+		k,
+		simd.splat_f64s(LN2_LO),
+		simd.mul_f64s(s, simd.add_f64s(hfsq, r)),
+	);
+	let y = simd.add_f64s(simd.sub_f64s(y, hfsq), f);
+	let y = simd.mul_add_e_f64s(k, simd.splat_f64s(LN2_HI), y);
+
+	log_special_f64s(simd, x, y)
+}
+
+/// Computes the base 2 logarithm of each lane of `x`.
+///
+/// Maximum error: 1 ULP.
+#[inline(always)]
+pub fn log2_f32s<S: Simd>(simd: S, x: S::f32s) -> S::f32s {
+	const IVLN2_HI: f32 = 1.4428710938e+00;
+	const IVLN2_LO: f32 = -1.7605285393e-04;
+
+	let (k, f) = log_reduce_f32s(simd, x);
+	let (s, hfsq, r) = log1p_kernel_f32s(simd, f);
+
+	// split f - hfsq so that multiplying the high part by IVLN2_HI is exact
+	let hi = simd.sub_f32s(f, hfsq);
+	let hi = simd.transmute_f32s_u32s(
+		simd.and_u32s(simd.transmute_u32s_f32s(hi), simd.splat_u32s(0xfffff000)),
+	);
+	let lo = simd.sub_f32s(simd.sub_f32s(f, hi), hfsq);
+	let lo = simd.mul_add_e_f32s(s, simd.add_f32s(hfsq, r), lo);
+
+	// (lo + hi) * IVLN2_LO + lo * IVLN2_HI + hi * IVLN2_HI + k
+	let y = simd.mul_f32s(simd.add_f32s(lo, hi), simd.splat_f32s(IVLN2_LO));
+	let y = simd.mul_add_e_f32s(lo, simd.splat_f32s(IVLN2_HI), y);
+	let y = simd.mul_add_e_f32s(hi, simd.splat_f32s(IVLN2_HI), y);
+	let y = simd.add_f32s(y, k);
+
+	log_special_f32s(simd, x, y)
+}
+
+/// Computes the base 2 logarithm of each lane of `x`.
+///
+/// Maximum error: 1 ULP.
+#[inline(always)]
+pub fn log2_f64s<S: Simd>(simd: S, x: S::f64s) -> S::f64s {
+	const IVLN2_HI: f64 = 1.44269504072144627571e+00;
+	const IVLN2_LO: f64 = 1.67517131648865118353e-10;
+
+	let (k, f) = log_reduce_f64s(simd, x);
+	let (s, hfsq, r) = log1p_kernel_f64s(simd, f);
+
+	let hi = simd.sub_f64s(f, hfsq);
+	let hi = simd.transmute_f64s_u64s(simd.and_u64s(
+		simd.transmute_u64s_f64s(hi),
+		simd.splat_u64s(0xffffffff_00000000),
+	));
+	let lo = simd.sub_f64s(simd.sub_f64s(f, hi), hfsq);
+	let lo = simd.mul_add_e_f64s(s, simd.add_f64s(hfsq, r), lo);
+
+	let val_hi = simd.mul_f64s(hi, simd.splat_f64s(IVLN2_HI));
+	let val_lo = simd.mul_f64s(simd.add_f64s(lo, hi), simd.splat_f64s(IVLN2_LO));
+	let val_lo = simd.mul_add_e_f64s(lo, simd.splat_f64s(IVLN2_HI), val_lo);
+
+	// add k with an error-free transformation, since it can cancel with val_hi
+	let w = simd.add_f64s(k, val_hi);
+	let val_lo = simd.add_f64s(val_lo, simd.add_f64s(simd.sub_f64s(k, w), val_hi));
+	let y = simd.add_f64s(val_lo, w);
+
+	log_special_f64s(simd, x, y)
+}
+
+/// Returns `(sin(r), cos(r), q)` where `x = r + q * pi / 2`.
+#[inline(always)]
+fn sincos_kernel_f32s<S: Simd>(simd: S, x: S::f32s) -> (S::f32s, S::f32s, S::u32s) {
+	let round = simd.splat_f32s(ROUND_F32);
+	let t = simd.mul_add_e_f32s(x, simd.splat_f32s(core::f32::consts::FRAC_2_PI), round);
+	let n = simd.sub_f32s(t, round);
+
+	let r = simd.mul_add_f32s(n, simd.splat_f32s(-1.5703125), x);
+	let r = simd.mul_add_f32s(n, simd.splat_f32s(-4.837512969970703125e-4), r);
+	let r = simd.mul_add_f32s(n, simd.splat_f32s(-7.54978995489188216e-8), r);
+	let r = simd.mul_add_f32s(n, simd.splat_f32s(1.7151245100058819e-15), r);
+
+	let z = simd.mul_f32s(r, r);
+	let sin = polynomial_f32s(
+		simd,
+		z,
+		&[-1.9515295891e-4, 8.3321608736e-3, -1.6666654611e-1],
+	);
+	let sin = simd.mul_add_e_f32s(simd.mul_f32s(sin, z), r, r);
+	let cos = polynomial_f32s(
+		simd,
+		z,
+		&[
+			2.443315711809948e-5,
+			-1.388731625493765e-3,
+			4.166664568298827e-2,
+		],
+	);
+	// 1 - z/2 loses the low bits of z/2, so they are added back separately
+	let hz = simd.mul_f32s(simd.splat_f32s(0.5), z);
+	let w = simd.sub_f32s(simd.splat_f32s(1.0), hz);
+	let cos = simd.mul_add_e_f32s(
+		simd.mul_f32s(cos, z),
+		z,
+		simd.sub_f32s(simd.sub_f32s(simd.splat_f32s(1.0), w), hz),
+	);
+	let cos = simd.add_f32s(w, cos);
+
+	(sin, cos, simd.transmute_u32s_f32s(t))
+}
+
+#[inline(always)]
+fn sincos_kernel_f64s<S: Simd>(simd: S, x: S::f64s) -> (S::f64s, S::f64s, S::u64s) {
+	let round = simd.splat_f64s(ROUND_F64);
+	let t = simd.mul_add_e_f64s(x, simd.splat_f64s(core::f64::consts::FRAC_2_PI), round);
+	let n = simd.sub_f64s(t, round);
+
+	let r = simd.mul_add_f64s(n, simd.splat_f64s(-1.57079625129699707031e0), x);
+	let r = simd.mul_add_f64s(n, simd.splat_f64s(-7.54978941586159635336e-8), r);
+	let r = simd.mul_add_f64s(n, simd.splat_f64s(-5.39030285815811905290e-15), r);
+
+	let z = simd.mul_f64s(r, r);
+	let sin = polynomial_f64s(
+		simd,
+		z,
+		&[
+			1.58962301576546568060e-10,
+			-2.50507477628578072866e-8,
+			2.75573136213857245213e-6,
+			-1.98412698295895385996e-4,
+			8.33333333332211858878e-3,
+			-1.66666666666666307295e-1,
+		],
+	);
+	let sin = simd.mul_add_e_f64s(simd.mul_f64s(sin, z), r, r);
+	let cos = polynomial_f64s(
+		simd,
+		z,
+		&[
+			-1.13585365213876817300e-11,
+			2.08757008419747316778e-9,
+			-2.75573141792967388112e-7,
+			2.48015872888517045348e-5,
+			-1.38888888888730564116e-3,
+			4.16666666666665929218e-2,
+		],
+	);
+	let hz = simd.mul_f64s(simd.splat_f64s(0.5), z);
+	let w = simd.sub_f64s(simd.splat_f64s(1.0), hz);
+	let cos = simd.mul_add_e_f64s(
+		simd.mul_f64s(cos, z),
+		z,
+		simd.sub_f64s(simd.sub_f64s(simd.splat_f64s(1.0), w), hz),
+	);
+	let cos = simd.add_f64s(w, cos);
+
+	(sin, cos, simd.transmute_u64s_f64s(t))
+}
+
+/// Picks `sin(r)` or `cos(r)` and the sign of the result depending on the quadrant `q`.
+#[inline(always)]
+fn sincos_select_f32s<S: Simd>(simd: S, sin: S::f32s, cos: S::f32s, q: S::u32s) -> S::f32s {
+	let odd = simd.equal_u32s(simd.and_u32s(q, simd.splat_u32s(1)), simd.splat_u32s(1));
+	let y = simd.select_f32s(odd, cos, sin);
+	let sign = simd.shl_const_u32s::<30>(simd.and_u32s(q, simd.splat_u32s(2)));
+	simd.transmute_f32s_u32s(simd.xor_u32s(simd.transmute_u32s_f32s(y), sign))
+}
+
+#[inline(always)]
+fn sincos_select_f64s<S: Simd>(simd: S, sin: S::f64s, cos: S::f64s, q: S::u64s) -> S::f64s {
+	let odd = simd.equal_u64s(simd.and_u64s(q, simd.splat_u64s(1)), simd.splat_u64s(1));
+	let y = simd.select_f64s(odd, cos, sin);
+	let sign = simd.shl_const_u64s::<62>(simd.and_u64s(q, simd.splat_u64s(2)));
+	simd.transmute_f64s_u64s(simd.xor_u64s(simd.transmute_u64s_f64s(y), sign))
+}
+
+/// Computes the sine of each lane of `x`.
+///
+/// Maximum error: 2 ULP for `|x| <= 8192`. Larger inputs are reduced with less precision.
+#[inline(always)]
+pub fn sin_f32s<S: Simd>(simd: S, x: S::f32s) -> S::f32s {
+	let (sin, cos, q) = sincos_kernel_f32s(simd, x);
+	sincos_select_f32s(simd, sin, cos, q)
+}
+
+/// Computes the cosine of each lane of `x`.
+///
+/// Maximum error: 2 ULP for `|x| <= 8192`. Larger inputs are reduced with less precision.
+#[inline(always)]
+pub fn cos_f32s<S: Simd>(simd: S, x: S::f32s) -> S::f32s {
+	let (sin, cos, q) = sincos_kernel_f32s(simd, x);
+	sincos_select_f32s(simd, sin, cos, simd.add_u32s(q, simd.splat_u32s(1)))
+}
+
+/// Computes the sine of each lane of `x`.
+///
+/// Maximum error: 1 ULP for `|x| <= 2^30`. Larger inputs are reduced with less precision.
+#[inline(always)]
+pub fn sin_f64s<S: Simd>(simd: S, x: S::f64s) -> S::f64s {
+	let (sin, cos, q) = sincos_kernel_f64s(simd, x);
+	sincos_select_f64s(simd, sin, cos, q)
+}
+
+/// Computes the cosine of each lane of `x`.
+///
+/// Maximum error: 1 ULP for `|x| <= 2^30`. Larger inputs are reduced with less precision.
+#[inline(always)]
+pub fn cos_f64s<S: Simd>(simd: S, x: S::f64s) -> S::f64s {
+	let (sin, cos, q) = sincos_kernel_f64s(simd, x);
+	sincos_select_f64s(simd, sin, cos, simd.add_u64s(q, simd.splat_u64s(1)))
+}
+
+/// Computes the hyperbolic tangent of each lane of `x`.
+///
+/// Maximum error: 2 ULP.
+#[inline(always)]
+pub fn tanh_f32s<S: Simd>(simd: S, x: S::f32s) -> S::f32s {
+	let ax = simd.abs_f32s(x);
+
+	let z = simd.mul_f32s(x, x);
+	let small = polynomial_f32s(
+		simd,
+		z,
+		&[
+			-5.70498872745e-3,
+			2.06390887954e-2,
+			-5.37397155531e-2,
+			1.33314422036e-1,
+			-3.33332819422e-1,
+		],
+	);
+	let small = simd.mul_add_e_f32s(simd.mul_f32s(small, z), x, x);
+
+	// 1 - 2 / (e^2|x| + 1), with the sign of x
+	let e = exp_f32s(simd, simd.add_f32s(ax, ax));
+	let large = simd.sub_f32s(
+		simd.splat_f32s(1.0),
+		simd.div_f32s(simd.splat_f32s(2.0), simd.add_f32s(e, simd.splat_f32s(1.0))),
+	);
+	let large = simd.or_f32s(large, simd.and_f32s(x, simd.splat_f32s(-0.0)));
+
+	simd.select_f32s(
+		simd.less_than_f32s(ax, simd.splat_f32s(0.625)),
+		small,
+		large,
+	)
+}
+
+/// Computes the hyperbolic tangent of each lane of `x`.
+///
+/// Maximum error: 2 ULP.
+#[inline(always)]
+pub fn tanh_f64s<S: Simd>(simd: S, x: S::f64s) -> S::f64s {
+	let ax = simd.abs_f64s(x);
+
+	let z = simd.mul_f64s(x, x);
+	let p = polynomial_f64s(
+		simd,
+		z,
+		&[
+			-9.64399179425052238628e-1,
+			-9.92877231001918586564e1,
+			-1.61468768441708447952e3,
+		],
+	);
+	let q = polynomial_f64s(
+		simd,
+		z,
+		&[
+			1.0,
+			1.12811678491632931402e2,
+			2.23548839060100448583e3,
+			4.84406305325125486048e3,
+		],
+	);
+	let small = simd.div_f64s(simd.mul_f64s(p, z), q);
+	let small = simd.mul_add_e_f64s(small, x, x);
+
+	let e = exp_f64s(simd, simd.add_f64s(ax, ax));
+	let large = simd.sub_f64s(
+		simd.splat_f64s(1.0),
+		simd.div_f64s(simd.splat_f64s(2.0), simd.add_f64s(e, simd.splat_f64s(1.0))),
+	);
+	let large = simd.or_f64s(large, simd.and_f64s(x, simd.splat_f64s(-0.0)));
+
+	simd.select_f64s(
+		simd.less_than_f64s(ax, simd.splat_f64s(0.625)),
+		small,
+		large,
+	)
+}
+
+/// Computes the logistic function `1 / (1 + e^-x)` for each lane of `x`.
+///
+/// Maximum error: 3 ULP.
+#[inline(always)]
+pub fn sigmoid_f32s<S: Simd>(simd: S, x: S::f32s) -> S::f32s {
+	// e^-|x| never overflows, and for negative x, the result is e^x / (1 + e^x)
+	let e = exp_f32s(simd, neg_f32s(simd, simd.abs_f32s(x)));
+	let num = simd.select_f32s(
+		simd.less_than_f32s(x, simd.splat_f32s(0.0)),
+		e,
+		simd.splat_f32s(1.0),
+	);
+	simd.div_f32s(num, simd.add_f32s(simd.splat_f32s(1.0), e))
+}
+
+/// Computes the logistic function `1 / (1 + e^-x)` for each lane of `x`.
+///
+/// Maximum error: 2 ULP.
+#[inline(always)]
+pub fn sigmoid_f64s<S: Simd>(simd: S, x: S::f64s) -> S::f64s {
+	let e = exp_f64s(simd, neg_f64s(simd, simd.abs_f64s(x)));
+	let num = simd.select_f64s(
+		simd.less_than_f64s(x, simd.splat_f64s(0.0)),
+		e,
+		simd.splat_f64s(1.0),
+	);
+	simd.div_f64s(num, simd.add_f64s(simd.splat_f64s(1.0), e))
+}
+
+/// Computes the natural logarithm of positive finite lanes of `x` as an unevaluated sum
+/// `hi + lo`, with about 100 bits of precision.
+#[inline(always)]
+fn ln_f64s_extended<S: Simd>(simd: S, x: S::f64s) -> (S::f64s, S::f64s) {
+	const LN2_HI: f64 = 6.93147180369123816490e-01;
+	const LN2_LO: f64 = 1.90821492927058770002e-10;
+	const TWO_THIRDS_HI: f64 = 0.6666666666666666;
+	const TWO_THIRDS_LO: f64 = 3.700743415417188e-17;
+
+	let (k, f) = log_reduce_f64s(simd, x);
+
+	// s = f / (2 + f), in double-double
+	let d_hi = simd.add_f64s(simd.splat_f64s(2.0), f);
+	let d_lo = simd.add_f64s(simd.sub_f64s(simd.splat_f64s(2.0), d_hi), f);
+	let s_hi = simd.div_f64s(f, d_hi);
+	let s_lo = simd.mul_add_f64s(neg_f64s(simd, s_hi), d_hi, f);
+	let s_lo = simd.div_f64s(simd.mul_add_f64s(neg_f64s(simd, s_hi), d_lo, s_lo), d_hi);
+
+	// log(1 + f) = 2 * atanh(s) = 2s + 2s^3 / 3 + s^5 * P(s^2)
+	let s2 = simd.mul_f64s(s_hi, s_hi);
+	let s2_lo = simd.mul_add_f64s(s_hi, s_hi, neg_f64s(simd, s2));
+	let s3 = simd.mul_f64s(s2, s_hi);
+	let s3_lo = simd.mul_add_f64s(s2, s_hi, neg_f64s(simd, s3));
+	let s3_lo = simd.mul_add_e_f64s(s2_lo, s_hi, s3_lo);
+	let s3_lo = simd.mul_add_e_f64s(simd.mul_f64s(simd.splat_f64s(3.0), s2), s_lo, s3_lo);
+
+	let t3 = simd.mul_f64s(s3, simd.splat_f64s(TWO_THIRDS_HI));
+	let t3_lo = simd.mul_add_f64s(s3, simd.splat_f64s(TWO_THIRDS_HI), neg_f64s(simd, t3));
+	let t3_lo = simd.mul_add_e_f64s(s3, simd.splat_f64s(TWO_THIRDS_LO), t3_lo);
+	let t3_lo = simd.mul_add_e_f64s(s3_lo, simd.splat_f64s(TWO_THIRDS_HI), t3_lo);
+
+	let tail = polynomial_f64s(
+		simd,
+		s2,
+		&[
+			2.0 / 25.0,
+			2.0 / 23.0,
+			2.0 / 21.0,
+			2.0 / 19.0,
+			2.0 / 17.0,
+			2.0 / 15.0,
+			2.0 / 13.0,
+			2.0 / 11.0,
+			2.0 / 9.0,
+			2.0 / 7.0,
+			2.0 / 5.0,
+		],
+	);
+	let tail = simd.mul_f64s(simd.mul_f64s(s3, s2), tail);
+
+	let two_s = simd.add_f64s(s_hi, s_hi);
+	let a_hi = simd.add_f64s(two_s, t3);
+	let a_lo = simd.add_f64s(simd.sub_f64s(two_s, a_hi), t3);
+	let a_lo = simd.add_f64s(
+		a_lo,
+		simd.add_f64s(simd.add_f64s(s_lo, s_lo), simd.add_f64s(t3_lo, tail)),
+	);
+
+	// add k * ln(2)
+	let p = simd.mul_f64s(k, simd.splat_f64s(LN2_HI));
+	let b_hi = simd.add_f64s(p, a_hi);
+	let bb = simd.sub_f64s(b_hi, p);
+	let b_lo = simd.add_f64s(
+		simd.sub_f64s(p, simd.sub_f64s(b_hi, bb)),
+		simd.sub_f64s(a_hi, bb),
+	);
+	let b_lo = simd.add_f64s(b_lo, simd.mul_add_e_f64s(k, simd.splat_f64s(LN2_LO), a_lo));
+
+	let hi = simd.add_f64s(b_hi, b_lo);
+	let lo = simd.sub_f64s(b_lo, simd.sub_f64s(hi, b_hi));
+	(hi, lo)
+}
+
+/// Computes `x^y` for each lane of `x` and `y`.
+///
+/// Maximum error: 1 ULP.
+#[inline(always)]
+pub fn pow_f32s<S: Simd>(simd: S, x: S::f32s, y: S::f32s) -> S::f32s {
+	// the double precision result has plenty of bits to spare
+	let lo = pow_f64s(simd, simd.widen_low_f32s(x), simd.widen_low_f32s(y));
+	let hi = pow_f64s(simd, simd.widen_high_f32s(x), simd.widen_high_f32s(y));
+	simd.narrow_f64s(lo, hi)
+}
+
+/// Computes `x^y` for each lane of `x` and `y`.
+///
+/// Maximum error: 1 ULP.
+#[inline(always)]
+pub fn pow_f64s<S: Simd>(simd: S, x: S::f64s, y: S::f64s) -> S::f64s {
+	let zero = simd.splat_f64s(0.0);
+	let one = simd.splat_f64s(1.0);
+	let inf = simd.splat_f64s(f64::INFINITY);
+
+	let ax = simd.abs_f64s(x);
+	let finite = simd.and_m64s(
+		simd.greater_than_f64s(ax, zero),
+		simd.less_than_f64s(ax, inf),
+	);
+	let (l_hi, l_lo) = ln_f64s_extended(simd, simd.select_f64s(finite, ax, one));
+	let l_hi = simd.select_f64s(
+		finite,
+		l_hi,
+		simd.select_f64s(
+			simd.equal_f64s(ax, zero),
+			simd.splat_f64s(f64::NEG_INFINITY),
+			ax,
+		),
+	);
+
+	// y * log(|x|), in double-double
+	let p_hi = simd.mul_f64s(y, l_hi);
+	let p_lo = simd.mul_add_f64s(y, l_hi, neg_f64s(simd, p_hi));
+	let p_lo = simd.mul_add_e_f64s(y, l_lo, p_lo);
+	// the low part only matters when the result neither overflows nor underflows
+	let p_lo = simd.select_f64s(
+		simd.less_than_f64s(simd.abs_f64s(p_hi), simd.splat_f64s(1024.0)),
+		p_lo,
+		zero,
+	);
+
+	let r = exp_f64s_impl(simd, p_hi, p_lo);
+
+	// negative bases
+	let half_y = simd.mul_f64s(y, simd.splat_f64s(0.5));
+	let y_int = simd.equal_f64s(simd.trunc_f64s(y), y);
+	let y_odd = simd.and_m64s(
+		y_int,
+		simd.not_m64s(simd.equal_f64s(simd.trunc_f64s(half_y), half_y)),
+	);
+	let x_sign = simd.less_than_i64s(
+		simd.transmute_i64s_u64s(simd.transmute_u64s_f64s(x)),
+		simd.splat_i64s(0),
+	);
+	let r = simd.select_f64s(simd.and_m64s(x_sign, y_odd), neg_f64s(simd, r), r);
+	let r = simd.select_f64s(
+		simd.and_m64s(
+			simd.and_m64s(
+				simd.less_than_f64s(x, zero),
+				simd.greater_than_f64s(x, neg_f64s(simd, inf)),
+			),
+			simd.not_m64s(y_int),
+		),
+		simd.splat_f64s(f64::NAN),
+		r,
+	);
+
+	// x^0 = 1^y = (-1)^inf = 1, even for nan
+	let is_one = simd.or_m64s(
+		simd.or_m64s(simd.equal_f64s(y, zero), simd.equal_f64s(x, one)),
+		simd.and_m64s(
+			simd.equal_f64s(ax, one),
+			simd.equal_f64s(simd.abs_f64s(y), inf),
+		),
+	);
+	simd.select_f64s(is_one, one, r)
+}
+
+#[cfg(test)]
+mod tests {
+	extern crate alloc;
+
+	use super::*;
+	use crate::*;
+	use alloc::vec::Vec;
+	use rand::random;
+
+	fn ulp_error_f32(actual: f32, expected: f64) -> f64 {
+		if actual.is_nan() || expected.is_nan() {
+			return if actual.is_nan() && expected.is_nan() {
+				0.0
+			} else {
+				f64::INFINITY
+			};
+		}
+		let rounded = expected as f32;
+		if rounded.is_infinite() || actual.is_infinite() {
+			return if actual == rounded {
+				0.0
+			} else {
+				f64::INFINITY
+			};
+		}
+		let ulp = f32::from_bits(rounded.abs().to_bits() + 1) - rounded.abs();
+		let ulp = (ulp as f64).max(f32::from_bits(1) as f64);
+		(actual as f64 - expected).abs() / ulp
+	}
+
+	fn ulp_error_f64(actual: f64, expected: f64) -> f64 {
+		if actual.is_nan() || expected.is_nan() {
+			return if actual.is_nan() && expected.is_nan() {
+				0.0
+			} else {
+				f64::INFINITY
+			};
+		}
+		if actual.is_infinite() || expected.is_infinite() {
+			return if actual == expected {
+				0.0
+			} else {
+				f64::INFINITY
+			};
+		}
+		let ulp = f64::from_bits(expected.abs().to_bits() + 1) - expected.abs();
+		let ulp = ulp.max(f64::from_bits(1));
+		(actual - expected).abs() / ulp
+	}
+
+	fn apply_f32s<S: Simd>(simd: S, inputs: &[f32], f: impl Fn(S::f32s) -> S::f32s) -> Vec<f32> {
+		let mut out = Vec::with_capacity(inputs.len());
+		for chunk in inputs.chunks(S::F32_LANES) {
+			let y = f(simd.partial_load_f32s(chunk));
+			out.extend_from_slice(
+				&bytemuck::cast_slice::<_, f32>(core::slice::from_ref(&y))[..chunk.len()],
+			);
+		}
+		out
+	}
+
+	fn apply_f64s<S: Simd>(simd: S, inputs: &[f64], f: impl Fn(S::f64s) -> S::f64s) -> Vec<f64> {
+		let mut out = Vec::with_capacity(inputs.len());
+		for chunk in inputs.chunks(S::F64_LANES) {
+			let y = f(simd.partial_load_f64s(chunk));
+			out.extend_from_slice(
+				&bytemuck::cast_slice::<_, f64>(core::slice::from_ref(&y))[..chunk.len()],
+			);
+		}
+		out
+	}
+
+	fn inputs_f32(lo: f32, hi: f32) -> Vec<f32> {
+		let mut x: Vec<f32> = (0..4096)
+			.map(|_| lo + (hi - lo) * random::<f32>())
+			.collect();
+		x.extend_from_slice(&[
+			lo,
+			hi,
+			0.0,
+			-0.0,
+			1.0,
+			-1.0,
+			f32::INFINITY,
+			f32::NEG_INFINITY,
+			f32::NAN,
+		]);
+		x
+	}
+
+	fn inputs_f64(lo: f64, hi: f64) -> Vec<f64> {
+		let mut x: Vec<f64> = (0..4096)
+			.map(|_| lo + (hi - lo) * random::<f64>())
+			.collect();
+		x.extend_from_slice(&[
+			lo,
+			hi,
+			0.0,
+			-0.0,
+			1.0,
+			-1.0,
+			f64::INFINITY,
+			f64::NEG_INFINITY,
+			f64::NAN,
+		]);
+		x
+	}
+
+	#[track_caller]
+	fn check_f32(inputs: &[f32], actual: &[f32], expected: impl Fn(f64) -> f64, max_ulp: f64) {
+		for (&x, &y) in core::iter::zip(inputs, actual) {
+			let e = expected(x as f64);
+			let err = ulp_error_f32(y, e);
+			assert!(
+				err <= max_ulp,
+				"x = {x:e}, actual = {y:e}, expected = {e:e}, error = {err} ulp"
+			);
+		}
+	}
+
+	#[track_caller]
+	fn check_f64(inputs: &[f64], actual: &[f64], expected: impl Fn(f64) -> f64, max_ulp: f64) {
+		for (&x, &y) in core::iter::zip(inputs, actual) {
+			let e = expected(x);
+			let err = ulp_error_f64(y, e);
+			assert!(
+				err <= max_ulp,
+				"x = {x:e}, actual = {y:e}, expected = {e:e}, error = {err} ulp"
+			);
+		}
+	}
+
+	fn sigmoid(x: f64) -> f64 {
+		if x < 0.0 {
+			let e = libm::exp(x);
+			e / (1.0 + e)
+		} else {
+			1.0 / (1.0 + libm::exp(-x))
+		}
+	}
+
+	fn check_math<S: Simd>(simd: S) {
+		// the f32 functions are checked against the f64 libm functions, which act as a
+		// reference with extra precision. the f64 functions are checked against libm, which is
+		// itself within 1 ULP of the exact result.
+		for (lo, hi) in [(-1.0, 1.0), (-110.0, 110.0)] {
+			let x = inputs_f32(lo, hi);
+			check_f32(
+				&x,
+				&apply_f32s(simd, &x, |x| exp_f32s(simd, x)),
+				libm::exp,
+				1.5,
+			);
+			check_f32(
+				&x,
+				&apply_f32s(simd, &x, |x| tanh_f32s(simd, x)),
+				libm::tanh,
+				2.0,
+			);
+			check_f32(
+				&x,
+				&apply_f32s(simd, &x, |x| sigmoid_f32s(simd, x)),
+				sigmoid,
+				3.0,
+			);
+		}
+		for (lo, hi) in [(-1.0, 1.0), (-750.0, 750.0)] {
+			let x = inputs_f64(lo, hi);
+			check_f64(
+				&x,
+				&apply_f64s(simd, &x, |x| exp_f64s(simd, x)),
+				libm::exp,
+				2.0,
+			);
+			check_f64(
+				&x,
+				&apply_f64s(simd, &x, |x| tanh_f64s(simd, x)),
+				libm::tanh,
+				3.0,
+			);
+			check_f64(
+				&x,
+				&apply_f64s(simd, &x, |x| sigmoid_f64s(simd, x)),
+				sigmoid,
+				3.0,
+			);
+		}
+
+		for (lo, hi) in [(0.0, 2.0), (0.0, 1e30), (0.0, f32::MAX), (0.0, 1e-36)] {
+			let x = inputs_f32(lo, hi);
+			check_f32(
+				&x,
+				&apply_f32s(simd, &x, |x| ln_f32s(simd, x)),
+				libm::log,
+				1.0,
+			);
+			check_f32(
+				&x,
+				&apply_f32s(simd, &x, |x| log2_f32s(simd, x)),
+				libm::log2,
+				1.0,
+			);
+		}
+		for (lo, hi) in [(0.0, 2.0), (0.0, 1e300), (0.0, f64::MAX), (0.0, 1e-305)] {
+			let x = inputs_f64(lo, hi);
+			check_f64(
+				&x,
+				&apply_f64s(simd, &x, |x| ln_f64s(simd, x)),
+				libm::log,
+				2.0,
+			);
+			check_f64(
+				&x,
+				&apply_f64s(simd, &x, |x| log2_f64s(simd, x)),
+				libm::log2,
+				2.0,
+			);
+		}
+
+		for (lo, hi) in [(-4.0, 4.0), (-8192.0, 8192.0)] {
+			let x = inputs_f32(lo, hi);
+			check_f32(
+				&x,
+				&apply_f32s(simd, &x, |x| sin_f32s(simd, x)),
+				libm::sin,
+				2.0,
+			);
+			check_f32(
+				&x,
+				&apply_f32s(simd, &x, |x| cos_f32s(simd, x)),
+				libm::cos,
+				2.0,
+			);
+		}
+		for (lo, hi) in [(-4.0, 4.0), (-1e9, 1e9)] {
+			let x = inputs_f64(lo, hi);
+			check_f64(
+				&x,
+				&apply_f64s(simd, &x, |x| sin_f64s(simd, x)),
+				libm::sin,
+				2.0,
+			);
+			check_f64(
+				&x,
+				&apply_f64s(simd, &x, |x| cos_f64s(simd, x)),
+				libm::cos,
+				2.0,
+			);
+		}
+
+		let special = [
+			0.0,
+			-0.0,
+			0.5,
+			-0.5,
+			1.0,
+			-1.0,
+			2.0,
+			-2.0,
+			3.0,
+			-3.0,
+			1.0 / 3.0,
+			-1.0 / 3.0,
+			1e300,
+			-1e300,
+			f64::INFINITY,
+			f64::NEG_INFINITY,
+			f64::NAN,
+		];
+		for (x, y) in [(0.0, 4.0), (0.0, 1e10), (-20.0, 20.0)]
+			.into_iter()
+			.flat_map(|(lo, hi)| {
+				(0..4096).map(move |_| {
+					let x: f64 = if lo == 0.0 {
+						hi * random::<f64>()
+					} else {
+						lo + (hi - lo) * random::<f64>()
+					};
+					let y = -300.0 + 600.0 * random::<f64>() / (1.0 + x.abs().ln().abs());
+					(x, y)
+				})
+			})
+			.chain(
+				special
+					.iter()
+					.flat_map(|&x| special.iter().map(move |&y| (x, y))),
+			) {
+			let pow = |x, y| -> f64 {
+				let z = pow_f64s(simd, simd.splat_f64s(x), simd.splat_f64s(y));
+				bytemuck::cast_slice::<_, f64>(core::slice::from_ref(&z))[0]
+			};
+			let expected = libm::pow(x, y);
+			let actual = pow(x, y);
+			let err = ulp_error_f64(actual, expected);
+			assert!(
+				err <= 2.0,
+				"x = {x:e}, y = {y:e}, actual = {actual:e}, expected = {expected:e}"
+			);
+			if expected == 0.0 {
+				assert_eq!(actual.to_bits(), expected.to_bits());
+			}
+
+			let (x, y) = (x as f32, y as f32);
+			let pow = |x, y| -> f32 {
+				let z = pow_f32s(simd, simd.splat_f32s(x), simd.splat_f32s(y));
+				bytemuck::cast_slice::<_, f32>(core::slice::from_ref(&z))[0]
+			};
+			let expected = libm::pow(x as f64, y as f64);
+			let actual = pow(x, y);
+			let err = ulp_error_f32(actual, expected);
+			assert!(
+				err <= 1.0,
+				"x = {x:e}, y = {y:e}, actual = {actual:e}, expected = {expected:e}"
+			);
+			if expected == 0.0 {
+				assert_eq!(actual.to_bits(), (expected as f32).to_bits());
+			}
+		}
+	}
+
+	#[test]
+	fn test_math() {
+		check_math(Scalar);
+		check_math(Scalar128b);
+		check_math(Scalar512b);
+
+		#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+		{
+			use x86::*;
+
+			if let Some(simd) = V2::try_new() {
+				check_math(simd);
+			}
+
+			if let Some(simd) = V3::try_new() {
+				check_math(simd);
+				check_math(V3_128b(simd));
+				check_math(V3_256b(simd));
+				check_math(V3_512b(simd));
+			}
+
+			#[cfg(feature = "x86-v4")]
+			if let Some(simd) = V4::try_new() {
+				check_math(simd);
+			}
+		}
+
+		#[cfg(target_arch = "aarch64")]
+		{
+			use aarch64::*;
+
+			if let Some(simd) = Neon::try_new() {
+				check_math(simd);
+			}
+
+			if let Some(simd) = NeonFcma::try_new() {
+				check_math(simd);
+			}
+		}
+
+		#[cfg(target_arch = "wasm32")]
+		{
+			use wasm::*;
+
+			if let Some(simd) = Simd128::try_new() {
+				check_math(simd);
+			}
+
+			if let Some(simd) = RelaxedSimd::try_new() {
+				check_math(simd);
+			}
+		}
+	}
+}
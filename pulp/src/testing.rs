@@ -0,0 +1,320 @@
+//! Helpers for checking that an operation behaves the same on every backend.
+//!
+//! [`for_each_available_simd`] runs a [`WithSimd`] operation once per backend that the current
+//! machine supports, and [`ApproxEq`] compares the outputs while allowing for the rounding
+//! differences that fused operations and approximations introduce.
+
+use crate::{Scalar, Scalar128b, Scalar256b, Scalar512b, Simd, WithSimd, c32, c64};
+use core::fmt::Debug;
+
+#[inline(always)]
+fn run<S: Simd, Op: WithSimd>(
+	name: &'static str,
+	simd: S,
+	op: Op,
+	f: &mut impl FnMut(&'static str, Op::Output),
+) {
+	f(name, simd.vectorize(op));
+}
+
+/// Invokes `op` once with each backend available on the current machine, and passes the name of
+/// the backend along with the output to `f`.
+///
+/// The first backend is always [`Scalar`], followed by the other portable backends, then the
+/// architecture specific ones.
+pub fn for_each_available_simd<Op: WithSimd + Clone>(
+	op: Op,
+	f: impl FnMut(&'static str, Op::Output),
+) {
+	let mut f = f;
+
+	run("Scalar", Scalar, op.clone(), &mut f);
+	run("Scalar128b", Scalar128b, op.clone(), &mut f);
+	run("Scalar256b", Scalar256b, op.clone(), &mut f);
+	run("Scalar512b", Scalar512b, op.clone(), &mut f);
+
+	#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+	{
+		use crate::x86::*;
+
+		if let Some(simd) = V2::try_new() {
+			run("V2", simd, op.clone(), &mut f);
+		}
+
+		if let Some(simd) = V3::try_new() {
+			run("V3", simd, op.clone(), &mut f);
+			run("V3_128b", V3_128b(simd), op.clone(), &mut f);
+			run("V3_256b", V3_256b(simd), op.clone(), &mut f);
+			run("V3_512b", V3_512b(simd), op.clone(), &mut f);
+		}
+
+		#[cfg(feature = "x86-v4")]
+		if let Some(simd) = V4::try_new() {
+			run("V4", simd, op.clone(), &mut f);
+			run("V4_256b", V4_256b(simd), op.clone(), &mut f);
+		}
+	}
+
+	#[cfg(target_arch = "aarch64")]
+	{
+		use crate::aarch64::*;
+
+		if let Some(simd) = Neon::try_new() {
+			run("Neon", simd, op.clone(), &mut f);
+		}
+
+		if let Some(simd) = NeonFcma::try_new() {
+			run("NeonFcma", simd, op.clone(), &mut f);
+		}
+	}
+
+	#[cfg(target_arch = "wasm32")]
+	{
+		use crate::wasm::*;
+
+		if let Some(simd) = Simd128::try_new() {
+			run("Simd128", simd, op.clone(), &mut f);
+		}
+
+		#[cfg(feature = "relaxed-simd")]
+		if let Some(simd) = RelaxedSimd::try_new() {
+			run("RelaxedSimd", simd, op.clone(), &mut f);
+		}
+	}
+}
+
+/// Invokes `op` once with each backend available on the current machine, and checks that every
+/// output is approximately equal to the output of [`Scalar`].
+///
+/// # Panics
+/// Panics with the name of the first backend whose output differs.
+#[track_caller]
+pub fn assert_all_simd_approx_eq<Op>(op: Op, tolerance: f64)
+where
+	Op: WithSimd + Clone,
+	Op::Output: ApproxEq + Debug,
+{
+	let mut expected = None;
+	for_each_available_simd(op, |name, output| match &expected {
+		None => expected = Some(output),
+		Some(expected) => assert!(
+			output.approx_eq(expected, tolerance),
+			"{name} produced {output:?}, while Scalar produced {expected:?}",
+		),
+	});
+}
+
+/// Values that can be compared up to a tolerance.
+pub trait ApproxEq {
+	/// Returns `true` if `self` and `other` are equal up to `tolerance`.
+	///
+	/// Floating point values may differ by `tolerance`, relative to their magnitude when it is
+	/// larger than one. NaNs compare equal to each other, and infinities only to themselves.
+	/// Complex values are compared component-wise, and other values exactly.
+	fn approx_eq(&self, other: &Self, tolerance: f64) -> bool;
+}
+
+#[inline]
+fn approx_eq_f64(a: f64, b: f64, tolerance: f64) -> bool {
+	if a.is_nan() || b.is_nan() {
+		a.is_nan() && b.is_nan()
+	} else if a.is_infinite() || b.is_infinite() {
+		a == b
+	} else {
+		(a - b).abs() <= tolerance * f64::max(1.0, f64::max(a.abs(), b.abs()))
+	}
+}
+
+impl ApproxEq for f32 {
+	#[inline]
+	fn approx_eq(&self, other: &Self, tolerance: f64) -> bool {
+		approx_eq_f64(*self as f64, *other as f64, tolerance)
+	}
+}
+
+impl ApproxEq for f64 {
+	#[inline]
+	fn approx_eq(&self, other: &Self, tolerance: f64) -> bool {
+		approx_eq_f64(*self, *other, tolerance)
+	}
+}
+
+impl ApproxEq for c32 {
+	#[inline]
+	fn approx_eq(&self, other: &Self, tolerance: f64) -> bool {
+		self.re.approx_eq(&other.re, tolerance) && self.im.approx_eq(&other.im, tolerance)
+	}
+}
+
+impl ApproxEq for c64 {
+	#[inline]
+	fn approx_eq(&self, other: &Self, tolerance: f64) -> bool {
+		self.re.approx_eq(&other.re, tolerance) && self.im.approx_eq(&other.im, tolerance)
+	}
+}
+
+macro_rules! impl_approx_eq_exact {
+	($($ty: ty),*) => {$(
+		impl ApproxEq for $ty {
+			#[inline]
+			fn approx_eq(&self, other: &Self, tolerance: f64) -> bool {
+				let _ = tolerance;
+				self == other
+			}
+		}
+	)*};
+}
+
+impl_approx_eq_exact!(
+	(),
+	bool,
+	u8,
+	i8,
+	u16,
+	i16,
+	u32,
+	i32,
+	u64,
+	i64,
+	u128,
+	i128,
+	usize,
+	isize
+);
+
+impl<T: ApproxEq> ApproxEq for [T] {
+	#[inline]
+	fn approx_eq(&self, other: &Self, tolerance: f64) -> bool {
+		self.len() == other.len()
+			&& core::iter::zip(self, other).all(|(a, b)| a.approx_eq(b, tolerance))
+	}
+}
+
+impl<T: ApproxEq, const N: usize> ApproxEq for [T; N] {
+	#[inline]
+	fn approx_eq(&self, other: &Self, tolerance: f64) -> bool {
+		self[..].approx_eq(&other[..], tolerance)
+	}
+}
+
+#[cfg(feature = "std")]
+impl<T: ApproxEq> ApproxEq for Vec<T> {
+	#[inline]
+	fn approx_eq(&self, other: &Self, tolerance: f64) -> bool {
+		self[..].approx_eq(&other[..], tolerance)
+	}
+}
+
+impl<T: ApproxEq> ApproxEq for Option<T> {
+	#[inline]
+	fn approx_eq(&self, other: &Self, tolerance: f64) -> bool {
+		match (self, other) {
+			(Some(a), Some(b)) => a.approx_eq(b, tolerance),
+			(None, None) => true,
+			_ => false,
+		}
+	}
+}
+
+macro_rules! impl_approx_eq_tuple {
+	($($t: ident $i: tt),*) => {
+		impl<$($t: ApproxEq),*> ApproxEq for ($($t,)*) {
+			#[inline]
+			fn approx_eq(&self, other: &Self, tolerance: f64) -> bool {
+				true $(&& self.$i.approx_eq(&other.$i, tolerance))*
+			}
+		}
+	};
+}
+
+impl_approx_eq_tuple!(A 0);
+impl_approx_eq_tuple!(A 0, B 1);
+impl_approx_eq_tuple!(A 0, B 1, C 2);
+impl_approx_eq_tuple!(A 0, B 1, C 2, D 3);
+
+#[cfg(test)]
+mod tests {
+	extern crate alloc;
+
+	use super::*;
+	use alloc::vec::Vec;
+
+	#[derive(Clone)]
+	struct Sum<'a>(&'a [f32]);
+
+	impl WithSimd for Sum<'_> {
+		type Output = f32;
+
+		#[inline(always)]
+		fn with_simd<S: Simd>(self, simd: S) -> Self::Output {
+			let (head, tail) = S::as_simd_f32s(self.0);
+			let mut acc = simd.splat_f32s(0.0);
+			for &x in head {
+				acc = simd.add_f32s(acc, x);
+			}
+			simd.reduce_sum_f32s(acc) + tail.iter().sum::<f32>()
+		}
+	}
+
+	#[derive(Clone)]
+	struct Lanes;
+
+	impl WithSimd for Lanes {
+		type Output = usize;
+
+		#[inline(always)]
+		fn with_simd<S: Simd>(self, simd: S) -> Self::Output {
+			let _ = simd;
+			S::F32_LANES
+		}
+	}
+
+	#[test]
+	fn test_for_each_available_simd() {
+		let mut names = Vec::new();
+		for_each_available_simd(Lanes, |name, lanes| names.push((name, lanes)));
+
+		assert_eq!(names[0], ("Scalar", 1));
+		for (i, &(name, lanes)) in names.iter().enumerate() {
+			assert!(names[..i].iter().all(|&(other, _)| other != name));
+
+			let expected = match name {
+				"Scalar" => 1,
+				"Scalar128b" | "V2" | "V3_128b" | "Neon" | "NeonFcma" | "Simd128"
+				| "RelaxedSimd" => 4,
+				"Scalar256b" | "V3" | "V3_256b" | "V4_256b" => 8,
+				"Scalar512b" | "V3_512b" | "V4" => 16,
+				_ => panic!("unexpected backend {name}"),
+			};
+			assert_eq!(lanes, expected, "{name}");
+		}
+	}
+
+	#[test]
+	fn test_assert_all_simd_approx_eq() {
+		let x = (0..1000).map(|i| (i as f32).sin()).collect::<Vec<_>>();
+		assert_all_simd_approx_eq(Sum(&x), 1e-4);
+	}
+
+	#[test]
+	#[should_panic]
+	fn test_assert_all_simd_approx_eq_mismatch() {
+		assert_all_simd_approx_eq(Lanes, 0.0);
+	}
+
+	#[test]
+	fn test_approx_eq() {
+		assert!(1.0f32.approx_eq(&(1.0 + 1e-7), 1e-6));
+		assert!(!1.0f32.approx_eq(&1.001, 1e-6));
+		assert!(1e10f64.approx_eq(&(1e10 + 1.0), 1e-9));
+		assert!(f64::NAN.approx_eq(&f64::NAN, 0.0));
+		assert!(!f64::NAN.approx_eq(&1.0, f64::INFINITY));
+		assert!(!f64::INFINITY.approx_eq(&f64::MAX, f64::INFINITY));
+		assert!(c32::new(1.0, 2.0).approx_eq(&c32::new(1.0, 2.0 + 1e-7), 1e-6));
+		assert!(!c64::new(1.0, 2.0).approx_eq(&c64::new(1.5, 2.0), 1e-6));
+		assert!([1.0f32, 2.0][..].approx_eq(&[1.0, 2.0], 0.0));
+		assert!(![1.0f32][..].approx_eq(&[1.0, 2.0][..], 0.0));
+		assert!((1u32, 1.0f64).approx_eq(&(1, 1.0 + 1e-12), 1e-9));
+		assert!(!(1u32, 1.0f64).approx_eq(&(2, 1.0), 1e-9));
+	}
+}
@@ -15,6 +15,301 @@ simd_type!({
 	}
 });
 
+static WASM_COMPRESS_IDX_32: [[u8; 16]; 16] = compress_idx::<16, 4, 16>();
+static WASM_COMPRESS_IDX_64: [[u8; 16]; 4] = compress_idx::<16, 8, 4>();
+static WASM_EXPAND_IDX_32: [[u8; 16]; 16] = expand_idx::<16, 4, 16>();
+static WASM_EXPAND_IDX_64: [[u8; 16]; 4] = expand_idx::<16, 8, 4>();
+
+macro_rules! impl_compress_expand {
+	() => {
+		#[inline(always)]
+		fn compress_store_u32s(
+			self,
+			mask: Self::m32s,
+			values: Self::u32s,
+			out: &mut [u32],
+		) -> usize {
+			#[cfg(miri)]
+			{
+				compress_store_fallback(mask, values, out)
+			}
+			#[cfg(not(miri))]
+			{
+				let mask = self.simd128.i32x4_bitmask(cast!(mask)) as usize;
+				let count = mask.count_ones() as usize;
+				let values = self
+					.simd128
+					.i8x16_swizzle(cast!(values), cast!(WASM_COMPRESS_IDX_32[mask]));
+				self.partial_store_u32s(&mut out[..count], cast!(values));
+				count
+			}
+		}
+
+		#[inline(always)]
+		fn compress_store_u64s(
+			self,
+			mask: Self::m64s,
+			values: Self::u64s,
+			out: &mut [u64],
+		) -> usize {
+			#[cfg(miri)]
+			{
+				compress_store_fallback(mask, values, out)
+			}
+			#[cfg(not(miri))]
+			{
+				let mask = self.simd128.i64x2_bitmask(cast!(mask)) as usize;
+				let count = mask.count_ones() as usize;
+				let values = self
+					.simd128
+					.i8x16_swizzle(cast!(values), cast!(WASM_COMPRESS_IDX_64[mask]));
+				self.partial_store_u64s(&mut out[..count], cast!(values));
+				count
+			}
+		}
+
+		#[inline(always)]
+		fn expand_load_u32s(self, mask: Self::m32s, values: &[u32]) -> Self::u32s {
+			#[cfg(miri)]
+			{
+				expand_load_fallback(mask, values)
+			}
+			#[cfg(not(miri))]
+			{
+				let mask = self.simd128.i32x4_bitmask(cast!(mask)) as usize;
+				let count = mask.count_ones() as usize;
+				let values = self.partial_load_u32s(&values[..count]);
+				cast!(
+					self.simd128
+						.i8x16_swizzle(cast!(values), cast!(WASM_EXPAND_IDX_32[mask]))
+				)
+			}
+		}
+
+		#[inline(always)]
+		fn expand_load_u64s(self, mask: Self::m64s, values: &[u64]) -> Self::u64s {
+			#[cfg(miri)]
+			{
+				expand_load_fallback(mask, values)
+			}
+			#[cfg(not(miri))]
+			{
+				let mask = self.simd128.i64x2_bitmask(cast!(mask)) as usize;
+				let count = mask.count_ones() as usize;
+				let values = self.partial_load_u64s(&values[..count]);
+				cast!(
+					self.simd128
+						.i8x16_swizzle(cast!(values), cast!(WASM_EXPAND_IDX_64[mask]))
+				)
+			}
+		}
+	};
+}
+
+macro_rules! impl_mask_query {
+	() => {
+		#[inline(always)]
+		fn to_bitmask_m8s(self, mask: Self::m8s) -> u64 {
+			self.simd128.i8x16_bitmask(cast!(mask)) as u64
+		}
+
+		#[inline(always)]
+		fn to_bitmask_m16s(self, mask: Self::m16s) -> u64 {
+			self.simd128.i16x8_bitmask(cast!(mask)) as u64
+		}
+
+		#[inline(always)]
+		fn to_bitmask_m32s(self, mask: Self::m32s) -> u64 {
+			self.simd128.i32x4_bitmask(cast!(mask)) as u64
+		}
+
+		#[inline(always)]
+		fn to_bitmask_m64s(self, mask: Self::m64s) -> u64 {
+			self.simd128.i64x2_bitmask(cast!(mask)) as u64
+		}
+
+		#[inline(always)]
+		fn from_bitmask_m8s(self, bits: u64) -> Self::m8s {
+			// each byte of the bitmask is broadcast to the lanes it covers
+			let bits = self.simd128.i8x16_swizzle(
+				self.simd128.i16x8_splat(bits as i16),
+				cast!(u8x16(0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 1, 1, 1, 1, 1, 1)),
+			);
+			let lane_bits = cast!(u8x16(
+				1, 2, 4, 8, 16, 32, 64, 128, 1, 2, 4, 8, 16, 32, 64, 128
+			));
+			cast!(
+				self.simd128
+					.i8x16_eq(self.simd128.v128_and(bits, lane_bits), lane_bits)
+			)
+		}
+
+		#[inline(always)]
+		fn from_bitmask_m16s(self, bits: u64) -> Self::m16s {
+			let bits = self.simd128.i16x8_splat(bits as i16);
+			let lane_bits = cast!(u16x8(1, 2, 4, 8, 16, 32, 64, 128));
+			cast!(
+				self.simd128
+					.i16x8_eq(self.simd128.v128_and(bits, lane_bits), lane_bits)
+			)
+		}
+
+		#[inline(always)]
+		fn from_bitmask_m32s(self, bits: u64) -> Self::m32s {
+			let bits = self.simd128.i32x4_splat(bits as i32);
+			let lane_bits = cast!(u32x4(1, 2, 4, 8));
+			cast!(
+				self.simd128
+					.i32x4_eq(self.simd128.v128_and(bits, lane_bits), lane_bits)
+			)
+		}
+
+		#[inline(always)]
+		fn from_bitmask_m64s(self, bits: u64) -> Self::m64s {
+			let bits = self.simd128.i64x2_splat(bits as i64);
+			let lane_bits = cast!(u64x2(1, 2));
+			cast!(
+				self.simd128
+					.i64x2_eq(self.simd128.v128_and(bits, lane_bits), lane_bits)
+			)
+		}
+
+		#[inline(always)]
+		fn any_m8s(self, mask: Self::m8s) -> bool {
+			self.simd128.v128_any_true(cast!(mask))
+		}
+
+		#[inline(always)]
+		fn any_m16s(self, mask: Self::m16s) -> bool {
+			self.simd128.v128_any_true(cast!(mask))
+		}
+
+		#[inline(always)]
+		fn any_m32s(self, mask: Self::m32s) -> bool {
+			self.simd128.v128_any_true(cast!(mask))
+		}
+
+		#[inline(always)]
+		fn any_m64s(self, mask: Self::m64s) -> bool {
+			self.simd128.v128_any_true(cast!(mask))
+		}
+
+		#[inline(always)]
+		fn all_m8s(self, mask: Self::m8s) -> bool {
+			self.simd128.i8x16_all_true(cast!(mask))
+		}
+
+		#[inline(always)]
+		fn all_m16s(self, mask: Self::m16s) -> bool {
+			self.simd128.i16x8_all_true(cast!(mask))
+		}
+
+		#[inline(always)]
+		fn all_m32s(self, mask: Self::m32s) -> bool {
+			self.simd128.i32x4_all_true(cast!(mask))
+		}
+
+		#[inline(always)]
+		fn all_m64s(self, mask: Self::m64s) -> bool {
+			self.simd128.i64x2_all_true(cast!(mask))
+		}
+	};
+}
+
+macro_rules! impl_ieee_min_max {
+	() => {
+		// `f32x4.min` and `f32x4.max` already propagate nans and order `-0.0` below `+0.0`
+		#[inline(always)]
+		fn min_nan_propagating_f32s(self, a: Self::f32s, b: Self::f32s) -> Self::f32s {
+			cast!(self.simd128.f32x4_min(cast!(a), cast!(b)))
+		}
+
+		#[inline(always)]
+		fn min_nan_propagating_f64s(self, a: Self::f64s, b: Self::f64s) -> Self::f64s {
+			cast!(self.simd128.f64x2_min(cast!(a), cast!(b)))
+		}
+
+		#[inline(always)]
+		fn max_nan_propagating_f32s(self, a: Self::f32s, b: Self::f32s) -> Self::f32s {
+			cast!(self.simd128.f32x4_max(cast!(a), cast!(b)))
+		}
+
+		#[inline(always)]
+		fn max_nan_propagating_f64s(self, a: Self::f64s, b: Self::f64s) -> Self::f64s {
+			cast!(self.simd128.f64x2_max(cast!(a), cast!(b)))
+		}
+	};
+}
+
+macro_rules! impl_mul_high {
+	() => {
+		#[inline(always)]
+		fn mul_u8s(self, a: Self::u8s, b: Self::u8s) -> Self::u8s {
+			let lo = self.simd128.i16x8_extmul_low_u8x16(cast!(a), cast!(b));
+			let hi = self.simd128.i16x8_extmul_high_u8x16(cast!(a), cast!(b));
+			cast!(
+				self.simd128
+					.i8x16_shuffle::<0, 2, 4, 6, 8, 10, 12, 14, 16, 18, 20, 22, 24, 26, 28, 30>(
+						lo, hi
+					)
+			)
+		}
+
+		#[inline(always)]
+		fn mul_high_u16s(self, a: Self::u16s, b: Self::u16s) -> Self::u16s {
+			let lo = self.simd128.i32x4_extmul_low_u16x8(cast!(a), cast!(b));
+			let hi = self.simd128.i32x4_extmul_high_u16x8(cast!(a), cast!(b));
+			cast!(
+				self.simd128
+					.i8x16_shuffle::<2, 3, 6, 7, 10, 11, 14, 15, 18, 19, 22, 23, 26, 27, 30, 31>(
+						lo, hi
+					)
+			)
+		}
+
+		#[inline(always)]
+		fn mul_high_i16s(self, a: Self::i16s, b: Self::i16s) -> Self::i16s {
+			let lo = self.simd128.i32x4_extmul_low_i16x8(cast!(a), cast!(b));
+			let hi = self.simd128.i32x4_extmul_high_i16x8(cast!(a), cast!(b));
+			cast!(
+				self.simd128
+					.i8x16_shuffle::<2, 3, 6, 7, 10, 11, 14, 15, 18, 19, 22, 23, 26, 27, 30, 31>(
+						lo, hi
+					)
+			)
+		}
+	};
+}
+
+macro_rules! impl_fixed_point {
+	() => {
+		#[inline(always)]
+		fn mul_round_q15_i16s(self, a: Self::i16s, b: Self::i16s) -> Self::i16s {
+			cast!(self.simd128.i16x8_q15mulr_sat(cast!(a), cast!(b)))
+		}
+
+		#[inline(always)]
+		fn mul_round_q31_i32s(self, a: Self::i32s, b: Self::i32s) -> Self::i32s {
+			let round = self.simd128.i64x2_splat(1 << 30);
+			let lo = self.simd128.i64x2_extmul_low_i32x4(cast!(a), cast!(b));
+			let hi = self.simd128.i64x2_extmul_high_i32x4(cast!(a), cast!(b));
+			let lo = self
+				.simd128
+				.i64x2_shr(self.simd128.i64x2_add(lo, round), 31);
+			let hi = self
+				.simd128
+				.i64x2_shr(self.simd128.i64x2_add(hi, round), 31);
+			let c = self
+				.simd128
+				.i8x16_shuffle::<0, 1, 2, 3, 8, 9, 10, 11, 16, 17, 18, 19, 24, 25, 26, 27>(lo, hi);
+
+			// `-1.0 * -1.0` wraps around to `i32::MIN`, which is otherwise unreachable
+			let overflow = self.simd128.i32x4_eq(c, self.simd128.i32x4_splat(i32::MIN));
+			cast!(self.simd128.v128_xor(c, overflow))
+		}
+	};
+}
+
 static NEON_ROTATE_IDX: [u8x16; 16] = [
 	u8x16(0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15),
 	u8x16(15, 0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14),
@@ -122,10 +417,155 @@ macro_rules! splat {
 	};
 }
 
+macro_rules! impl_uniform_shift {
+	($func: ident, $wasm_fn: ident, $ty: ident, $wasm_ty: ident, $factor: literal) => {
+		paste! {
+			#[inline(always)]
+			fn [<wrapping_ $func _ $ty s>](self, a: Self::[<$ty s>], amount: u32) -> Self::[<$ty s>] {
+				cast!(self.simd128.[<$wasm_ty x $factor _ $wasm_fn>](cast!(a), amount))
+			}
+		}
+	};
+	($func: ident, $wasm_fn: ident, $($ty: ident => $wasm_ty: ident x $factor: literal),*) => {
+		$(impl_uniform_shift!($func, $wasm_fn, $ty, $wasm_ty, $factor);)*
+	};
+}
+
+macro_rules! impl_widen_narrow {
+	() => {
+		#[inline(always)]
+		fn widen_low_u8s(self, a: Self::u8s) -> Self::u16s {
+			cast!(self.simd128.i16x8_extend_low_u8x16(cast!(a)))
+		}
+
+		#[inline(always)]
+		fn widen_high_u8s(self, a: Self::u8s) -> Self::u16s {
+			cast!(self.simd128.i16x8_extend_high_u8x16(cast!(a)))
+		}
+
+		#[inline(always)]
+		fn widen_low_i8s(self, a: Self::i8s) -> Self::i16s {
+			cast!(self.simd128.i16x8_extend_low_i8x16(cast!(a)))
+		}
+
+		#[inline(always)]
+		fn widen_high_i8s(self, a: Self::i8s) -> Self::i16s {
+			cast!(self.simd128.i16x8_extend_high_i8x16(cast!(a)))
+		}
+
+		#[inline(always)]
+		fn widen_low_u16s(self, a: Self::u16s) -> Self::u32s {
+			cast!(self.simd128.i32x4_extend_low_u16x8(cast!(a)))
+		}
+
+		#[inline(always)]
+		fn widen_high_u16s(self, a: Self::u16s) -> Self::u32s {
+			cast!(self.simd128.i32x4_extend_high_u16x8(cast!(a)))
+		}
+
+		#[inline(always)]
+		fn widen_low_i16s(self, a: Self::i16s) -> Self::i32s {
+			cast!(self.simd128.i32x4_extend_low_i16x8(cast!(a)))
+		}
+
+		#[inline(always)]
+		fn widen_high_i16s(self, a: Self::i16s) -> Self::i32s {
+			cast!(self.simd128.i32x4_extend_high_i16x8(cast!(a)))
+		}
+
+		#[inline(always)]
+		fn widen_low_u32s(self, a: Self::u32s) -> Self::u64s {
+			cast!(self.simd128.i64x2_extend_low_u32x4(cast!(a)))
+		}
+
+		#[inline(always)]
+		fn widen_high_u32s(self, a: Self::u32s) -> Self::u64s {
+			cast!(self.simd128.i64x2_extend_high_u32x4(cast!(a)))
+		}
+
+		#[inline(always)]
+		fn widen_low_i32s(self, a: Self::i32s) -> Self::i64s {
+			cast!(self.simd128.i64x2_extend_low_i32x4(cast!(a)))
+		}
+
+		#[inline(always)]
+		fn widen_high_i32s(self, a: Self::i32s) -> Self::i64s {
+			cast!(self.simd128.i64x2_extend_high_i32x4(cast!(a)))
+		}
+
+		#[inline(always)]
+		fn widen_low_f32s(self, a: Self::f32s) -> Self::f64s {
+			cast!(self.simd128.f64x2_promote_low_f32x4(cast!(a)))
+		}
+
+		#[inline(always)]
+		fn widen_high_f32s(self, a: Self::f32s) -> Self::f64s {
+			let a = self.simd128.i64x2_shuffle::<1, 1>(cast!(a), cast!(a));
+			cast!(self.simd128.f64x2_promote_low_f32x4(a))
+		}
+
+		#[inline(always)]
+		fn narrow_saturating_i16s(self, a: Self::i16s, b: Self::i16s) -> Self::i8s {
+			cast!(self.simd128.i8x16_narrow_i16x8(cast!(a), cast!(b)))
+		}
+
+		#[inline(always)]
+		fn narrow_saturating_i32s(self, a: Self::i32s, b: Self::i32s) -> Self::i16s {
+			cast!(self.simd128.i16x8_narrow_i32x4(cast!(a), cast!(b)))
+		}
+
+		#[inline(always)]
+		fn narrow_saturating_unsigned_i16s(self, a: Self::i16s, b: Self::i16s) -> Self::u8s {
+			cast!(self.simd128.u8x16_narrow_i16x8(cast!(a), cast!(b)))
+		}
+
+		#[inline(always)]
+		fn narrow_saturating_unsigned_i32s(self, a: Self::i32s, b: Self::i32s) -> Self::u16s {
+			cast!(self.simd128.u16x8_narrow_i32x4(cast!(a), cast!(b)))
+		}
+
+		#[inline(always)]
+		fn narrow_f64s(self, a: Self::f64s, b: Self::f64s) -> Self::f32s {
+			let a = self.simd128.f32x4_demote_f64x2_zero(cast!(a));
+			let b = self.simd128.f32x4_demote_f64x2_zero(cast!(b));
+			cast!(self.simd128.i64x2_shuffle::<0, 2>(a, b))
+		}
+	};
+}
+
+macro_rules! impl_bit_count {
+	() => {
+		#[inline(always)]
+		fn count_ones_u8s(self, a: Self::u8s) -> Self::u8s {
+			cast!(self.simd128.i8x16_popcnt(cast!(a)))
+		}
+
+		#[inline(always)]
+		fn count_ones_u16s(self, a: Self::u16s) -> Self::u16s {
+			cast!(
+				self.simd128
+					.i16x8_extadd_pairwise_u8x16(self.simd128.i8x16_popcnt(cast!(a)))
+			)
+		}
+
+		#[inline(always)]
+		fn count_ones_u32s(self, a: Self::u32s) -> Self::u32s {
+			cast!(
+				self.simd128.i32x4_extadd_pairwise_u16x8(
+					self.simd128
+						.i16x8_extadd_pairwise_u8x16(self.simd128.i8x16_popcnt(cast!(a))),
+				)
+			)
+		}
+	};
+}
+
 impl crate::seal::Seal for Simd128 {}
 impl Simd for Simd128 {
+	type bf16s = u16x8;
 	type c32s = f32x4;
 	type c64s = f64x2;
+	type f16s = u16x8;
 	type f32s = f32x4;
 	type f64s = f64x2;
 	type i16s = i16x8;
@@ -151,6 +591,22 @@ impl Simd for Simd128 {
 
 	impl_binop!(sub, u8 x 16, i8 x 16, u16 x 8, i16 x 8, u32 x 4, i32 x 4, u64 x 2, i64 x 2);
 
+	impl_binop!(saturating_add, add_sat, u8 x 16, i8 x 16, u16 x 8, i16 x 8);
+
+	impl_binop!(saturating_sub, sub_sat, u8 x 16, i8 x 16, u16 x 8, i16 x 8);
+
+	impl_binop!(average, avgr, u8 x 16, u16 x 8);
+
+	impl_compress_expand!();
+
+	impl_mask_query!();
+
+	impl_ieee_min_max!();
+
+	impl_mul_high!();
+
+	impl_fixed_point!();
+
 	impl_binop!(mul, u16 x 8, i16 x 8, u32 x 4, i32 x 4);
 
 	impl_binop_scalar!(mul, u64, i64);
@@ -185,6 +641,15 @@ impl Simd for Simd128 {
 
 	splat!(u8 x 16, i8 x 16, u16 x 8, i16 x 8, u32 x 4, i32 x 4, u64 x 2, i64 x 2, f32 x 4, f64 x 2);
 
+	impl_uniform_shift!(shl, shl, u8 => i8 x 16, u16 => i16 x 8, u32 => i32 x 4, u64 => i64 x 2);
+
+	impl_uniform_shift!(shr, shr, u8 => u8 x 16, u16 => u16 x 8, u32 => u32 x 4, u64 => u64 x 2);
+
+	impl_uniform_shift!(sra, shr, i8 => i8 x 16, i16 => i16 x 8, i32 => i32 x 4, i64 => i64 x 2);
+
+	impl_widen_narrow!();
+	impl_bit_count!();
+
 	#[inline(always)]
 	fn greater_than_u64s(self, a: Self::u64s, b: Self::u64s) -> Self::m64s {
 		let s = self.splat_u64s(i64::MIN as u64);
@@ -1104,6 +1569,18 @@ impl Simd for Simd128 {
 		}
 	}
 
+	#[inline(always)]
+	fn table_lookup_u8s(self, table: Self::u8s, idx: Self::u8s) -> Self::u8s {
+		#[cfg(miri)]
+		{
+			Scalar128b.table_lookup_u8s(table, idx)
+		}
+		#[cfg(not(miri))]
+		{
+			cast!(self.simd128.i8x16_swizzle(cast!(table), cast!(idx)))
+		}
+	}
+
 	#[inline(always)]
 	fn select_u32s(
 		self,
@@ -1130,6 +1607,27 @@ impl Simd for Simd128 {
 		)
 	}
 
+	#[inline(always)]
+	fn select_u8s(self, mask: Self::m8s, if_true: Self::u8s, if_false: Self::u8s) -> Self::u8s {
+		cast!(
+			self.simd128
+				.v128_bitselect(cast!(if_true), cast!(if_false), cast!(mask))
+		)
+	}
+
+	#[inline(always)]
+	fn select_u16s(
+		self,
+		mask: Self::m16s,
+		if_true: Self::u16s,
+		if_false: Self::u16s,
+	) -> Self::u16s {
+		cast!(
+			self.simd128
+				.v128_bitselect(cast!(if_true), cast!(if_false), cast!(mask))
+		)
+	}
+
 	#[inline(always)]
 	fn splat_c32s(self, value: c32) -> Self::c32s {
 		cast!(self.simd128.f64x2_splat(cast!(value)))
@@ -1197,33 +1695,73 @@ impl Simd for Simd128 {
 	}
 
 	#[inline(always)]
-	fn wrapping_dyn_shl_u32s(self, a: Self::u32s, amount: Self::u32s) -> Self::u32s {
-		u32x4(
-			a.0 << amount.0,
-			a.1 << amount.1,
-			a.2 << amount.2,
-			a.3 << amount.3,
-		)
+	fn sqrt_f32s(self, a: Self::f32s) -> Self::f32s {
+		cast!(self.simd128.f32x4_sqrt(cast!(a)))
 	}
 
 	#[inline(always)]
-	fn wrapping_dyn_shr_u32s(self, a: Self::u32s, amount: Self::u32s) -> Self::u32s {
-		u32x4(
-			a.0 >> amount.0,
-			a.1 >> amount.1,
-			a.2 >> amount.2,
-			a.3 >> amount.3,
-		)
+	fn sqrt_f64s(self, a: Self::f64s) -> Self::f64s {
+		cast!(self.simd128.f64x2_sqrt(cast!(a)))
 	}
 
 	#[inline(always)]
-	fn sqrt_f32s(self, a: Self::f32s) -> Self::f32s {
-		cast!(self.simd128.f32x4_sqrt(cast!(a)))
+	fn floor_f32s(self, a: Self::f32s) -> Self::f32s {
+		cast!(self.simd128.f32x4_floor(cast!(a)))
 	}
 
 	#[inline(always)]
-	fn sqrt_f64s(self, a: Self::f64s) -> Self::f64s {
-		cast!(self.simd128.f64x2_sqrt(cast!(a)))
+	fn floor_f64s(self, a: Self::f64s) -> Self::f64s {
+		cast!(self.simd128.f64x2_floor(cast!(a)))
+	}
+
+	#[inline(always)]
+	fn ceil_f32s(self, a: Self::f32s) -> Self::f32s {
+		cast!(self.simd128.f32x4_ceil(cast!(a)))
+	}
+
+	#[inline(always)]
+	fn ceil_f64s(self, a: Self::f64s) -> Self::f64s {
+		cast!(self.simd128.f64x2_ceil(cast!(a)))
+	}
+
+	#[inline(always)]
+	fn trunc_f32s(self, a: Self::f32s) -> Self::f32s {
+		cast!(self.simd128.f32x4_trunc(cast!(a)))
+	}
+
+	#[inline(always)]
+	fn trunc_f64s(self, a: Self::f64s) -> Self::f64s {
+		cast!(self.simd128.f64x2_trunc(cast!(a)))
+	}
+
+	#[inline(always)]
+	fn round_ties_even_f32s(self, a: Self::f32s) -> Self::f32s {
+		cast!(self.simd128.f32x4_nearest(cast!(a)))
+	}
+
+	#[inline(always)]
+	fn round_ties_even_f64s(self, a: Self::f64s) -> Self::f64s {
+		cast!(self.simd128.f64x2_nearest(cast!(a)))
+	}
+
+	#[inline(always)]
+	fn convert_f32s_i32s(self, a: Self::f32s) -> Self::i32s {
+		cast!(self.simd128.i32x4_trunc_sat_f32x4(cast!(a)))
+	}
+
+	#[inline(always)]
+	fn convert_f32s_u32s(self, a: Self::f32s) -> Self::u32s {
+		cast!(self.simd128.u32x4_trunc_sat_f32x4(cast!(a)))
+	}
+
+	#[inline(always)]
+	fn convert_i32s_f32s(self, a: Self::i32s) -> Self::f32s {
+		cast!(self.simd128.f32x4_convert_i32x4(cast!(a)))
+	}
+
+	#[inline(always)]
+	fn convert_u32s_f32s(self, a: Self::u32s) -> Self::f32s {
+		cast!(self.simd128.f32x4_convert_u32x4(cast!(a)))
 	}
 }
 
@@ -1231,8 +1769,10 @@ impl Simd for Simd128 {
 impl crate::seal::Seal for RelaxedSimd {}
 #[cfg(feature = "relaxed-simd")]
 impl Simd for RelaxedSimd {
+	type bf16s = u16x8;
 	type c32s = f32x4;
 	type c64s = f64x2;
+	type f16s = u16x8;
 	type f32s = f32x4;
 	type f64s = f64x2;
 	type i16s = i16x8;
@@ -1258,6 +1798,22 @@ impl Simd for RelaxedSimd {
 
 	impl_binop!(sub, u8 x 16, i8 x 16, u16 x 8, i16 x 8, u32 x 4, i32 x 4, u64 x 2, i64 x 2);
 
+	impl_binop!(saturating_add, add_sat, u8 x 16, i8 x 16, u16 x 8, i16 x 8);
+
+	impl_binop!(saturating_sub, sub_sat, u8 x 16, i8 x 16, u16 x 8, i16 x 8);
+
+	impl_binop!(average, avgr, u8 x 16, u16 x 8);
+
+	impl_compress_expand!();
+
+	impl_mask_query!();
+
+	impl_ieee_min_max!();
+
+	impl_mul_high!();
+
+	impl_fixed_point!();
+
 	impl_binop!(mul, u16 x 8, i16 x 8, u32 x 4, i32 x 4);
 
 	impl_binop_scalar!(mul, u64, i64);
@@ -1292,6 +1848,15 @@ impl Simd for RelaxedSimd {
 
 	splat!(u8 x 16, i8 x 16, u16 x 8, i16 x 8, u32 x 4, i32 x 4, u64 x 2, i64 x 2, f32 x 4, f64 x 2);
 
+	impl_uniform_shift!(shl, shl, u8 => i8 x 16, u16 => i16 x 8, u32 => i32 x 4, u64 => i64 x 2);
+
+	impl_uniform_shift!(shr, shr, u8 => u8 x 16, u16 => u16 x 8, u32 => u32 x 4, u64 => u64 x 2);
+
+	impl_uniform_shift!(sra, shr, i8 => i8 x 16, i16 => i16 x 8, i32 => i32 x 4, i64 => i64 x 2);
+
+	impl_widen_narrow!();
+	impl_bit_count!();
+
 	#[inline(always)]
 	fn greater_than_u64s(self, a: Self::u64s, b: Self::u64s) -> Self::m64s {
 		let s = self.splat_u64s(i64::MIN as u64);
@@ -2254,6 +2819,18 @@ impl Simd for RelaxedSimd {
 		}
 	}
 
+	#[inline(always)]
+	fn table_lookup_u8s(self, table: Self::u8s, idx: Self::u8s) -> Self::u8s {
+		#[cfg(miri)]
+		{
+			Scalar128b.table_lookup_u8s(table, idx)
+		}
+		#[cfg(not(miri))]
+		{
+			cast!(self.simd128.i8x16_swizzle(cast!(table), cast!(idx)))
+		}
+	}
+
 	#[inline(always)]
 	fn select_u32s(
 		self,
@@ -2280,6 +2857,27 @@ impl Simd for RelaxedSimd {
 		)
 	}
 
+	#[inline(always)]
+	fn select_u8s(self, mask: Self::m8s, if_true: Self::u8s, if_false: Self::u8s) -> Self::u8s {
+		cast!(
+			self.simd128
+				.v128_bitselect(cast!(if_true), cast!(if_false), cast!(mask))
+		)
+	}
+
+	#[inline(always)]
+	fn select_u16s(
+		self,
+		mask: Self::m16s,
+		if_true: Self::u16s,
+		if_false: Self::u16s,
+	) -> Self::u16s {
+		cast!(
+			self.simd128
+				.v128_bitselect(cast!(if_true), cast!(if_false), cast!(mask))
+		)
+	}
+
 	#[inline(always)]
 	fn splat_c32s(self, value: c32) -> Self::c32s {
 		cast!(self.simd128.f64x2_splat(cast!(value)))
@@ -2347,33 +2945,73 @@ impl Simd for RelaxedSimd {
 	}
 
 	#[inline(always)]
-	fn wrapping_dyn_shl_u32s(self, a: Self::u32s, amount: Self::u32s) -> Self::u32s {
-		u32x4(
-			a.0 << amount.0,
-			a.1 << amount.1,
-			a.2 << amount.2,
-			a.3 << amount.3,
-		)
+	fn sqrt_f32s(self, a: Self::f32s) -> Self::f32s {
+		cast!(self.simd128.f32x4_sqrt(cast!(a)))
 	}
 
 	#[inline(always)]
-	fn wrapping_dyn_shr_u32s(self, a: Self::u32s, amount: Self::u32s) -> Self::u32s {
-		u32x4(
-			a.0 >> amount.0,
-			a.1 >> amount.1,
-			a.2 >> amount.2,
-			a.3 >> amount.3,
-		)
+	fn sqrt_f64s(self, a: Self::f64s) -> Self::f64s {
+		cast!(self.simd128.f64x2_sqrt(cast!(a)))
 	}
 
 	#[inline(always)]
-	fn sqrt_f32s(self, a: Self::f32s) -> Self::f32s {
-		cast!(self.simd128.f32x4_sqrt(cast!(a)))
+	fn floor_f32s(self, a: Self::f32s) -> Self::f32s {
+		cast!(self.simd128.f32x4_floor(cast!(a)))
 	}
 
 	#[inline(always)]
-	fn sqrt_f64s(self, a: Self::f64s) -> Self::f64s {
-		cast!(self.simd128.f64x2_sqrt(cast!(a)))
+	fn floor_f64s(self, a: Self::f64s) -> Self::f64s {
+		cast!(self.simd128.f64x2_floor(cast!(a)))
+	}
+
+	#[inline(always)]
+	fn ceil_f32s(self, a: Self::f32s) -> Self::f32s {
+		cast!(self.simd128.f32x4_ceil(cast!(a)))
+	}
+
+	#[inline(always)]
+	fn ceil_f64s(self, a: Self::f64s) -> Self::f64s {
+		cast!(self.simd128.f64x2_ceil(cast!(a)))
+	}
+
+	#[inline(always)]
+	fn trunc_f32s(self, a: Self::f32s) -> Self::f32s {
+		cast!(self.simd128.f32x4_trunc(cast!(a)))
+	}
+
+	#[inline(always)]
+	fn trunc_f64s(self, a: Self::f64s) -> Self::f64s {
+		cast!(self.simd128.f64x2_trunc(cast!(a)))
+	}
+
+	#[inline(always)]
+	fn round_ties_even_f32s(self, a: Self::f32s) -> Self::f32s {
+		cast!(self.simd128.f32x4_nearest(cast!(a)))
+	}
+
+	#[inline(always)]
+	fn round_ties_even_f64s(self, a: Self::f64s) -> Self::f64s {
+		cast!(self.simd128.f64x2_nearest(cast!(a)))
+	}
+
+	#[inline(always)]
+	fn convert_f32s_i32s(self, a: Self::f32s) -> Self::i32s {
+		cast!(self.simd128.i32x4_trunc_sat_f32x4(cast!(a)))
+	}
+
+	#[inline(always)]
+	fn convert_f32s_u32s(self, a: Self::f32s) -> Self::u32s {
+		cast!(self.simd128.u32x4_trunc_sat_f32x4(cast!(a)))
+	}
+
+	#[inline(always)]
+	fn convert_i32s_f32s(self, a: Self::i32s) -> Self::f32s {
+		cast!(self.simd128.f32x4_convert_i32x4(cast!(a)))
+	}
+
+	#[inline(always)]
+	fn convert_u32s_f32s(self, a: Self::u32s) -> Self::f32s {
+		cast!(self.simd128.f32x4_convert_u32x4(cast!(a)))
 	}
 }
 
@@ -2481,20 +3119,94 @@ pub enum Arch {
 	Simd128(Simd128),
 }
 
+/// wasm instruction set levels, ordered from the least to the most capable.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[non_exhaustive]
+pub enum ArchLevel {
+	Scalar,
+	Simd128,
+	RelaxedSimd,
+}
+
+impl ArchLevel {
+	const NAMES: &[(&str, Self)] = &[
+		("scalar", Self::Scalar),
+		("simd128", Self::Simd128),
+		("relaxed-simd", Self::RelaxedSimd),
+	];
+
+	/// Parses a level from its name, ignoring case: `"scalar"`, `"simd128"` or `"relaxed-simd"`.
+	#[inline]
+	pub fn from_name(name: &str) -> Option<Self> {
+		let name = name.trim();
+		Self::NAMES
+			.iter()
+			.find(|(level_name, _)| level_name.eq_ignore_ascii_case(name))
+			.map(|&(_, level)| level)
+	}
+
+	/// Returns the name of the level, as accepted by [`ArchLevel::from_name`].
+	#[inline]
+	pub fn name(self) -> &'static str {
+		Self::NAMES
+			.iter()
+			.find(|&&(_, level)| level == self)
+			.unwrap()
+			.0
+	}
+}
+
 impl Arch {
 	/// Detects the best available instruction set.
+	///
+	/// With the `std` feature, a lower level can be requested through the `PULP_ARCH` environment
+	/// variable (e.g. `PULP_ARCH=simd128`), which is read once and clamped to what the runtime
+	/// supports. Unknown names are ignored.
 	#[inline]
 	pub fn new() -> Self {
+		#[cfg(feature = "std")]
+		if let Some(level) = crate::arch_override() {
+			return Self::with_max_level(level);
+		}
+		Self::with_max_level(ArchLevel::RelaxedSimd)
+	}
+
+	/// Detects the best available instruction set that does not exceed `level`.
+	#[inline]
+	pub fn with_max_level(level: ArchLevel) -> Self {
 		#[cfg(feature = "relaxed-simd")]
-		if let Some(simd) = RelaxedSimd::try_new() {
-			return Self::RelaxedSimd(simd);
+		if level >= ArchLevel::RelaxedSimd {
+			if let Some(simd) = RelaxedSimd::try_new() {
+				return Self::RelaxedSimd(simd);
+			}
 		}
-		if let Some(simd) = Simd128::try_new() {
-			return Self::Simd128(simd);
+		if level >= ArchLevel::Simd128 {
+			if let Some(simd) = Simd128::try_new() {
+				return Self::Simd128(simd);
+			}
 		}
 		Self::Scalar
 	}
 
+	/// Detects the best available instruction set that does not exceed the level named `name`, see
+	/// [`ArchLevel::from_name`]. Returns `None` if the name is not recognized.
+	#[inline]
+	pub fn from_name(name: &str) -> Option<Self> {
+		ArchLevel::from_name(name).map(Self::with_max_level)
+	}
+
+	/// Returns the level of the instruction set.
+	#[inline]
+	pub fn level(self) -> ArchLevel {
+		match self {
+			#[cfg(feature = "relaxed-simd")]
+			Arch::RelaxedSimd(_) => ArchLevel::RelaxedSimd,
+			Arch::Simd128(_) => ArchLevel::Simd128,
+
+			Arch::Scalar => ArchLevel::Scalar,
+		}
+	}
+
 	/// Detects the best available instruction set.
 	#[inline(always)]
 	pub fn dispatch<Op: WithSimd>(self, op: Op) -> Op::Output {
@@ -1,6 +1,10 @@
 use super::*;
 #[cfg(target_arch = "x86_64")]
 use crate::core_arch::x86::Avx2;
+#[cfg(all(feature = "x86-v4", target_feature = "avx512vbmi"))]
+use crate::core_arch::x86::Avx512vbmi;
+#[cfg(all(feature = "x86-v4", target_feature = "avx512vbmi2"))]
+use crate::core_arch::x86::Avx512vbmi2;
 
 #[cfg(target_arch = "x86")]
 use core::arch::x86::*;
@@ -241,6 +245,2019 @@ macro_rules! binop_256_full {
 	};
 }
 
+macro_rules! impl_uniform_shift {
+	($func: ident, $inherent: ident, $ty: ident, $factor: literal) => {
+		paste! {
+			#[inline(always)]
+			fn [<wrapping_ $func _ $ty s>](self, a: Self::[<$ty s>], amount: u32) -> Self::[<$ty s>] {
+				cast!(self.[<$inherent _ $ty x $factor>](a, u64x2((amount % $ty::BITS) as u64, 0)))
+			}
+		}
+	};
+	($func: ident, $inherent: ident, $($ty: ident x $factor: literal),*) => {
+		$(impl_uniform_shift!($func, $inherent, $ty, $factor);)*
+	};
+}
+
+macro_rules! impl_dyn_shift {
+	($func: ident, $inherent: ident, $ty: ident, $amount: ident, $factor: literal) => {
+		paste! {
+			#[inline(always)]
+			fn [<wrapping_dyn_ $func _ $ty s>](self, a: Self::[<$ty s>], amount: Self::[<$amount s>]) -> Self::[<$ty s>] {
+				let amount = self.[<and_ $amount x $factor>](amount, self.[<splat_ $amount x $factor>]($ty::BITS as $amount - 1));
+				self.[<$inherent _dyn_ $ty x $factor>](a, cast!(amount))
+			}
+		}
+	};
+	($func: ident, $inherent: ident, $($ty: ident x $factor: literal => $amount: ident),*) => {
+		$(impl_dyn_shift!($func, $inherent, $ty, $amount, $factor);)*
+	};
+}
+
+// 8-bit shifts go through the 16-bit ones, masking off the bits that crossed over from the
+// neighboring lane. Arithmetic right shifts without a native instruction flip the sign bit,
+// shift logically, then subtract the shifted sign bit back out.
+macro_rules! impl_derived_shift {
+	(u8) => {
+		#[inline(always)]
+		fn wrapping_shl_u8s(self, a: Self::u8s, amount: u32) -> Self::u8s {
+			let amount = amount % 8;
+			self.and_u8s(
+				cast!(self.wrapping_shl_u16s(cast!(a), amount)),
+				self.splat_u8s(0xFF << amount),
+			)
+		}
+
+		#[inline(always)]
+		fn wrapping_shr_u8s(self, a: Self::u8s, amount: u32) -> Self::u8s {
+			let amount = amount % 8;
+			self.and_u8s(
+				cast!(self.wrapping_shr_u16s(cast!(a), amount)),
+				self.splat_u8s(0xFF >> amount),
+			)
+		}
+
+		#[inline(always)]
+		fn wrapping_sra_i8s(self, a: Self::i8s, amount: u32) -> Self::i8s {
+			let amount = amount % 8;
+			let sign = self.splat_u8s(1 << 7);
+			let a = self.xor_u8s(self.transmute_u8s_i8s(a), sign);
+			self.transmute_i8s_u8s(self.sub_u8s(
+				self.wrapping_shr_u8s(a, amount),
+				self.splat_u8s((1 << 7) >> amount),
+			))
+		}
+	};
+	(i64) => {
+		#[inline(always)]
+		fn wrapping_sra_i64s(self, a: Self::i64s, amount: u32) -> Self::i64s {
+			let amount = amount % 64;
+			let sign = self.splat_u64s(1 << 63);
+			let a = self.xor_u64s(self.transmute_u64s_i64s(a), sign);
+			self.transmute_i64s_u64s(self.sub_u64s(
+				self.wrapping_shr_u64s(a, amount),
+				self.splat_u64s((1 << 63) >> amount),
+			))
+		}
+	};
+	(dyn i64) => {
+		#[inline(always)]
+		fn wrapping_dyn_sra_i64s(self, a: Self::i64s, amount: Self::u64s) -> Self::i64s {
+			let sign = self.splat_u64s(1 << 63);
+			let a = self.xor_u64s(self.transmute_u64s_i64s(a), sign);
+			self.transmute_i64s_u64s(self.sub_u64s(
+				self.wrapping_dyn_shr_u64s(a, amount),
+				self.wrapping_dyn_shr_u64s(sign, amount),
+			))
+		}
+	};
+	($($($ty: ident)+),*) => {
+		$(impl_derived_shift!($($ty)+);)*
+	};
+}
+
+// Truncates `a` and returns the low 64 bits of the resulting integer, for `|a| < 2^83`.
+#[inline(always)]
+fn convert_f64s_u64s_split<S: Simd>(simd: S, a: S::f64s) -> S::u64s {
+	// 2^52 + 2^51, adding it to an integer `|x| < 2^51` leaves `x` in the low bits of the mantissa
+	let magic = simd.splat_f64s(6755399441055744.0);
+	let to_int = |x| {
+		simd.sub_u64s(
+			simd.transmute_u64s_f64s(simd.add_f64s(x, magic)),
+			simd.transmute_u64s_f64s(magic),
+		)
+	};
+
+	let a = simd.trunc_f64s(a);
+	let hi = simd.floor_f64s(simd.mul_f64s(a, simd.splat_f64s(1.0 / 4294967296.0)));
+	let lo = simd.sub_f64s(a, simd.mul_f64s(hi, simd.splat_f64s(4294967296.0)));
+	simd.add_u64s(simd.shl_const_u64s::<32>(to_int(hi)), to_int(lo))
+}
+
+// `cvtt*` instructions return `MIN` for NaN and out of range inputs, so the results are fixed up
+// to saturate like `as` does. Unsigned and 64-bit conversions without a native instruction are
+// built from the 32-bit signed ones, or from splitting the value into two 32-bit halves that can
+// each be converted exactly through the `2^52` exponent trick.
+macro_rules! impl_derived_convert {
+	(i32 x $factor: literal) => {
+		paste! {
+			#[inline(always)]
+			fn convert_f32s_i32s(self, a: Self::f32s) -> Self::i32s {
+				let r = self.[<convert_f32x $factor _to_i32x $factor>](a);
+				let r = self.select_i32s(
+					self.greater_than_or_equal_f32s(a, self.splat_f32s(2147483648.0)),
+					self.splat_i32s(i32::MAX),
+					r,
+				);
+				self.select_i32s(self.equal_f32s(a, a), r, self.splat_i32s(0))
+			}
+
+			#[inline(always)]
+			fn convert_i32s_f32s(self, a: Self::i32s) -> Self::f32s {
+				self.[<convert_i32x $factor _to_f32x $factor>](a)
+			}
+		}
+	};
+	(u32) => {
+		#[inline(always)]
+		fn convert_f32s_u32s(self, a: Self::f32s) -> Self::u32s {
+			let big = self.greater_than_or_equal_f32s(a, self.splat_f32s(2147483648.0));
+			let a = self.select_f32s(big, self.sub_f32s(a, self.splat_f32s(2147483648.0)), a);
+			let r = self.max_i32s(self.convert_f32s_i32s(a), self.splat_i32s(0));
+			self.xor_u32s(
+				self.transmute_u32s_i32s(r),
+				self.select_u32s(big, self.splat_u32s(1 << 31), self.splat_u32s(0)),
+			)
+		}
+
+		#[inline(always)]
+		fn convert_u32s_f32s(self, a: Self::u32s) -> Self::f32s {
+			let hi = self.convert_i32s_f32s(self.transmute_i32s_u32s(self.shr_const_u32s::<16>(a)));
+			let lo = self.convert_i32s_f32s(self.transmute_i32s_u32s(self.and_u32s(a, self.splat_u32s(0xFFFF))));
+			self.add_f32s(self.mul_f32s(hi, self.splat_f32s(65536.0)), lo)
+		}
+	};
+	(i64) => {
+		#[inline(always)]
+		fn convert_f64s_i64s(self, a: Self::f64s) -> Self::i64s {
+			let r = convert_f64s_u64s_split(self, a);
+			let r = self.select_u64s(
+				self.greater_than_or_equal_f64s(a, self.splat_f64s(9223372036854775808.0)),
+				self.splat_u64s(i64::MAX as u64),
+				r,
+			);
+			let r = self.select_u64s(
+				self.less_than_f64s(a, self.splat_f64s(-9223372036854775808.0)),
+				self.splat_u64s(i64::MIN as u64),
+				r,
+			);
+			self.transmute_i64s_u64s(self.select_u64s(self.equal_f64s(a, a), r, self.splat_u64s(0)))
+		}
+
+		#[inline(always)]
+		fn convert_f64s_u64s(self, a: Self::f64s) -> Self::u64s {
+			let r = convert_f64s_u64s_split(self, a);
+			let r = self.select_u64s(
+				self.greater_than_or_equal_f64s(a, self.splat_f64s(18446744073709551616.0)),
+				self.splat_u64s(u64::MAX),
+				r,
+			);
+			self.select_u64s(self.greater_than_f64s(a, self.splat_f64s(-1.0)), r, self.splat_u64s(0))
+		}
+
+		#[inline(always)]
+		fn convert_i64s_f64s(self, a: Self::i64s) -> Self::f64s {
+			let a = self.transmute_u64s_i64s(a);
+			let sign = self.and_u64s(a, self.splat_u64s(1 << 63));
+			let mask = self.transmute_u64s_i64s(self.sra_const_i64s::<63>(self.transmute_i64s_u64s(a)));
+			let abs = self.sub_u64s(self.xor_u64s(a, mask), mask);
+			self.or_f64s(self.convert_u64s_f64s(abs), self.transmute_f64s_u64s(sign))
+		}
+
+		#[inline(always)]
+		fn convert_u64s_f64s(self, a: Self::u64s) -> Self::f64s {
+			// 2^52 + lo
+			let lo = self.or_u64s(self.and_u64s(a, self.splat_u64s(0xFFFF_FFFF)), self.splat_u64s(0x4330_0000_0000_0000));
+			// 2^84 + hi * 2^32
+			let hi = self.or_u64s(self.shr_const_u64s::<32>(a), self.splat_u64s(0x4530_0000_0000_0000));
+			let hi = self.sub_f64s(
+				self.transmute_f64s_u64s(hi),
+				self.splat_f64s(f64::from_bits(0x4530_0000_0010_0000)),
+			);
+			self.add_f64s(hi, self.transmute_f64s_u64s(lo))
+		}
+	};
+	($($ty: ident $(x $factor: literal)?),*) => {
+		$(impl_derived_convert!($ty $(x $factor)?);)*
+	};
+}
+
+// `pack` and `shuffle` instructions operate on each 128-bit lane separately, so the 256-bit
+// versions need an extra cross-lane permutation to keep the lanes of `a` before the lanes of `b`,
+// while the 512-bit versions use the dedicated AVX-512 down-conversions instead.
+// The remaining narrowing conversions are derived from the signed to unsigned saturating ones, by
+// first masking or clamping the inputs to the output range.
+macro_rules! impl_widen_narrow {
+	(128) => {
+		#[inline(always)]
+		fn widen_low_u8s(self, a: Self::u8s) -> Self::u16s {
+			self.convert_u8x16_to_u16x8(a)
+		}
+
+		#[inline(always)]
+		fn widen_high_u8s(self, a: Self::u8s) -> Self::u16s {
+			self.convert_u8x16_to_u16x8(cast!(self.sse2._mm_unpackhi_epi64(cast!(a), cast!(a))))
+		}
+
+		#[inline(always)]
+		fn widen_low_i8s(self, a: Self::i8s) -> Self::i16s {
+			self.convert_i8x16_to_i16x8(a)
+		}
+
+		#[inline(always)]
+		fn widen_high_i8s(self, a: Self::i8s) -> Self::i16s {
+			self.convert_i8x16_to_i16x8(cast!(self.sse2._mm_unpackhi_epi64(cast!(a), cast!(a))))
+		}
+
+		#[inline(always)]
+		fn widen_low_u16s(self, a: Self::u16s) -> Self::u32s {
+			self.convert_u16x8_to_u32x4(a)
+		}
+
+		#[inline(always)]
+		fn widen_high_u16s(self, a: Self::u16s) -> Self::u32s {
+			self.convert_u16x8_to_u32x4(cast!(self.sse2._mm_unpackhi_epi64(cast!(a), cast!(a))))
+		}
+
+		#[inline(always)]
+		fn widen_low_i16s(self, a: Self::i16s) -> Self::i32s {
+			self.convert_i16x8_to_i32x4(a)
+		}
+
+		#[inline(always)]
+		fn widen_high_i16s(self, a: Self::i16s) -> Self::i32s {
+			self.convert_i16x8_to_i32x4(cast!(self.sse2._mm_unpackhi_epi64(cast!(a), cast!(a))))
+		}
+
+		#[inline(always)]
+		fn widen_low_u32s(self, a: Self::u32s) -> Self::u64s {
+			self.convert_u32x4_to_u64x2(a)
+		}
+
+		#[inline(always)]
+		fn widen_high_u32s(self, a: Self::u32s) -> Self::u64s {
+			self.convert_u32x4_to_u64x2(cast!(self.sse2._mm_unpackhi_epi64(cast!(a), cast!(a))))
+		}
+
+		#[inline(always)]
+		fn widen_low_i32s(self, a: Self::i32s) -> Self::i64s {
+			self.convert_i32x4_to_i64x2(a)
+		}
+
+		#[inline(always)]
+		fn widen_high_i32s(self, a: Self::i32s) -> Self::i64s {
+			self.convert_i32x4_to_i64x2(cast!(self.sse2._mm_unpackhi_epi64(cast!(a), cast!(a))))
+		}
+
+		#[inline(always)]
+		fn widen_low_f32s(self, a: Self::f32s) -> Self::f64s {
+			self.convert_f32x4_to_f64x2(a)
+		}
+
+		#[inline(always)]
+		fn widen_high_f32s(self, a: Self::f32s) -> Self::f64s {
+			self.convert_f32x4_to_f64x2(cast!(self.sse._mm_movehl_ps(cast!(a), cast!(a))))
+		}
+
+		#[inline(always)]
+		fn narrow_saturating_i16s(self, a: Self::i16s, b: Self::i16s) -> Self::i8s {
+			self.pack_with_signed_saturation_i16x8(a, b)
+		}
+
+		#[inline(always)]
+		fn narrow_saturating_i32s(self, a: Self::i32s, b: Self::i32s) -> Self::i16s {
+			self.pack_with_signed_saturation_i32x4(a, b)
+		}
+
+		#[inline(always)]
+		fn narrow_saturating_unsigned_i16s(self, a: Self::i16s, b: Self::i16s) -> Self::u8s {
+			self.pack_with_unsigned_saturation_i16x8(a, b)
+		}
+
+		#[inline(always)]
+		fn narrow_saturating_unsigned_i32s(self, a: Self::i32s, b: Self::i32s) -> Self::u16s {
+			self.pack_with_unsigned_saturation_i32x4(a, b)
+		}
+
+		#[inline(always)]
+		fn narrow_u64s(self, a: Self::u64s, b: Self::u64s) -> Self::u32s {
+			cast!(self.sse._mm_shuffle_ps::<0b10_00_10_00>(cast!(a), cast!(b)))
+		}
+
+		#[inline(always)]
+		fn narrow_f64s(self, a: Self::f64s, b: Self::f64s) -> Self::f32s {
+			cast!(self.sse._mm_movelh_ps(
+				cast!(self.convert_f64x2_to_f32x4(a)),
+				cast!(self.convert_f64x2_to_f32x4(b))
+			))
+		}
+
+		impl_widen_narrow!(derived);
+	};
+	(256) => {
+		#[inline(always)]
+		fn widen_low_u8s(self, a: Self::u8s) -> Self::u16s {
+			let a: [u8x16; 2] = cast!(a);
+			self.convert_u8x16_to_u16x16(a[0])
+		}
+
+		#[inline(always)]
+		fn widen_high_u8s(self, a: Self::u8s) -> Self::u16s {
+			let a: [u8x16; 2] = cast!(a);
+			self.convert_u8x16_to_u16x16(a[1])
+		}
+
+		#[inline(always)]
+		fn widen_low_i8s(self, a: Self::i8s) -> Self::i16s {
+			let a: [i8x16; 2] = cast!(a);
+			self.convert_i8x16_to_i16x16(a[0])
+		}
+
+		#[inline(always)]
+		fn widen_high_i8s(self, a: Self::i8s) -> Self::i16s {
+			let a: [i8x16; 2] = cast!(a);
+			self.convert_i8x16_to_i16x16(a[1])
+		}
+
+		#[inline(always)]
+		fn widen_low_u16s(self, a: Self::u16s) -> Self::u32s {
+			let a: [u16x8; 2] = cast!(a);
+			self.convert_u16x8_to_u32x8(a[0])
+		}
+
+		#[inline(always)]
+		fn widen_high_u16s(self, a: Self::u16s) -> Self::u32s {
+			let a: [u16x8; 2] = cast!(a);
+			self.convert_u16x8_to_u32x8(a[1])
+		}
+
+		#[inline(always)]
+		fn widen_low_i16s(self, a: Self::i16s) -> Self::i32s {
+			let a: [i16x8; 2] = cast!(a);
+			self.convert_i16x8_to_i32x8(a[0])
+		}
+
+		#[inline(always)]
+		fn widen_high_i16s(self, a: Self::i16s) -> Self::i32s {
+			let a: [i16x8; 2] = cast!(a);
+			self.convert_i16x8_to_i32x8(a[1])
+		}
+
+		#[inline(always)]
+		fn widen_low_u32s(self, a: Self::u32s) -> Self::u64s {
+			let a: [u32x4; 2] = cast!(a);
+			self.convert_u32x4_to_u64x4(a[0])
+		}
+
+		#[inline(always)]
+		fn widen_high_u32s(self, a: Self::u32s) -> Self::u64s {
+			let a: [u32x4; 2] = cast!(a);
+			self.convert_u32x4_to_u64x4(a[1])
+		}
+
+		#[inline(always)]
+		fn widen_low_i32s(self, a: Self::i32s) -> Self::i64s {
+			let a: [i32x4; 2] = cast!(a);
+			self.convert_i32x4_to_i64x4(a[0])
+		}
+
+		#[inline(always)]
+		fn widen_high_i32s(self, a: Self::i32s) -> Self::i64s {
+			let a: [i32x4; 2] = cast!(a);
+			self.convert_i32x4_to_i64x4(a[1])
+		}
+
+		#[inline(always)]
+		fn widen_low_f32s(self, a: Self::f32s) -> Self::f64s {
+			let a: [f32x4; 2] = cast!(a);
+			self.convert_f32x4_to_f64x4(a[0])
+		}
+
+		#[inline(always)]
+		fn widen_high_f32s(self, a: Self::f32s) -> Self::f64s {
+			let a: [f32x4; 2] = cast!(a);
+			self.convert_f32x4_to_f64x4(a[1])
+		}
+
+		#[inline(always)]
+		fn narrow_saturating_i16s(self, a: Self::i16s, b: Self::i16s) -> Self::i8s {
+			let c = self.pack_with_signed_saturation_i16x16(a, b);
+			cast!(
+				self.avx2
+					._mm256_permute4x64_epi64::<0b11_01_10_00>(cast!(c))
+			)
+		}
+
+		#[inline(always)]
+		fn narrow_saturating_i32s(self, a: Self::i32s, b: Self::i32s) -> Self::i16s {
+			let c = self.pack_with_signed_saturation_i32x8(a, b);
+			cast!(
+				self.avx2
+					._mm256_permute4x64_epi64::<0b11_01_10_00>(cast!(c))
+			)
+		}
+
+		#[inline(always)]
+		fn narrow_saturating_unsigned_i16s(self, a: Self::i16s, b: Self::i16s) -> Self::u8s {
+			let c = self.pack_with_unsigned_saturation_i16x16(a, b);
+			cast!(
+				self.avx2
+					._mm256_permute4x64_epi64::<0b11_01_10_00>(cast!(c))
+			)
+		}
+
+		#[inline(always)]
+		fn narrow_saturating_unsigned_i32s(self, a: Self::i32s, b: Self::i32s) -> Self::u16s {
+			let c = self.pack_with_unsigned_saturation_i32x8(a, b);
+			cast!(
+				self.avx2
+					._mm256_permute4x64_epi64::<0b11_01_10_00>(cast!(c))
+			)
+		}
+
+		#[inline(always)]
+		fn narrow_u64s(self, a: Self::u64s, b: Self::u64s) -> Self::u32s {
+			let c = self
+				.avx
+				._mm256_shuffle_ps::<0b10_00_10_00>(cast!(a), cast!(b));
+			cast!(
+				self.avx2
+					._mm256_permute4x64_epi64::<0b11_01_10_00>(cast!(c))
+			)
+		}
+
+		#[inline(always)]
+		fn narrow_f64s(self, a: Self::f64s, b: Self::f64s) -> Self::f32s {
+			cast!([
+				self.convert_f64x4_to_f32x4(a),
+				self.convert_f64x4_to_f32x4(b)
+			])
+		}
+
+		impl_widen_narrow!(derived);
+	};
+	(512) => {
+		#[inline(always)]
+		fn widen_low_u8s(self, a: Self::u8s) -> Self::u16s {
+			let a: [u8x32; 2] = cast!(a);
+			self.convert_u8x32_to_u16x32(a[0])
+		}
+
+		#[inline(always)]
+		fn widen_high_u8s(self, a: Self::u8s) -> Self::u16s {
+			let a: [u8x32; 2] = cast!(a);
+			self.convert_u8x32_to_u16x32(a[1])
+		}
+
+		#[inline(always)]
+		fn widen_low_i8s(self, a: Self::i8s) -> Self::i16s {
+			let a: [i8x32; 2] = cast!(a);
+			self.convert_i8x32_to_i16x32(a[0])
+		}
+
+		#[inline(always)]
+		fn widen_high_i8s(self, a: Self::i8s) -> Self::i16s {
+			let a: [i8x32; 2] = cast!(a);
+			self.convert_i8x32_to_i16x32(a[1])
+		}
+
+		#[inline(always)]
+		fn widen_low_u16s(self, a: Self::u16s) -> Self::u32s {
+			let a: [u16x16; 2] = cast!(a);
+			self.convert_u16x16_to_u32x16(a[0])
+		}
+
+		#[inline(always)]
+		fn widen_high_u16s(self, a: Self::u16s) -> Self::u32s {
+			let a: [u16x16; 2] = cast!(a);
+			self.convert_u16x16_to_u32x16(a[1])
+		}
+
+		#[inline(always)]
+		fn widen_low_i16s(self, a: Self::i16s) -> Self::i32s {
+			let a: [i16x16; 2] = cast!(a);
+			self.convert_i16x16_to_i32x16(a[0])
+		}
+
+		#[inline(always)]
+		fn widen_high_i16s(self, a: Self::i16s) -> Self::i32s {
+			let a: [i16x16; 2] = cast!(a);
+			self.convert_i16x16_to_i32x16(a[1])
+		}
+
+		#[inline(always)]
+		fn widen_low_u32s(self, a: Self::u32s) -> Self::u64s {
+			let a: [u32x8; 2] = cast!(a);
+			self.convert_u32x8_to_u64x8(a[0])
+		}
+
+		#[inline(always)]
+		fn widen_high_u32s(self, a: Self::u32s) -> Self::u64s {
+			let a: [u32x8; 2] = cast!(a);
+			self.convert_u32x8_to_u64x8(a[1])
+		}
+
+		#[inline(always)]
+		fn widen_low_i32s(self, a: Self::i32s) -> Self::i64s {
+			let a: [i32x8; 2] = cast!(a);
+			self.convert_i32x8_to_i64x8(a[0])
+		}
+
+		#[inline(always)]
+		fn widen_high_i32s(self, a: Self::i32s) -> Self::i64s {
+			let a: [i32x8; 2] = cast!(a);
+			self.convert_i32x8_to_i64x8(a[1])
+		}
+
+		#[inline(always)]
+		fn widen_low_f32s(self, a: Self::f32s) -> Self::f64s {
+			let a: [f32x8; 2] = cast!(a);
+			self.convert_f32x8_to_f64x8(a[0])
+		}
+
+		#[inline(always)]
+		fn widen_high_f32s(self, a: Self::f32s) -> Self::f64s {
+			let a: [f32x8; 2] = cast!(a);
+			self.convert_f32x8_to_f64x8(a[1])
+		}
+
+		#[inline(always)]
+		fn narrow_u16s(self, a: Self::u16s, b: Self::u16s) -> Self::u8s {
+			cast!([
+				self.convert_u16x32_to_u8x32(a),
+				self.convert_u16x32_to_u8x32(b)
+			])
+		}
+
+		#[inline(always)]
+		fn narrow_i16s(self, a: Self::i16s, b: Self::i16s) -> Self::i8s {
+			cast!([
+				self.convert_i16x32_to_i8x32(a),
+				self.convert_i16x32_to_i8x32(b)
+			])
+		}
+
+		#[inline(always)]
+		fn narrow_u32s(self, a: Self::u32s, b: Self::u32s) -> Self::u16s {
+			cast!([
+				self.convert_u32x16_to_u16x16(a),
+				self.convert_u32x16_to_u16x16(b)
+			])
+		}
+
+		#[inline(always)]
+		fn narrow_i32s(self, a: Self::i32s, b: Self::i32s) -> Self::i16s {
+			cast!([
+				self.convert_i32x16_to_i16x16(a),
+				self.convert_i32x16_to_i16x16(b)
+			])
+		}
+
+		#[inline(always)]
+		fn narrow_u64s(self, a: Self::u64s, b: Self::u64s) -> Self::u32s {
+			cast!([
+				self.convert_u64x8_to_u32x8(a),
+				self.convert_u64x8_to_u32x8(b)
+			])
+		}
+
+		#[inline(always)]
+		fn narrow_i64s(self, a: Self::i64s, b: Self::i64s) -> Self::i32s {
+			cast!([
+				self.convert_i64x8_to_i32x8(a),
+				self.convert_i64x8_to_i32x8(b)
+			])
+		}
+
+		#[inline(always)]
+		fn narrow_f64s(self, a: Self::f64s, b: Self::f64s) -> Self::f32s {
+			cast!([
+				self.convert_f64x8_to_f32x8(a),
+				self.convert_f64x8_to_f32x8(b)
+			])
+		}
+
+		#[inline(always)]
+		fn narrow_saturating_u16s(self, a: Self::u16s, b: Self::u16s) -> Self::u8s {
+			cast!([
+				self.avx512bw._mm512_cvtusepi16_epi8(cast!(a)),
+				self.avx512bw._mm512_cvtusepi16_epi8(cast!(b))
+			])
+		}
+
+		#[inline(always)]
+		fn narrow_saturating_i16s(self, a: Self::i16s, b: Self::i16s) -> Self::i8s {
+			cast!([
+				self.avx512bw._mm512_cvtsepi16_epi8(cast!(a)),
+				self.avx512bw._mm512_cvtsepi16_epi8(cast!(b))
+			])
+		}
+
+		#[inline(always)]
+		fn narrow_saturating_u32s(self, a: Self::u32s, b: Self::u32s) -> Self::u16s {
+			cast!([
+				self.avx512f._mm512_cvtusepi32_epi16(cast!(a)),
+				self.avx512f._mm512_cvtusepi32_epi16(cast!(b))
+			])
+		}
+
+		#[inline(always)]
+		fn narrow_saturating_i32s(self, a: Self::i32s, b: Self::i32s) -> Self::i16s {
+			cast!([
+				self.avx512f._mm512_cvtsepi32_epi16(cast!(a)),
+				self.avx512f._mm512_cvtsepi32_epi16(cast!(b))
+			])
+		}
+
+		#[inline(always)]
+		fn narrow_saturating_u64s(self, a: Self::u64s, b: Self::u64s) -> Self::u32s {
+			cast!([
+				self.avx512f._mm512_cvtusepi64_epi32(cast!(a)),
+				self.avx512f._mm512_cvtusepi64_epi32(cast!(b))
+			])
+		}
+
+		#[inline(always)]
+		fn narrow_saturating_i64s(self, a: Self::i64s, b: Self::i64s) -> Self::i32s {
+			cast!([
+				self.avx512f._mm512_cvtsepi64_epi32(cast!(a)),
+				self.avx512f._mm512_cvtsepi64_epi32(cast!(b))
+			])
+		}
+
+		#[inline(always)]
+		fn narrow_saturating_unsigned_i16s(self, a: Self::i16s, b: Self::i16s) -> Self::u8s {
+			let zero = self.splat_i16s(0);
+			let a = self.max_i16s(a, zero);
+			let b = self.max_i16s(b, zero);
+			cast!([
+				self.avx512bw._mm512_cvtusepi16_epi8(cast!(a)),
+				self.avx512bw._mm512_cvtusepi16_epi8(cast!(b))
+			])
+		}
+
+		#[inline(always)]
+		fn narrow_saturating_unsigned_i32s(self, a: Self::i32s, b: Self::i32s) -> Self::u16s {
+			let zero = self.splat_i32s(0);
+			let a = self.max_i32s(a, zero);
+			let b = self.max_i32s(b, zero);
+			cast!([
+				self.avx512f._mm512_cvtusepi32_epi16(cast!(a)),
+				self.avx512f._mm512_cvtusepi32_epi16(cast!(b))
+			])
+		}
+
+		#[inline(always)]
+		fn narrow_saturating_unsigned_i64s(self, a: Self::i64s, b: Self::i64s) -> Self::u32s {
+			let zero = self.splat_i64s(0);
+			let a = self.max_i64s(a, zero);
+			let b = self.max_i64s(b, zero);
+			cast!([
+				self.avx512f._mm512_cvtusepi64_epi32(cast!(a)),
+				self.avx512f._mm512_cvtusepi64_epi32(cast!(b))
+			])
+		}
+	};
+	(derived) => {
+		#[inline(always)]
+		fn narrow_u16s(self, a: Self::u16s, b: Self::u16s) -> Self::u8s {
+			let mask = self.splat_u16s(0xFF);
+			self.narrow_saturating_unsigned_i16s(
+				self.transmute_i16s_u16s(self.and_u16s(a, mask)),
+				self.transmute_i16s_u16s(self.and_u16s(b, mask)),
+			)
+		}
+
+		#[inline(always)]
+		fn narrow_u32s(self, a: Self::u32s, b: Self::u32s) -> Self::u16s {
+			let mask = self.splat_u32s(0xFFFF);
+			self.narrow_saturating_unsigned_i32s(
+				self.transmute_i32s_u32s(self.and_u32s(a, mask)),
+				self.transmute_i32s_u32s(self.and_u32s(b, mask)),
+			)
+		}
+
+		#[inline(always)]
+		fn narrow_i16s(self, a: Self::i16s, b: Self::i16s) -> Self::i8s {
+			self.transmute_i8s_u8s(
+				self.narrow_u16s(self.transmute_u16s_i16s(a), self.transmute_u16s_i16s(b)),
+			)
+		}
+
+		#[inline(always)]
+		fn narrow_i32s(self, a: Self::i32s, b: Self::i32s) -> Self::i16s {
+			self.transmute_i16s_u16s(
+				self.narrow_u32s(self.transmute_u32s_i32s(a), self.transmute_u32s_i32s(b)),
+			)
+		}
+
+		#[inline(always)]
+		fn narrow_i64s(self, a: Self::i64s, b: Self::i64s) -> Self::i32s {
+			self.transmute_i32s_u32s(
+				self.narrow_u64s(self.transmute_u64s_i64s(a), self.transmute_u64s_i64s(b)),
+			)
+		}
+
+		#[inline(always)]
+		fn narrow_saturating_u16s(self, a: Self::u16s, b: Self::u16s) -> Self::u8s {
+			let max = self.splat_u16s(0xFF);
+			self.narrow_saturating_unsigned_i16s(
+				self.transmute_i16s_u16s(self.min_u16s(a, max)),
+				self.transmute_i16s_u16s(self.min_u16s(b, max)),
+			)
+		}
+
+		#[inline(always)]
+		fn narrow_saturating_u32s(self, a: Self::u32s, b: Self::u32s) -> Self::u16s {
+			let max = self.splat_u32s(0xFFFF);
+			self.narrow_saturating_unsigned_i32s(
+				self.transmute_i32s_u32s(self.min_u32s(a, max)),
+				self.transmute_i32s_u32s(self.min_u32s(b, max)),
+			)
+		}
+
+		#[inline(always)]
+		fn narrow_saturating_u64s(self, a: Self::u64s, b: Self::u64s) -> Self::u32s {
+			let max = self.splat_u64s(u32::MAX as u64);
+			let clamp = |x| self.select_u64s(self.greater_than_u64s(x, max), max, x);
+			self.narrow_u64s(clamp(a), clamp(b))
+		}
+
+		#[inline(always)]
+		fn narrow_saturating_i64s(self, a: Self::i64s, b: Self::i64s) -> Self::i32s {
+			let min = self.splat_i64s(i32::MIN as i64);
+			let max = self.splat_i64s(i32::MAX as i64);
+			let clamp = |x| {
+				let x = self.select_i64s(self.less_than_i64s(x, min), min, x);
+				self.select_i64s(self.greater_than_i64s(x, max), max, x)
+			};
+			self.narrow_i64s(clamp(a), clamp(b))
+		}
+
+		#[inline(always)]
+		fn narrow_saturating_unsigned_i64s(self, a: Self::i64s, b: Self::i64s) -> Self::u32s {
+			let min = self.splat_i64s(0);
+			let max = self.splat_i64s(u32::MAX as i64);
+			let clamp = |x| {
+				let x = self.select_i64s(self.less_than_i64s(x, min), min, x);
+				self.transmute_u64s_i64s(self.select_i64s(self.greater_than_i64s(x, max), max, x))
+			};
+			self.narrow_u64s(clamp(a), clamp(b))
+		}
+	};
+}
+
+macro_rules! impl_f16_convert {
+	(128) => {
+		#[inline(always)]
+		fn convert_f16s_f32s(self, a: Self::f16s) -> (Self::f32s, Self::f32s) {
+			let a = cast!(a);
+			(
+				cast!(self.f16c._mm_cvtph_ps(a)),
+				cast!(self.f16c._mm_cvtph_ps(self.sse2._mm_unpackhi_epi64(a, a))),
+			)
+		}
+
+		#[inline(always)]
+		fn convert_f32s_f16s(self, a: Self::f32s, b: Self::f32s) -> Self::f16s {
+			const ROUNDING: i32 = _MM_FROUND_TO_NEAREST_INT;
+			cast!(self.sse2._mm_unpacklo_epi64(
+				self.f16c._mm_cvtps_ph::<ROUNDING>(cast!(a)),
+				self.f16c._mm_cvtps_ph::<ROUNDING>(cast!(b)),
+			))
+		}
+	};
+	(256) => {
+		#[inline(always)]
+		fn convert_f16s_f32s(self, a: Self::f16s) -> (Self::f32s, Self::f32s) {
+			let a: [__m128i; 2] = cast!(a);
+			(
+				cast!(self.f16c._mm256_cvtph_ps(a[0])),
+				cast!(self.f16c._mm256_cvtph_ps(a[1])),
+			)
+		}
+
+		#[inline(always)]
+		fn convert_f32s_f16s(self, a: Self::f32s, b: Self::f32s) -> Self::f16s {
+			const ROUNDING: i32 = _MM_FROUND_TO_NEAREST_INT;
+			cast!([
+				self.f16c._mm256_cvtps_ph::<ROUNDING>(cast!(a)),
+				self.f16c._mm256_cvtps_ph::<ROUNDING>(cast!(b)),
+			])
+		}
+	};
+	(512) => {
+		#[inline(always)]
+		fn convert_f16s_f32s(self, a: Self::f16s) -> (Self::f32s, Self::f32s) {
+			let a: [__m256i; 2] = cast!(a);
+			(
+				cast!(self.avx512f._mm512_cvtph_ps(a[0])),
+				cast!(self.avx512f._mm512_cvtph_ps(a[1])),
+			)
+		}
+
+		#[inline(always)]
+		fn convert_f32s_f16s(self, a: Self::f32s, b: Self::f32s) -> Self::f16s {
+			const ROUNDING: i32 = _MM_FROUND_TO_NEAREST_INT | _MM_FROUND_NO_EXC;
+			cast!([
+				self.avx512f._mm512_cvtps_ph::<ROUNDING>(cast!(a)),
+				self.avx512f._mm512_cvtps_ph::<ROUNDING>(cast!(b)),
+			])
+		}
+	};
+}
+
+static SSE_COMPRESS_IDX_32: [[u8; 16]; 16] = compress_idx::<16, 4, 16>();
+static SSE_COMPRESS_IDX_64: [[u8; 16]; 4] = compress_idx::<16, 8, 4>();
+static SSE_EXPAND_IDX_32: [[u8; 16]; 16] = expand_idx::<16, 4, 16>();
+static SSE_EXPAND_IDX_64: [[u8; 16]; 4] = expand_idx::<16, 8, 4>();
+
+// indices of 32-bit units, widened before use
+static AVX2_COMPRESS_IDX_32: [[u8; 8]; 256] = compress_idx::<8, 1, 256>();
+static AVX2_COMPRESS_IDX_64: [[u8; 8]; 16] = compress_idx::<8, 2, 16>();
+static AVX2_EXPAND_IDX_32: [[u8; 8]; 256] = expand_idx::<8, 1, 256>();
+static AVX2_EXPAND_IDX_64: [[u8; 8]; 16] = expand_idx::<8, 2, 16>();
+
+macro_rules! impl_compress_expand {
+	(128) => {
+		#[inline(always)]
+		fn compress_store_u32s(
+			self,
+			mask: Self::m32s,
+			values: Self::u32s,
+			out: &mut [u32],
+		) -> usize {
+			let mask = self.sse._mm_movemask_ps(cast!(mask)) as usize;
+			let count = mask.count_ones() as usize;
+			let values = self
+				.ssse3
+				._mm_shuffle_epi8(cast!(values), cast!(SSE_COMPRESS_IDX_32[mask]));
+			self.partial_store_u32s(&mut out[..count], cast!(values));
+			count
+		}
+
+		#[inline(always)]
+		fn compress_store_u64s(
+			self,
+			mask: Self::m64s,
+			values: Self::u64s,
+			out: &mut [u64],
+		) -> usize {
+			let mask = self.sse2._mm_movemask_pd(cast!(mask)) as usize;
+			let count = mask.count_ones() as usize;
+			let values = self
+				.ssse3
+				._mm_shuffle_epi8(cast!(values), cast!(SSE_COMPRESS_IDX_64[mask]));
+			self.partial_store_u64s(&mut out[..count], cast!(values));
+			count
+		}
+
+		#[inline(always)]
+		fn expand_load_u32s(self, mask: Self::m32s, values: &[u32]) -> Self::u32s {
+			let mask = self.sse._mm_movemask_ps(cast!(mask)) as usize;
+			let count = mask.count_ones() as usize;
+			let values = self.partial_load_u32s(&values[..count]);
+			cast!(
+				self.ssse3
+					._mm_shuffle_epi8(cast!(values), cast!(SSE_EXPAND_IDX_32[mask]))
+			)
+		}
+
+		#[inline(always)]
+		fn expand_load_u64s(self, mask: Self::m64s, values: &[u64]) -> Self::u64s {
+			let mask = self.sse2._mm_movemask_pd(cast!(mask)) as usize;
+			let count = mask.count_ones() as usize;
+			let values = self.partial_load_u64s(&values[..count]);
+			cast!(
+				self.ssse3
+					._mm_shuffle_epi8(cast!(values), cast!(SSE_EXPAND_IDX_64[mask]))
+			)
+		}
+	};
+	(256) => {
+		#[inline(always)]
+		fn compress_store_u32s(
+			self,
+			mask: Self::m32s,
+			values: Self::u32s,
+			out: &mut [u32],
+		) -> usize {
+			let mask = self.avx._mm256_movemask_ps(cast!(mask)) as usize;
+			let count = mask.count_ones() as usize;
+			let idx = self.avx2._mm256_cvtepu8_epi32(
+				self.sse2
+					._mm_set_epi64x(0, i64::from_le_bytes(AVX2_COMPRESS_IDX_32[mask])),
+			);
+			let values = self.avx2._mm256_permutevar8x32_epi32(cast!(values), idx);
+			self.partial_store_u32s(&mut out[..count], cast!(values));
+			count
+		}
+
+		#[inline(always)]
+		fn compress_store_u64s(
+			self,
+			mask: Self::m64s,
+			values: Self::u64s,
+			out: &mut [u64],
+		) -> usize {
+			let mask = self.avx._mm256_movemask_pd(cast!(mask)) as usize;
+			let count = mask.count_ones() as usize;
+			let idx = self.avx2._mm256_cvtepu8_epi32(
+				self.sse2
+					._mm_set_epi64x(0, i64::from_le_bytes(AVX2_COMPRESS_IDX_64[mask])),
+			);
+			let values = self.avx2._mm256_permutevar8x32_epi32(cast!(values), idx);
+			self.partial_store_u64s(&mut out[..count], cast!(values));
+			count
+		}
+
+		#[inline(always)]
+		fn expand_load_u32s(self, mask: Self::m32s, values: &[u32]) -> Self::u32s {
+			let bits = self.avx._mm256_movemask_ps(cast!(mask)) as usize;
+			let count = bits.count_ones() as usize;
+			let values = self.partial_load_u32s(&values[..count]);
+			let idx = self.avx2._mm256_cvtepu8_epi32(
+				self.sse2
+					._mm_set_epi64x(0, i64::from_le_bytes(AVX2_EXPAND_IDX_32[bits])),
+			);
+			let values = self.avx2._mm256_permutevar8x32_epi32(cast!(values), idx);
+			self.and_u32s(cast!(values), cast!(mask))
+		}
+
+		#[inline(always)]
+		fn expand_load_u64s(self, mask: Self::m64s, values: &[u64]) -> Self::u64s {
+			let bits = self.avx._mm256_movemask_pd(cast!(mask)) as usize;
+			let count = bits.count_ones() as usize;
+			let values = self.partial_load_u64s(&values[..count]);
+			let idx = self.avx2._mm256_cvtepu8_epi32(
+				self.sse2
+					._mm_set_epi64x(0, i64::from_le_bytes(AVX2_EXPAND_IDX_64[bits])),
+			);
+			let values = self.avx2._mm256_permutevar8x32_epi32(cast!(values), idx);
+			self.and_u64s(cast!(values), cast!(mask))
+		}
+	};
+}
+
+macro_rules! impl_mul_sub {
+	($f32: ident, $f64: ident) => {
+		paste! {
+			#[inline(always)]
+			fn mul_sub_f32s(self, a: Self::f32s, b: Self::f32s, c: Self::f32s) -> Self::f32s {
+				self.[<mul_sub_ $f32>](a, b, c)
+			}
+
+			#[inline(always)]
+			fn mul_sub_e_f32s(self, a: Self::f32s, b: Self::f32s, c: Self::f32s) -> Self::f32s {
+				self.mul_sub_f32s(a, b, c)
+			}
+
+			#[inline(always)]
+			fn mul_sub_f64s(self, a: Self::f64s, b: Self::f64s, c: Self::f64s) -> Self::f64s {
+				self.[<mul_sub_ $f64>](a, b, c)
+			}
+
+			#[inline(always)]
+			fn mul_sub_e_f64s(self, a: Self::f64s, b: Self::f64s, c: Self::f64s) -> Self::f64s {
+				self.mul_sub_f64s(a, b, c)
+			}
+
+			#[inline(always)]
+			fn negate_mul_sub_f32s(self, a: Self::f32s, b: Self::f32s, c: Self::f32s) -> Self::f32s {
+				self.[<negate_mul_sub_ $f32>](a, b, c)
+			}
+
+			#[inline(always)]
+			fn negate_mul_sub_e_f32s(self, a: Self::f32s, b: Self::f32s, c: Self::f32s) -> Self::f32s {
+				self.negate_mul_sub_f32s(a, b, c)
+			}
+
+			#[inline(always)]
+			fn negate_mul_sub_f64s(self, a: Self::f64s, b: Self::f64s, c: Self::f64s) -> Self::f64s {
+				self.[<negate_mul_sub_ $f64>](a, b, c)
+			}
+
+			#[inline(always)]
+			fn negate_mul_sub_e_f64s(self, a: Self::f64s, b: Self::f64s, c: Self::f64s) -> Self::f64s {
+				self.negate_mul_sub_f64s(a, b, c)
+			}
+
+			#[inline(always)]
+			fn mul_addsub_f32s(self, a: Self::f32s, b: Self::f32s, c: Self::f32s) -> Self::f32s {
+				self.[<mul_addsub_ $f32>](a, b, c)
+			}
+
+			#[inline(always)]
+			fn mul_addsub_e_f32s(self, a: Self::f32s, b: Self::f32s, c: Self::f32s) -> Self::f32s {
+				self.mul_addsub_f32s(a, b, c)
+			}
+
+			#[inline(always)]
+			fn mul_addsub_f64s(self, a: Self::f64s, b: Self::f64s, c: Self::f64s) -> Self::f64s {
+				self.[<mul_addsub_ $f64>](a, b, c)
+			}
+
+			#[inline(always)]
+			fn mul_addsub_e_f64s(self, a: Self::f64s, b: Self::f64s, c: Self::f64s) -> Self::f64s {
+				self.mul_addsub_f64s(a, b, c)
+			}
+
+			#[inline(always)]
+			fn mul_subadd_f32s(self, a: Self::f32s, b: Self::f32s, c: Self::f32s) -> Self::f32s {
+				self.[<mul_subadd_ $f32>](a, b, c)
+			}
+
+			#[inline(always)]
+			fn mul_subadd_e_f32s(self, a: Self::f32s, b: Self::f32s, c: Self::f32s) -> Self::f32s {
+				self.mul_subadd_f32s(a, b, c)
+			}
+
+			#[inline(always)]
+			fn mul_subadd_f64s(self, a: Self::f64s, b: Self::f64s, c: Self::f64s) -> Self::f64s {
+				self.[<mul_subadd_ $f64>](a, b, c)
+			}
+
+			#[inline(always)]
+			fn mul_subadd_e_f64s(self, a: Self::f64s, b: Self::f64s, c: Self::f64s) -> Self::f64s {
+				self.mul_subadd_f64s(a, b, c)
+			}
+		}
+	};
+}
+
+macro_rules! impl_mul_high {
+	($simd: ident, $bw: ident, $mul_epu32: ident, $mulhi_epu16: ident, $mulhi_epi16: ident) => {
+		#[inline(always)]
+		fn mul_high_u16s(self, a: Self::u16s, b: Self::u16s) -> Self::u16s {
+			cast!(self.$bw.$mulhi_epu16(cast!(a), cast!(b)))
+		}
+
+		#[inline(always)]
+		fn mul_high_i16s(self, a: Self::i16s, b: Self::i16s) -> Self::i16s {
+			cast!(self.$bw.$mulhi_epi16(cast!(a), cast!(b)))
+		}
+
+		#[inline(always)]
+		fn mul_u8s(self, a: Self::u8s, b: Self::u8s) -> Self::u8s {
+			// there is no 8-bit multiply, so the even and odd bytes are multiplied as 16-bit lanes,
+			// with the odd bytes of `b` kept in place so that their product lands in the high byte
+			let a: Self::u16s = cast!(a);
+			let b: Self::u16s = cast!(b);
+			let even = self.mul_u16s(a, b);
+			let odd = self.mul_u16s(
+				self.shr_const_u16s::<8>(a),
+				self.and_u16s(b, self.splat_u16s(0xff00)),
+			);
+			cast!(self.or_u16s(self.and_u16s(even, self.splat_u16s(0x00ff)), odd))
+		}
+
+		#[inline(always)]
+		fn widening_mul_u64s(self, a: Self::u64s, b: Self::u64s) -> (Self::u64s, Self::u64s) {
+			// schoolbook multiplication on 32-bit halves, none of the partial sums can overflow
+			let mul = |a: Self::u64s, b: Self::u64s| -> Self::u64s {
+				cast!(self.$simd.$mul_epu32(cast!(a), cast!(b)))
+			};
+			let low = self.splat_u64s(u32::MAX as u64);
+
+			let a_hi = self.shr_const_u64s::<32>(a);
+			let b_hi = self.shr_const_u64s::<32>(b);
+
+			let ll = mul(a, b);
+			let lh = mul(a, b_hi);
+			let hl = mul(a_hi, b);
+			let hh = mul(a_hi, b_hi);
+
+			let t = self.add_u64s(hl, self.shr_const_u64s::<32>(ll));
+			let u = self.add_u64s(lh, self.and_u64s(t, low));
+
+			let lo = self.or_u64s(self.and_u64s(ll, low), self.shl_const_u64s::<32>(u));
+			let hi = self.add_u64s(
+				self.add_u64s(hh, self.shr_const_u64s::<32>(t)),
+				self.shr_const_u64s::<32>(u),
+			);
+			(lo, hi)
+		}
+	};
+}
+
+macro_rules! impl_fixed_point {
+	($mulhrs_simd: ident, $mulhrs: ident, $mul_simd: ident, $mul_epi32: ident) => {
+		#[inline(always)]
+		fn mul_round_q15_i16s(self, a: Self::i16s, b: Self::i16s) -> Self::i16s {
+			let c: Self::i16s = cast!(self.$mulhrs_simd.$mulhrs(cast!(a), cast!(b)));
+			// `-1.0 * -1.0` wraps around to `i16::MIN`, which is otherwise unreachable,
+			// so it can be mapped back to `i16::MAX`
+			let overflow = self.equal_i16s(c, self.splat_i16s(i16::MIN));
+			self.select_i16s(overflow, self.splat_i16s(i16::MAX), c)
+		}
+
+		#[inline(always)]
+		fn mul_round_q31_i32s(self, a: Self::i32s, b: Self::i32s) -> Self::i32s {
+			let mul = |a: Self::u64s, b: Self::u64s| -> Self::u64s {
+				cast!(self.$mul_simd.$mul_epi32(cast!(a), cast!(b)))
+			};
+			let a: Self::u64s = cast!(a);
+			let b: Self::u64s = cast!(b);
+			let round = self.splat_u64s(1 << 30);
+
+			// the products of the even and odd lanes, with their result moved into place
+			let even = self.shr_const_u64s::<31>(self.add_u64s(mul(a, b), round));
+			let odd = self.shl_const_u64s::<1>(self.add_u64s(
+				mul(self.shr_const_u64s::<32>(a), self.shr_const_u64s::<32>(b)),
+				round,
+			));
+			let c: Self::i32s = cast!(self.or_u64s(
+				self.and_u64s(even, self.splat_u64s(u32::MAX as u64)),
+				self.and_u64s(odd, self.splat_u64s((u32::MAX as u64) << 32)),
+			));
+
+			// `-1.0 * -1.0` wraps around to `i32::MIN`, which is otherwise unreachable,
+			// so it can be mapped back to `i32::MAX`
+			let overflow = self.equal_i32s(c, self.splat_i32s(i32::MIN));
+			self.select_i32s(overflow, self.splat_i32s(i32::MAX), c)
+		}
+	};
+}
+
+macro_rules! impl_recip_approx {
+	($simd: ident, $rcp: ident, $rsqrt: ident) => {
+		#[inline(always)]
+		fn recip_approx_f32s(self, a: Self::f32s) -> Self::f32s {
+			cast!(self.$simd.$rcp(cast!(a)))
+		}
+
+		#[inline(always)]
+		fn rsqrt_approx_f32s(self, a: Self::f32s) -> Self::f32s {
+			cast!(self.$simd.$rsqrt(cast!(a)))
+		}
+
+		// the initial estimate has at least 12 bits of precision, so a single newton step is enough.
+		// the estimate is already exact when it's zero or infinite, where the step would produce
+		// `0 * inf`
+		#[inline(always)]
+		fn recip_approx_refined_f32s(self, a: Self::f32s) -> Self::f32s {
+			recip_in_range_f32s(self, a, |a| {
+				let x = self.recip_approx_f32s(a);
+				let e = self.negate_mul_add_e_f32s(a, x, self.splat_f32s(1.0));
+				let exact = self.or_m32s(
+					self.is_infinite_f32s(x),
+					self.equal_f32s(x, self.splat_f32s(0.0)),
+				);
+				self.select_f32s(exact, x, self.mul_add_e_f32s(x, e, x))
+			})
+		}
+
+		#[inline(always)]
+		fn rsqrt_approx_refined_f32s(self, a: Self::f32s) -> Self::f32s {
+			rsqrt_in_range_f32s(self, a, |a| {
+				let y = self.rsqrt_approx_f32s(a);
+				let half = self.splat_f32s(0.5);
+				let e =
+					self.negate_mul_add_e_f32s(self.mul_f32s(self.mul_f32s(half, a), y), y, half);
+				let exact = self.or_m32s(
+					self.is_infinite_f32s(y),
+					self.equal_f32s(y, self.splat_f32s(0.0)),
+				);
+				self.select_f32s(exact, y, self.mul_add_e_f32s(y, e, y))
+			})
+		}
+	};
+}
+
+macro_rules! impl_mask_query {
+	(128) => {
+		#[inline(always)]
+		fn to_bitmask_m8s(self, mask: Self::m8s) -> u64 {
+			self.sse2._mm_movemask_epi8(cast!(mask)) as u32 as u64
+		}
+
+		#[inline(always)]
+		fn to_bitmask_m16s(self, mask: Self::m16s) -> u64 {
+			let mask: __m128i = cast!(mask);
+			(self.sse2._mm_movemask_epi8(self.sse2._mm_packs_epi16(mask, mask)) & 0xff) as u64
+		}
+
+		#[inline(always)]
+		fn to_bitmask_m32s(self, mask: Self::m32s) -> u64 {
+			self.sse._mm_movemask_ps(cast!(mask)) as u64
+		}
+
+		#[inline(always)]
+		fn to_bitmask_m64s(self, mask: Self::m64s) -> u64 {
+			self.sse2._mm_movemask_pd(cast!(mask)) as u64
+		}
+
+		#[inline(always)]
+		fn from_bitmask_m8s(self, bits: u64) -> Self::m8s {
+			// each byte of the bitmask is broadcast to the lanes it covers
+			let bits = self.ssse3._mm_shuffle_epi8(
+				self.sse2._mm_set1_epi16(bits as i16),
+				cast!(u8x16(0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 1, 1, 1, 1, 1, 1)),
+			);
+			let lane_bits: __m128i = cast!(u8x16(
+				1, 2, 4, 8, 16, 32, 64, 128, 1, 2, 4, 8, 16, 32, 64, 128,
+			));
+			cast!(
+				self.sse2
+					._mm_cmpeq_epi8(self.sse2._mm_and_si128(bits, lane_bits), lane_bits)
+			)
+		}
+
+		#[inline(always)]
+		fn from_bitmask_m16s(self, bits: u64) -> Self::m16s {
+			let bits = self.sse2._mm_set1_epi16(bits as i16);
+			let lane_bits: __m128i = cast!(u16x8(1, 2, 4, 8, 16, 32, 64, 128));
+			cast!(
+				self.sse2
+					._mm_cmpeq_epi16(self.sse2._mm_and_si128(bits, lane_bits), lane_bits)
+			)
+		}
+
+		#[inline(always)]
+		fn from_bitmask_m32s(self, bits: u64) -> Self::m32s {
+			let bits = self.sse2._mm_set1_epi32(bits as i32);
+			let lane_bits: __m128i = cast!(u32x4(1, 2, 4, 8));
+			cast!(
+				self.sse2
+					._mm_cmpeq_epi32(self.sse2._mm_and_si128(bits, lane_bits), lane_bits)
+			)
+		}
+
+		#[inline(always)]
+		fn from_bitmask_m64s(self, bits: u64) -> Self::m64s {
+			let bits = self.sse2._mm_set1_epi64x(bits as i64);
+			let lane_bits: __m128i = cast!(u64x2(1, 2));
+			cast!(
+				self.sse4_1
+					._mm_cmpeq_epi64(self.sse2._mm_and_si128(bits, lane_bits), lane_bits)
+			)
+		}
+	};
+	(256) => {
+		#[inline(always)]
+		fn to_bitmask_m8s(self, mask: Self::m8s) -> u64 {
+			self.avx2._mm256_movemask_epi8(cast!(mask)) as u32 as u64
+		}
+
+		#[inline(always)]
+		fn to_bitmask_m16s(self, mask: Self::m16s) -> u64 {
+			let mask: __m256i = cast!(mask);
+			// packing works within each 128-bit half, so the lanes end up in bytes 0..8 and 16..24
+			let bits =
+				self.avx2._mm256_movemask_epi8(self.avx2._mm256_packs_epi16(mask, mask)) as u32;
+			((bits & 0xff) | ((bits >> 8) & 0xff00)) as u64
+		}
+
+		#[inline(always)]
+		fn to_bitmask_m32s(self, mask: Self::m32s) -> u64 {
+			self.avx._mm256_movemask_ps(cast!(mask)) as u64
+		}
+
+		#[inline(always)]
+		fn to_bitmask_m64s(self, mask: Self::m64s) -> u64 {
+			self.avx._mm256_movemask_pd(cast!(mask)) as u64
+		}
+
+		#[inline(always)]
+		fn from_bitmask_m8s(self, bits: u64) -> Self::m8s {
+			// each byte of the bitmask is broadcast to the lanes it covers
+			let bits = self.avx2._mm256_shuffle_epi8(
+				self.avx._mm256_set1_epi32(bits as i32),
+				cast!(u8x32(
+					0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 1, 1, 1, 1, 1, 1, 2, 2, 2, 2, 2, 2, 2, 2, 3, 3,
+					3, 3, 3, 3, 3, 3,
+				)),
+			);
+			let lane_bits: __m256i = cast!(u8x32(
+				1, 2, 4, 8, 16, 32, 64, 128, 1, 2, 4, 8, 16, 32, 64, 128, 1, 2, 4, 8, 16, 32, 64,
+				128, 1, 2, 4, 8, 16, 32, 64, 128,
+			));
+			cast!(
+				self.avx2
+					._mm256_cmpeq_epi8(self.avx2._mm256_and_si256(bits, lane_bits), lane_bits)
+			)
+		}
+
+		#[inline(always)]
+		fn from_bitmask_m16s(self, bits: u64) -> Self::m16s {
+			let bits = self.avx._mm256_set1_epi16(bits as i16);
+			let lane_bits: __m256i = cast!(u16x16(
+				1, 2, 4, 8, 16, 32, 64, 128, 256, 512, 1024, 2048, 4096, 8192, 16384, 32768,
+			));
+			cast!(
+				self.avx2
+					._mm256_cmpeq_epi16(self.avx2._mm256_and_si256(bits, lane_bits), lane_bits)
+			)
+		}
+
+		#[inline(always)]
+		fn from_bitmask_m32s(self, bits: u64) -> Self::m32s {
+			let bits = self.avx._mm256_set1_epi32(bits as i32);
+			let lane_bits: __m256i = cast!(u32x8(1, 2, 4, 8, 16, 32, 64, 128));
+			cast!(
+				self.avx2
+					._mm256_cmpeq_epi32(self.avx2._mm256_and_si256(bits, lane_bits), lane_bits)
+			)
+		}
+
+		#[inline(always)]
+		fn from_bitmask_m64s(self, bits: u64) -> Self::m64s {
+			let bits = self.avx._mm256_set1_epi64x(bits as i64);
+			let lane_bits: __m256i = cast!(u64x4(1, 2, 4, 8));
+			cast!(
+				self.avx2
+					._mm256_cmpeq_epi64(self.avx2._mm256_and_si256(bits, lane_bits), lane_bits)
+			)
+		}
+	};
+	(512) => {
+		#[inline(always)]
+		fn to_bitmask_m8s(self, mask: Self::m8s) -> u64 {
+			mask.0
+		}
+
+		#[inline(always)]
+		fn to_bitmask_m16s(self, mask: Self::m16s) -> u64 {
+			mask.0 as u64
+		}
+
+		#[inline(always)]
+		fn to_bitmask_m32s(self, mask: Self::m32s) -> u64 {
+			mask.0 as u64
+		}
+
+		#[inline(always)]
+		fn to_bitmask_m64s(self, mask: Self::m64s) -> u64 {
+			mask.0 as u64
+		}
+
+		#[inline(always)]
+		fn from_bitmask_m8s(self, bits: u64) -> Self::m8s {
+			b64(bits)
+		}
+
+		#[inline(always)]
+		fn from_bitmask_m16s(self, bits: u64) -> Self::m16s {
+			b32(bits as u32)
+		}
+
+		#[inline(always)]
+		fn from_bitmask_m32s(self, bits: u64) -> Self::m32s {
+			b16(bits as u16)
+		}
+
+		#[inline(always)]
+		fn from_bitmask_m64s(self, bits: u64) -> Self::m64s {
+			b8(bits as u8)
+		}
+
+		#[inline(always)]
+		fn first_true_m8s(self, mask: Self::m8s) -> usize {
+			mask.0.trailing_zeros() as usize
+		}
+
+		#[inline(always)]
+		fn first_true_m16s(self, mask: Self::m16s) -> usize {
+			mask.0.trailing_zeros() as usize
+		}
+
+		#[inline(always)]
+		fn first_true_m32s(self, mask: Self::m32s) -> usize {
+			mask.0.trailing_zeros() as usize
+		}
+
+		#[inline(always)]
+		fn first_true_m64s(self, mask: Self::m64s) -> usize {
+			mask.0.trailing_zeros() as usize
+		}
+	};
+	(x2) => {
+		impl_mask_query!(x2, 8, 16, 32, 64);
+	};
+	(x2, $($bits: literal),*) => {
+		paste! {$(
+			#[inline(always)]
+			fn [<to_bitmask_m $bits s>](self, mask: Self::[<m $bits s>]) -> u64 {
+				let simd = V3_256b(*self);
+				let [m0, m1]: [_; 2] = cast!(mask);
+				simd.[<to_bitmask_m $bits s>](m0)
+					| (simd.[<to_bitmask_m $bits s>](m1) << V3_256b::[<U $bits _LANES>])
+			}
+
+			#[inline(always)]
+			fn [<from_bitmask_m $bits s>](self, bits: u64) -> Self::[<m $bits s>] {
+				let simd = V3_256b(*self);
+				cast!([
+					simd.[<from_bitmask_m $bits s>](bits),
+					simd.[<from_bitmask_m $bits s>](bits >> V3_256b::[<U $bits _LANES>]),
+				])
+			}
+		)*}
+	};
+}
+
+macro_rules! impl_int_reduce {
+	(@fold $self: ident, $a: expr, $op: ident, $ty: ident, $($shift: literal),*) => {{
+		let mut x = $a;
+		$(x = $self.$op(x, cast!($self.sse2._mm_srli_si128::<$shift>(cast!(x))));)*
+		cast_lossy::<_, $ty>(x)
+	}};
+	(128) => {
+		#[inline(always)]
+		fn reduce_sum_u8s(self, a: Self::u8s) -> u64 {
+			let sum: [u64; 2] = cast!(self.sse2._mm_sad_epu8(cast!(a), self.sse2._mm_setzero_si128()));
+			sum[0] + sum[1]
+		}
+
+		#[inline(always)]
+		fn reduce_sum_u32s(self, a: Self::u32s) -> u32 {
+			impl_int_reduce!(@fold self, a, add_u32s, u32, 8, 4)
+		}
+
+		#[inline(always)]
+		fn reduce_sum_i32s(self, a: Self::i32s) -> i32 {
+			self.reduce_sum_u32s(cast!(a)) as i32
+		}
+
+		#[inline(always)]
+		fn reduce_sum_u64s(self, a: Self::u64s) -> u64 {
+			impl_int_reduce!(@fold self, a, add_u64s, u64, 8)
+		}
+
+		#[inline(always)]
+		fn reduce_sum_i64s(self, a: Self::i64s) -> i64 {
+			self.reduce_sum_u64s(cast!(a)) as i64
+		}
+
+		impl_int_reduce!(128 u8, 8, 4, 2, 1);
+		impl_int_reduce!(128 i8, 8, 4, 2, 1);
+		impl_int_reduce!(128 u16, 8, 4, 2);
+		impl_int_reduce!(128 i16, 8, 4, 2);
+		impl_int_reduce!(128 u32, 8, 4);
+		impl_int_reduce!(128 i32, 8, 4);
+		impl_int_reduce!(128 u64, 8);
+		impl_int_reduce!(128 i64, 8);
+	};
+	(128 $ty: ident, $($shift: literal),*) => {
+		paste! {
+			#[inline(always)]
+			fn [<reduce_min_ $ty s>](self, a: Self::[<$ty s>]) -> $ty {
+				impl_int_reduce!(@fold self, a, [<min_ $ty s>], $ty, $($shift),*)
+			}
+
+			#[inline(always)]
+			fn [<reduce_max_ $ty s>](self, a: Self::[<$ty s>]) -> $ty {
+				impl_int_reduce!(@fold self, a, [<max_ $ty s>], $ty, $($shift),*)
+			}
+		}
+	};
+	(split $self: ident => $simd: expr) => {
+		#[inline(always)]
+		fn reduce_sum_u8s($self, a: Self::u8s) -> u64 {
+			let simd = $simd;
+			let a: [_; 2] = cast!(a);
+			simd.reduce_sum_u8s(a[0]) + simd.reduce_sum_u8s(a[1])
+		}
+
+		impl_int_reduce!(split $self => $simd, add => sum, u32, i32, u64, i64);
+		impl_int_reduce!(split $self => $simd, min => min, u8, i8, u16, i16, u32, i32, u64, i64);
+		impl_int_reduce!(split $self => $simd, max => max, u8, i8, u16, i16, u32, i32, u64, i64);
+	};
+	(split $self: ident => $simd: expr, $op: ident => $func: ident, $($ty: ident),*) => {
+		paste! {$(
+			#[inline(always)]
+			fn [<reduce_ $func _ $ty s>]($self, a: Self::[<$ty s>]) -> $ty {
+				let simd = $simd;
+				let a: [_; 2] = cast!(a);
+				simd.[<reduce_ $func _ $ty s>](simd.[<$op _ $ty s>](a[0], a[1]))
+			}
+		)*}
+	};
+}
+
+macro_rules! impl_bit_count {
+	(@lookup $self: ident, $ext: ident, $shuffle: ident, $n: literal, $hi: expr, $lo: expr, $a: expr) => {{
+		let a: Self::u8s = $a;
+		let hi_table: Self::u8s = cast!([$hi; $n]);
+		let lo_table: Self::u8s = cast!([$lo; $n]);
+		let mask = $self.splat_u8s(0x0f);
+		let lo = $self.and_u8s(a, mask);
+		let hi = $self.and_u8s(cast!($self.shr_const_u16s::<4>(cast!(a))), mask);
+		let lo: Self::u8s = cast!($self.$ext.$shuffle(cast!(lo_table), cast!(lo)));
+		let hi: Self::u8s = cast!($self.$ext.$shuffle(cast!(hi_table), cast!(hi)));
+		(hi, lo)
+	}};
+	(@widen count_ones, $self: ident, $from: ident => $to: ident, $bits: literal, $a: expr) => {
+		paste! {{
+			let c: Self::[<$to s>] = cast!($self.[<count_ones_ $from s>](cast!($a)));
+			$self.[<add_ $to s>](
+				$self.[<shr_const_ $to s>]::<$bits>(c),
+				$self.[<and_ $to s>](c, $self.[<splat_ $to s>]((1 << $bits) - 1)),
+			)
+		}}
+	};
+	(@widen leading_zeros, $self: ident, $from: ident => $to: ident, $bits: literal, $a: expr) => {
+		paste! {{
+			let c: Self::[<$to s>] = cast!($self.[<leading_zeros_ $from s>](cast!($a)));
+			let hi = $self.[<shr_const_ $to s>]::<$bits>(c);
+			let lo = $self.[<and_ $to s>](c, $self.[<splat_ $to s>]((1 << $bits) - 1));
+			// `hi` is at most `$bits`, which is a power of two, so this is all ones if the high half
+			// is zero, and zero otherwise
+			let hi_is_zero = $self.[<sub_ $to s>](
+				$self.[<splat_ $to s>](0),
+				$self.[<shr_const_ $to s>]::<{ ($bits as u32).trailing_zeros() }>(hi),
+			);
+			$self.[<add_ $to s>](hi, $self.[<and_ $to s>](lo, hi_is_zero))
+		}}
+	};
+	($ext: ident, $shuffle: ident, $n: literal) => {
+		impl_bit_count!(@common $ext, $shuffle, $n);
+
+		#[inline(always)]
+		fn leading_zeros_u32s(self, a: Self::u32s) -> Self::u32s {
+			impl_bit_count!(@widen leading_zeros, self, u16 => u32, 16, a)
+		}
+
+		#[inline(always)]
+		fn leading_zeros_u64s(self, a: Self::u64s) -> Self::u64s {
+			impl_bit_count!(@widen leading_zeros, self, u32 => u64, 32, a)
+		}
+	};
+	(@common $ext: ident, $shuffle: ident, $n: literal) => {
+		#[inline(always)]
+		fn count_ones_u8s(self, a: Self::u8s) -> Self::u8s {
+			let (hi, lo) = impl_bit_count!(
+				@lookup self,
+				$ext,
+				$shuffle,
+				$n,
+				[0u8, 1, 1, 2, 1, 2, 2, 3, 1, 2, 2, 3, 2, 3, 3, 4],
+				[0u8, 1, 1, 2, 1, 2, 2, 3, 1, 2, 2, 3, 2, 3, 3, 4],
+				a
+			);
+			self.add_u8s(hi, lo)
+		}
+
+		#[inline(always)]
+		fn count_ones_u16s(self, a: Self::u16s) -> Self::u16s {
+			impl_bit_count!(@widen count_ones, self, u8 => u16, 8, a)
+		}
+
+		#[inline(always)]
+		fn count_ones_u32s(self, a: Self::u32s) -> Self::u32s {
+			impl_bit_count!(@widen count_ones, self, u16 => u32, 16, a)
+		}
+
+		#[inline(always)]
+		fn count_ones_u64s(self, a: Self::u64s) -> Self::u64s {
+			impl_bit_count!(@widen count_ones, self, u32 => u64, 32, a)
+		}
+
+		#[inline(always)]
+		fn leading_zeros_u8s(self, a: Self::u8s) -> Self::u8s {
+			let (hi, lo) = impl_bit_count!(
+				@lookup self,
+				$ext,
+				$shuffle,
+				$n,
+				[8u8, 3, 2, 2, 1, 1, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0],
+				[8u8, 7, 6, 6, 5, 5, 5, 5, 4, 4, 4, 4, 4, 4, 4, 4],
+				a
+			);
+			// the high nibble only wins if it is nonzero, in which case its count is below 4
+			self.min_u8s(hi, lo)
+		}
+
+		#[inline(always)]
+		fn leading_zeros_u16s(self, a: Self::u16s) -> Self::u16s {
+			impl_bit_count!(@widen leading_zeros, self, u8 => u16, 8, a)
+		}
+
+		impl_bit_count!(@trailing_zeros u8, u16, u32, u64);
+	};
+	(@trailing_zeros $($ty: ident),*) => {
+		paste! {$(
+			#[inline(always)]
+			fn [<trailing_zeros_ $ty s>](self, a: Self::[<$ty s>]) -> Self::[<$ty s>] {
+				// the trailing zeros of `a` are exactly the ones of `!a & (a - 1)`
+				self.[<count_ones_ $ty s>](self.[<and_ $ty s>](
+					self.[<not_ $ty s>](a),
+					self.[<sub_ $ty s>](a, self.[<splat_ $ty s>](1)),
+				))
+			}
+		)*}
+	};
+}
+
+macro_rules! impl_gather_scatter {
+	(@bias $simd: expr, $ptr: expr, $idx: expr) => {
+		// the hardware sign extends 32-bit indices, so we offset them by 2^31 to treat them as
+		// unsigned
+		(
+			$ptr.wrapping_add(1 << 31) as *const i32,
+			cast!($simd.xor_u32s($idx, $simd.splat_u32s(1 << 31))),
+		)
+	};
+	(128) => {
+		impl_gather_scatter!(@avx2 _mm);
+	};
+	(256) => {
+		impl_gather_scatter!(@avx2 _mm256);
+	};
+	(@avx2 $prefix: ident) => {
+		paste! {
+			/// # Safety
+			///
+			/// See the trait-level safety documentation.
+			#[inline(always)]
+			unsafe fn gather_ptr_u32s(self, ptr: *const u32, idx: Self::u32s) -> Self::u32s {
+				let (ptr, idx) = impl_gather_scatter!(@bias self, ptr, idx);
+				cast!(self.avx2.[<$prefix _i32gather_epi32>]::<4>(ptr, idx))
+			}
+
+			/// # Safety
+			///
+			/// See the trait-level safety documentation.
+			#[inline(always)]
+			unsafe fn mask_gather_ptr_u32s(
+				self,
+				mask: MemMask<Self::m32s>,
+				ptr: *const u32,
+				idx: Self::u32s,
+			) -> Self::u32s {
+				let (ptr, idx) = impl_gather_scatter!(@bias self, ptr, idx);
+				cast!(self.avx2.[<$prefix _mask_i32gather_epi32>]::<4>(
+					cast!(self.splat_u32s(0)),
+					ptr,
+					idx,
+					cast!(mask.mask),
+				))
+			}
+
+			/// # Safety
+			///
+			/// See the trait-level safety documentation.
+			#[inline(always)]
+			unsafe fn gather_ptr_u64s(self, ptr: *const u64, idx: Self::u64s) -> Self::u64s {
+				cast!(self.avx2.[<$prefix _i64gather_epi64>]::<8>(ptr as _, cast!(idx)))
+			}
+
+			/// # Safety
+			///
+			/// See the trait-level safety documentation.
+			#[inline(always)]
+			unsafe fn mask_gather_ptr_u64s(
+				self,
+				mask: MemMask<Self::m64s>,
+				ptr: *const u64,
+				idx: Self::u64s,
+			) -> Self::u64s {
+				cast!(self.avx2.[<$prefix _mask_i64gather_epi64>]::<8>(
+					cast!(self.splat_u64s(0)),
+					ptr as _,
+					cast!(idx),
+					cast!(mask.mask),
+				))
+			}
+		}
+	};
+	(512) => {
+		/// # Safety
+		///
+		/// See the trait-level safety documentation.
+		#[inline(always)]
+		unsafe fn gather_ptr_u32s(self, ptr: *const u32, idx: Self::u32s) -> Self::u32s {
+			let (ptr, idx) = impl_gather_scatter!(@bias self, ptr, idx);
+			cast!(self.avx512f._mm512_i32gather_epi32::<4>(idx, ptr))
+		}
+
+		/// # Safety
+		///
+		/// See the trait-level safety documentation.
+		#[inline(always)]
+		unsafe fn mask_gather_ptr_u32s(
+			self,
+			mask: MemMask<Self::m32s>,
+			ptr: *const u32,
+			idx: Self::u32s,
+		) -> Self::u32s {
+			let (ptr, idx) = impl_gather_scatter!(@bias self, ptr, idx);
+			cast!(self.avx512f._mm512_mask_i32gather_epi32::<4>(
+				cast!(self.splat_u32s(0)),
+				mask.mask.0,
+				idx,
+				ptr,
+			))
+		}
+
+		/// # Safety
+		///
+		/// See the trait-level safety documentation.
+		#[inline(always)]
+		unsafe fn gather_ptr_u64s(self, ptr: *const u64, idx: Self::u64s) -> Self::u64s {
+			cast!(self.avx512f._mm512_i64gather_epi64::<8>(cast!(idx), ptr as _))
+		}
+
+		/// # Safety
+		///
+		/// See the trait-level safety documentation.
+		#[inline(always)]
+		unsafe fn mask_gather_ptr_u64s(
+			self,
+			mask: MemMask<Self::m64s>,
+			ptr: *const u64,
+			idx: Self::u64s,
+		) -> Self::u64s {
+			cast!(self.avx512f._mm512_mask_i64gather_epi64::<8>(
+				cast!(self.splat_u64s(0)),
+				mask.mask.0,
+				cast!(idx),
+				ptr as _,
+			))
+		}
+
+		/// # Safety
+		///
+		/// See the trait-level safety documentation.
+		#[inline(always)]
+		unsafe fn scatter_ptr_u32s(self, ptr: *mut u32, idx: Self::u32s, values: Self::u32s) {
+			let (ptr, idx) = impl_gather_scatter!(@bias self, ptr, idx);
+			self.avx512f._mm512_i32scatter_epi32::<4>(ptr as _, idx, cast!(values))
+		}
+
+		/// # Safety
+		///
+		/// See the trait-level safety documentation.
+		#[inline(always)]
+		unsafe fn mask_scatter_ptr_u32s(
+			self,
+			mask: MemMask<Self::m32s>,
+			ptr: *mut u32,
+			idx: Self::u32s,
+			values: Self::u32s,
+		) {
+			let (ptr, idx) = impl_gather_scatter!(@bias self, ptr, idx);
+			self.avx512f._mm512_mask_i32scatter_epi32::<4>(ptr as _, mask.mask.0, idx, cast!(values))
+		}
+
+		/// # Safety
+		///
+		/// See the trait-level safety documentation.
+		#[inline(always)]
+		unsafe fn scatter_ptr_u64s(self, ptr: *mut u64, idx: Self::u64s, values: Self::u64s) {
+			self.avx512f._mm512_i64scatter_epi64::<8>(ptr as _, cast!(idx), cast!(values))
+		}
+
+		/// # Safety
+		///
+		/// See the trait-level safety documentation.
+		#[inline(always)]
+		unsafe fn mask_scatter_ptr_u64s(
+			self,
+			mask: MemMask<Self::m64s>,
+			ptr: *mut u64,
+			idx: Self::u64s,
+			values: Self::u64s,
+		) {
+			self.avx512f._mm512_mask_i64scatter_epi64::<8>(
+				ptr as _,
+				mask.mask.0,
+				cast!(idx),
+				cast!(values),
+			)
+		}
+	};
+}
+
+// the methods that don't involve masks, which `V3_256b` and `V4_256b` both forward to `V3`
+macro_rules! inherit_256b {
+	() => {
+		inherit!({
+			fn abs2_c32s(self, a: Self::c32s) -> Self::c32s;
+			fn abs2_c64s(self, a: Self::c64s) -> Self::c64s;
+			fn abs_max_c32s(self, a: Self::c32s) -> Self::c32s;
+			fn abs_max_c64s(self, a: Self::c64s) -> Self::c64s;
+			fn add_c32s(self, a: Self::c32s, b: Self::c32s) -> Self::c32s;
+			fn add_c64s(self, a: Self::c64s, b: Self::c64s) -> Self::c64s;
+			fn conj_c32s(self, a: Self::c32s) -> Self::c32s;
+			fn conj_c64s(self, a: Self::c64s) -> Self::c64s;
+			fn conj_mul_add_c32s(self, a: Self::c32s, b: Self::c32s, c: Self::c32s) -> Self::c32s;
+			fn conj_mul_add_c64s(self, a: Self::c64s, b: Self::c64s, c: Self::c64s) -> Self::c64s;
+			fn conj_mul_c32s(self, a: Self::c32s, b: Self::c32s) -> Self::c32s;
+			fn conj_mul_c64s(self, a: Self::c64s, b: Self::c64s) -> Self::c64s;
+			fn convert_f32s_i32s(self, a: Self::f32s) -> Self::i32s;
+			fn convert_f32s_u32s(self, a: Self::f32s) -> Self::u32s;
+			fn convert_f64s_i64s(self, a: Self::f64s) -> Self::i64s;
+			fn convert_f64s_u64s(self, a: Self::f64s) -> Self::u64s;
+			fn convert_i32s_f32s(self, a: Self::i32s) -> Self::f32s;
+			fn convert_u32s_f32s(self, a: Self::u32s) -> Self::f32s;
+			fn convert_i64s_f64s(self, a: Self::i64s) -> Self::f64s;
+			fn convert_u64s_f64s(self, a: Self::u64s) -> Self::f64s;
+			/// # Safety
+			///
+			/// See the trait-level safety documentation.
+			unsafe fn gather_ptr_u32s(self, ptr: *const u32, idx: Self::u32s) -> Self::u32s;
+			/// # Safety
+			///
+			/// See the trait-level safety documentation.
+			unsafe fn gather_ptr_u64s(self, ptr: *const u64, idx: Self::u64s) -> Self::u64s;
+			fn mul_add_c32s(self, a: Self::c32s, b: Self::c32s, c: Self::c32s) -> Self::c32s;
+			fn mul_add_c64s(self, a: Self::c64s, b: Self::c64s, c: Self::c64s) -> Self::c64s;
+			fn mul_add_e_f32s(self, a: Self::f32s, b: Self::f32s, c: Self::f32s) -> Self::f32s;
+			fn mul_add_e_f64s(self, a: Self::f64s, b: Self::f64s, c: Self::f64s) -> Self::f64s;
+			fn mul_add_f32s(self, a: Self::f32s, b: Self::f32s, c: Self::f32s) -> Self::f32s;
+			fn mul_add_f64s(self, a: Self::f64s, b: Self::f64s, c: Self::f64s) -> Self::f64s;
+			fn negate_mul_add_e_f32s(
+				self,
+				a: Self::f32s,
+				b: Self::f32s,
+				c: Self::f32s,
+			) -> Self::f32s;
+			fn negate_mul_add_e_f64s(
+				self,
+				a: Self::f64s,
+				b: Self::f64s,
+				c: Self::f64s,
+			) -> Self::f64s;
+			fn negate_mul_add_f32s(self, a: Self::f32s, b: Self::f32s, c: Self::f32s)
+			-> Self::f32s;
+			fn negate_mul_add_f64s(self, a: Self::f64s, b: Self::f64s, c: Self::f64s)
+			-> Self::f64s;
+			fn mul_sub_e_f32s(self, a: Self::f32s, b: Self::f32s, c: Self::f32s) -> Self::f32s;
+			fn mul_sub_e_f64s(self, a: Self::f64s, b: Self::f64s, c: Self::f64s) -> Self::f64s;
+			fn mul_sub_f32s(self, a: Self::f32s, b: Self::f32s, c: Self::f32s) -> Self::f32s;
+			fn mul_sub_f64s(self, a: Self::f64s, b: Self::f64s, c: Self::f64s) -> Self::f64s;
+			fn negate_mul_sub_e_f32s(
+				self,
+				a: Self::f32s,
+				b: Self::f32s,
+				c: Self::f32s,
+			) -> Self::f32s;
+			fn negate_mul_sub_e_f64s(
+				self,
+				a: Self::f64s,
+				b: Self::f64s,
+				c: Self::f64s,
+			) -> Self::f64s;
+			fn negate_mul_sub_f32s(self, a: Self::f32s, b: Self::f32s, c: Self::f32s)
+			-> Self::f32s;
+			fn negate_mul_sub_f64s(self, a: Self::f64s, b: Self::f64s, c: Self::f64s)
+			-> Self::f64s;
+			fn mul_addsub_e_f32s(self, a: Self::f32s, b: Self::f32s, c: Self::f32s) -> Self::f32s;
+			fn mul_addsub_e_f64s(self, a: Self::f64s, b: Self::f64s, c: Self::f64s) -> Self::f64s;
+			fn mul_addsub_f32s(self, a: Self::f32s, b: Self::f32s, c: Self::f32s) -> Self::f32s;
+			fn mul_addsub_f64s(self, a: Self::f64s, b: Self::f64s, c: Self::f64s) -> Self::f64s;
+			fn mul_subadd_e_f32s(self, a: Self::f32s, b: Self::f32s, c: Self::f32s) -> Self::f32s;
+			fn mul_subadd_e_f64s(self, a: Self::f64s, b: Self::f64s, c: Self::f64s) -> Self::f64s;
+			fn mul_subadd_f32s(self, a: Self::f32s, b: Self::f32s, c: Self::f32s) -> Self::f32s;
+			fn mul_subadd_f64s(self, a: Self::f64s, b: Self::f64s, c: Self::f64s) -> Self::f64s;
+			fn recip_approx_f32s(self, a: Self::f32s) -> Self::f32s;
+			fn rsqrt_approx_f32s(self, a: Self::f32s) -> Self::f32s;
+			fn recip_approx_refined_f32s(self, a: Self::f32s) -> Self::f32s;
+			fn rsqrt_approx_refined_f32s(self, a: Self::f32s) -> Self::f32s;
+			fn mul_c32s(self, a: Self::c32s, b: Self::c32s) -> Self::c32s;
+			fn mul_c64s(self, a: Self::c64s, b: Self::c64s) -> Self::c64s;
+			fn neg_c32s(self, a: Self::c32s) -> Self::c32s;
+			fn neg_c64s(self, a: Self::c64s) -> Self::c64s;
+			fn min_u64s(self, a: Self::u64s, b: Self::u64s) -> Self::u64s;
+			fn min_i64s(self, a: Self::i64s, b: Self::i64s) -> Self::i64s;
+			fn max_u64s(self, a: Self::u64s, b: Self::u64s) -> Self::u64s;
+			fn max_i64s(self, a: Self::i64s, b: Self::i64s) -> Self::i64s;
+			fn mul_u64s(self, a: Self::u64s, b: Self::u64s) -> Self::u64s;
+			fn mul_i64s(self, a: Self::i64s, b: Self::i64s) -> Self::i64s;
+			fn mul_u8s(self, a: Self::u8s, b: Self::u8s) -> Self::u8s;
+			fn mul_high_u16s(self, a: Self::u16s, b: Self::u16s) -> Self::u16s;
+			fn mul_high_i16s(self, a: Self::i16s, b: Self::i16s) -> Self::i16s;
+			fn mul_round_q15_i16s(self, a: Self::i16s, b: Self::i16s) -> Self::i16s;
+			fn mul_round_q31_i32s(self, a: Self::i32s, b: Self::i32s) -> Self::i32s;
+			fn partial_load_u32s(self, slice: &[u32]) -> Self::u32s;
+			fn partial_load_u64s(self, slice: &[u64]) -> Self::u64s;
+			fn partial_store_u32s(self, slice: &mut [u32], values: Self::u32s);
+			fn partial_store_u64s(self, slice: &mut [u64], values: Self::u64s);
+			fn reduce_max_c32s(self, a: Self::c32s) -> c32;
+			fn reduce_max_c64s(self, a: Self::c64s) -> c64;
+			fn reduce_max_f32s(self, a: Self::f32s) -> f32;
+			fn reduce_max_f64s(self, a: Self::f64s) -> f64;
+			fn reduce_min_c32s(self, a: Self::c32s) -> c32;
+			fn reduce_min_c64s(self, a: Self::c64s) -> c64;
+			fn reduce_min_f32s(self, a: Self::f32s) -> f32;
+			fn reduce_min_f64s(self, a: Self::f64s) -> f64;
+			fn reduce_product_f32s(self, a: Self::f32s) -> f32;
+			fn reduce_product_f64s(self, a: Self::f64s) -> f64;
+			fn reduce_sum_c32s(self, a: Self::c32s) -> c32;
+			fn reduce_sum_c64s(self, a: Self::c64s) -> c64;
+			fn reduce_sum_f32s(self, a: Self::f32s) -> f32;
+			fn reduce_sum_f64s(self, a: Self::f64s) -> f64;
+			fn reduce_sum_u8s(self, a: Self::u8s) -> u64;
+			fn reduce_sum_u32s(self, a: Self::u32s) -> u32;
+			fn reduce_sum_i32s(self, a: Self::i32s) -> i32;
+			fn reduce_sum_u64s(self, a: Self::u64s) -> u64;
+			fn reduce_sum_i64s(self, a: Self::i64s) -> i64;
+			fn reduce_min_u8s(self, a: Self::u8s) -> u8;
+			fn reduce_min_i8s(self, a: Self::i8s) -> i8;
+			fn reduce_min_u16s(self, a: Self::u16s) -> u16;
+			fn reduce_min_i16s(self, a: Self::i16s) -> i16;
+			fn reduce_min_u32s(self, a: Self::u32s) -> u32;
+			fn reduce_min_i32s(self, a: Self::i32s) -> i32;
+			fn reduce_min_u64s(self, a: Self::u64s) -> u64;
+			fn reduce_min_i64s(self, a: Self::i64s) -> i64;
+			fn reduce_max_u8s(self, a: Self::u8s) -> u8;
+			fn reduce_max_i8s(self, a: Self::i8s) -> i8;
+			fn reduce_max_u16s(self, a: Self::u16s) -> u16;
+			fn reduce_max_i16s(self, a: Self::i16s) -> i16;
+			fn reduce_max_u32s(self, a: Self::u32s) -> u32;
+			fn reduce_max_i32s(self, a: Self::i32s) -> i32;
+			fn reduce_max_u64s(self, a: Self::u64s) -> u64;
+			fn reduce_max_i64s(self, a: Self::i64s) -> i64;
+			fn count_ones_u8s(self, a: Self::u8s) -> Self::u8s;
+			fn count_ones_u16s(self, a: Self::u16s) -> Self::u16s;
+			fn count_ones_u32s(self, a: Self::u32s) -> Self::u32s;
+			fn count_ones_u64s(self, a: Self::u64s) -> Self::u64s;
+			fn leading_zeros_u8s(self, a: Self::u8s) -> Self::u8s;
+			fn leading_zeros_u16s(self, a: Self::u16s) -> Self::u16s;
+			fn leading_zeros_u32s(self, a: Self::u32s) -> Self::u32s;
+			fn leading_zeros_u64s(self, a: Self::u64s) -> Self::u64s;
+			fn trailing_zeros_u8s(self, a: Self::u8s) -> Self::u8s;
+			fn trailing_zeros_u16s(self, a: Self::u16s) -> Self::u16s;
+			fn trailing_zeros_u32s(self, a: Self::u32s) -> Self::u32s;
+			fn trailing_zeros_u64s(self, a: Self::u64s) -> Self::u64s;
+			fn rotate_right_c32s(self, a: Self::c32s, amount: usize) -> Self::c32s;
+			fn rotate_right_c64s(self, a: Self::c64s, amount: usize) -> Self::c64s;
+			fn rotate_right_u32s(self, a: Self::u32s, amount: usize) -> Self::u32s;
+			fn rotate_right_u64s(self, a: Self::u64s, amount: usize) -> Self::u64s;
+			fn table_lookup_u8s(self, table: Self::u8s, idx: Self::u8s) -> Self::u8s;
+			fn splat_c32s(self, a: c32) -> Self::c32s;
+			fn splat_c64s(self, a: c64) -> Self::c64s;
+			fn sub_c32s(self, a: Self::c32s, b: Self::c32s) -> Self::c32s;
+			fn sub_c64s(self, a: Self::c64s, b: Self::c64s) -> Self::c64s;
+			fn swap_re_im_c32s(self, a: Self::c32s) -> Self::c32s;
+			fn swap_re_im_c64s(self, a: Self::c64s) -> Self::c64s;
+			fn widening_mul_u32s(self, a: Self::u32s, b: Self::u32s) -> (Self::u32s, Self::u32s);
+			fn widening_mul_u64s(self, a: Self::u64s, b: Self::u64s) -> (Self::u64s, Self::u64s);
+			fn wrapping_dyn_shl_u32s(self, a: Self::u32s, amount: Self::u32s) -> Self::u32s;
+			fn wrapping_dyn_shr_u32s(self, a: Self::u32s, amount: Self::u32s) -> Self::u32s;
+			fn wrapping_dyn_shl_u64s(self, a: Self::u64s, amount: Self::u64s) -> Self::u64s;
+			fn wrapping_dyn_shr_u64s(self, a: Self::u64s, amount: Self::u64s) -> Self::u64s;
+			fn wrapping_dyn_sra_i32s(self, a: Self::i32s, amount: Self::u32s) -> Self::i32s;
+			fn wrapping_dyn_sra_i64s(self, a: Self::i64s, amount: Self::u64s) -> Self::i64s;
+			fn wrapping_shl_u8s(self, a: Self::u8s, amount: u32) -> Self::u8s;
+			fn wrapping_shl_u16s(self, a: Self::u16s, amount: u32) -> Self::u16s;
+			fn wrapping_shl_u32s(self, a: Self::u32s, amount: u32) -> Self::u32s;
+			fn wrapping_shl_u64s(self, a: Self::u64s, amount: u32) -> Self::u64s;
+			fn wrapping_shr_u8s(self, a: Self::u8s, amount: u32) -> Self::u8s;
+			fn wrapping_shr_u16s(self, a: Self::u16s, amount: u32) -> Self::u16s;
+			fn wrapping_shr_u32s(self, a: Self::u32s, amount: u32) -> Self::u32s;
+			fn wrapping_shr_u64s(self, a: Self::u64s, amount: u32) -> Self::u64s;
+			fn wrapping_sra_i8s(self, a: Self::i8s, amount: u32) -> Self::i8s;
+			fn wrapping_sra_i16s(self, a: Self::i16s, amount: u32) -> Self::i16s;
+			fn wrapping_sra_i32s(self, a: Self::i32s, amount: u32) -> Self::i32s;
+			fn wrapping_sra_i64s(self, a: Self::i64s, amount: u32) -> Self::i64s;
+			fn widen_low_u8s(self, a: Self::u8s) -> Self::u16s;
+			fn widen_high_u8s(self, a: Self::u8s) -> Self::u16s;
+			fn widen_low_i8s(self, a: Self::i8s) -> Self::i16s;
+			fn widen_high_i8s(self, a: Self::i8s) -> Self::i16s;
+			fn widen_low_u16s(self, a: Self::u16s) -> Self::u32s;
+			fn widen_high_u16s(self, a: Self::u16s) -> Self::u32s;
+			fn widen_low_i16s(self, a: Self::i16s) -> Self::i32s;
+			fn widen_high_i16s(self, a: Self::i16s) -> Self::i32s;
+			fn widen_low_u32s(self, a: Self::u32s) -> Self::u64s;
+			fn widen_high_u32s(self, a: Self::u32s) -> Self::u64s;
+			fn widen_low_i32s(self, a: Self::i32s) -> Self::i64s;
+			fn widen_high_i32s(self, a: Self::i32s) -> Self::i64s;
+			fn widen_low_f32s(self, a: Self::f32s) -> Self::f64s;
+			fn widen_high_f32s(self, a: Self::f32s) -> Self::f64s;
+			fn narrow_u16s(self, a: Self::u16s, b: Self::u16s) -> Self::u8s;
+			fn narrow_i16s(self, a: Self::i16s, b: Self::i16s) -> Self::i8s;
+			fn narrow_u32s(self, a: Self::u32s, b: Self::u32s) -> Self::u16s;
+			fn narrow_i32s(self, a: Self::i32s, b: Self::i32s) -> Self::i16s;
+			fn narrow_u64s(self, a: Self::u64s, b: Self::u64s) -> Self::u32s;
+			fn narrow_i64s(self, a: Self::i64s, b: Self::i64s) -> Self::i32s;
+			fn narrow_f64s(self, a: Self::f64s, b: Self::f64s) -> Self::f32s;
+			fn narrow_saturating_u16s(self, a: Self::u16s, b: Self::u16s) -> Self::u8s;
+			fn narrow_saturating_i16s(self, a: Self::i16s, b: Self::i16s) -> Self::i8s;
+			fn narrow_saturating_u32s(self, a: Self::u32s, b: Self::u32s) -> Self::u16s;
+			fn narrow_saturating_i32s(self, a: Self::i32s, b: Self::i32s) -> Self::i16s;
+			fn narrow_saturating_u64s(self, a: Self::u64s, b: Self::u64s) -> Self::u32s;
+			fn narrow_saturating_i64s(self, a: Self::i64s, b: Self::i64s) -> Self::i32s;
+			fn narrow_saturating_unsigned_i16s(self, a: Self::i16s, b: Self::i16s) -> Self::u8s;
+			fn narrow_saturating_unsigned_i32s(self, a: Self::i32s, b: Self::i32s) -> Self::u16s;
+			fn narrow_saturating_unsigned_i64s(self, a: Self::i64s, b: Self::i64s) -> Self::u32s;
+			fn convert_f16s_f32s(self, a: Self::f16s) -> (Self::f32s, Self::f32s);
+			fn convert_f32s_f16s(self, a: Self::f32s, b: Self::f32s) -> Self::f16s;
+		});
+	};
+}
+
 mod v1;
 mod v2;
 mod v3;
@@ -305,23 +2322,158 @@ pub enum Arch {
 	#[cfg(feature = "x86-v4")]
 	#[cfg_attr(docsrs, doc(cfg(feature = "x86-v4")))]
 	V4(V4) = 2,
+
+	#[cfg(feature = "x86-v2")]
+	#[cfg_attr(docsrs, doc(cfg(feature = "x86-v2")))]
+	V2(V2) = 3,
+
+	#[cfg(feature = "x86-v3")]
+	#[cfg_attr(docsrs, doc(cfg(feature = "x86-v3")))]
+	V3_128b(V3_128b) = 4,
+
+	#[cfg(feature = "x86-v4")]
+	#[cfg_attr(docsrs, doc(cfg(feature = "x86-v4")))]
+	V4_256b(V4_256b) = 5,
+}
+
+/// x86 instruction set levels, ordered from the least to the most capable.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[non_exhaustive]
+pub enum ArchLevel {
+	Scalar,
+	V2,
+	V3,
+	V4,
+}
+
+impl ArchLevel {
+	const NAMES: &[(&str, Self)] = &[
+		("scalar", Self::Scalar),
+		("v2", Self::V2),
+		("v3", Self::V3),
+		("v4", Self::V4),
+	];
+
+	/// Parses a level from its name, ignoring case: `"scalar"`, `"v2"`, `"v3"` or `"v4"`.
+	#[inline]
+	pub fn from_name(name: &str) -> Option<Self> {
+		let name = name.trim();
+		Self::NAMES
+			.iter()
+			.find(|(level_name, _)| level_name.eq_ignore_ascii_case(name))
+			.map(|&(_, level)| level)
+	}
+
+	/// Returns the name of the level, as accepted by [`ArchLevel::from_name`].
+	#[inline]
+	pub fn name(self) -> &'static str {
+		Self::NAMES
+			.iter()
+			.find(|&&(_, level)| level == self)
+			.unwrap()
+			.0
+	}
 }
 
 impl Arch {
 	/// Detects the best available instruction set.
+	///
+	/// With the `std` feature, a lower level can be requested through the `PULP_ARCH` environment
+	/// variable (e.g. `PULP_ARCH=v3`), which is read once and clamped to what the cpu supports.
+	/// Unknown names are ignored.
 	#[inline]
 	pub fn new() -> Self {
+		#[cfg(feature = "std")]
+		if let Some(level) = crate::arch_override() {
+			return Self::with_max_level(level);
+		}
+		Self::with_max_level(ArchLevel::V4)
+	}
+
+	/// Detects the best available instruction set that does not exceed `level`.
+	#[inline]
+	pub fn with_max_level(level: ArchLevel) -> Self {
+		Self::with_max_level_and_register_width(level, usize::MAX)
+	}
+
+	/// Detects the best available instruction set whose vector registers are at most `bits` wide.
+	///
+	/// This picks a backend that is restricted to narrower vectors when one exists, e.g.
+	/// `V4_256b` for `bits = 256`, which avoids the frequency penalty of 512-bit instructions
+	/// on some processors, or [`V3_128b`] for `bits = 128`. The `PULP_ARCH` environment variable
+	/// is taken into account in the same way as in [`Arch::new`].
+	#[inline]
+	pub fn with_max_register_width(bits: usize) -> Self {
+		#[cfg(feature = "std")]
+		if let Some(level) = crate::arch_override() {
+			return Self::with_max_level_and_register_width(level, bits);
+		}
+		Self::with_max_level_and_register_width(ArchLevel::V4, bits)
+	}
+
+	/// Detects the best available instruction set that does not exceed `level`, and whose vector
+	/// registers are at most `bits` wide.
+	#[inline]
+	pub fn with_max_level_and_register_width(level: ArchLevel, bits: usize) -> Self {
 		#[cfg(feature = "x86-v4")]
-		if let Some(simd) = V4::try_new() {
-			return Self::V4(simd);
+		if level >= ArchLevel::V4 {
+			if let Some(simd) = V4::try_new() {
+				if bits >= 512 {
+					return Self::V4(simd);
+				}
+				if bits >= 256 {
+					return Self::V4_256b(V4_256b(simd));
+				}
+			}
 		}
 		#[cfg(feature = "x86-v3")]
-		if let Some(simd) = V3::try_new() {
-			return Self::V3(simd);
+		if level >= ArchLevel::V3 {
+			if let Some(simd) = V3::try_new() {
+				if bits >= 256 {
+					return Self::V3(simd);
+				}
+				if bits >= 128 {
+					return Self::V3_128b(V3_128b(simd));
+				}
+			}
+		}
+		#[cfg(feature = "x86-v2")]
+		if level >= ArchLevel::V2 && bits >= 128 {
+			if let Some(simd) = V2::try_new() {
+				return Self::V2(simd);
+			}
 		}
+		#[cfg(not(any(feature = "x86-v2", feature = "x86-v3", feature = "x86-v4")))]
+		let _ = (level, bits);
 		Self::Scalar
 	}
 
+	/// Detects the best available instruction set that does not exceed the level named `name`, see
+	/// [`ArchLevel::from_name`]. Returns `None` if the name is not recognized.
+	#[inline]
+	pub fn from_name(name: &str) -> Option<Self> {
+		ArchLevel::from_name(name).map(Self::with_max_level)
+	}
+
+	/// Returns the level of the instruction set.
+	#[inline]
+	pub fn level(self) -> ArchLevel {
+		match self {
+			#[cfg(feature = "x86-v4")]
+			Arch::V4(_) => ArchLevel::V4,
+			#[cfg(feature = "x86-v3")]
+			Arch::V3(_) => ArchLevel::V3,
+			#[cfg(feature = "x86-v2")]
+			Arch::V2(_) => ArchLevel::V2,
+			#[cfg(feature = "x86-v3")]
+			Arch::V3_128b(_) => ArchLevel::V3,
+			#[cfg(feature = "x86-v4")]
+			Arch::V4_256b(_) => ArchLevel::V4,
+
+			Arch::Scalar => ArchLevel::Scalar,
+		}
+	}
+
 	/// Detects the best available instruction set.
 	#[inline(always)]
 	pub fn dispatch<Op: WithSimd>(self, op: Op) -> Op::Output {
@@ -330,6 +2482,12 @@ impl Arch {
 			Arch::V4(simd) => Simd::vectorize(simd, op),
 			#[cfg(feature = "x86-v3")]
 			Arch::V3(simd) => Simd::vectorize(simd, op),
+			#[cfg(feature = "x86-v2")]
+			Arch::V2(simd) => Simd::vectorize(simd, op),
+			#[cfg(feature = "x86-v3")]
+			Arch::V3_128b(simd) => Simd::vectorize(simd, op),
+			#[cfg(feature = "x86-v4")]
+			Arch::V4_256b(simd) => Simd::vectorize(simd, op),
 
 			Arch::Scalar => Simd::vectorize(Scalar, op),
 		}
@@ -376,6 +2534,115 @@ mod tests {
         };
     }
 
+	#[test]
+	fn test_arch_level() {
+		assert!(matches!(
+			Arch::with_max_level(ArchLevel::Scalar),
+			Arch::Scalar
+		));
+		assert!(Arch::from_name("avx9000").is_none());
+
+		for level in [
+			ArchLevel::Scalar,
+			ArchLevel::V2,
+			ArchLevel::V3,
+			ArchLevel::V4,
+		] {
+			assert_eq!(ArchLevel::from_name(level.name()), Some(level));
+			assert_eq!(
+				ArchLevel::from_name(&level.name().to_uppercase()),
+				Some(level)
+			);
+
+			let arch = Arch::with_max_level(level);
+			assert!(arch.level() <= level);
+			assert_eq!(Arch::from_name(level.name()).unwrap().level(), arch.level());
+		}
+
+		#[cfg(feature = "x86-v2")]
+		if V2::try_new().is_some() {
+			assert_eq!(Arch::with_max_level(ArchLevel::V2).level(), ArchLevel::V2);
+		}
+
+		#[cfg(feature = "x86-v3")]
+		if V3::try_new().is_some() {
+			assert_eq!(Arch::with_max_level(ArchLevel::V3).level(), ArchLevel::V3);
+		}
+	}
+
+	#[cfg(feature = "x86-v2")]
+	#[test]
+	fn test_arch_v2_dispatch() {
+		if V2::try_new().is_none() {
+			return;
+		}
+		let arch = Arch::with_max_level(ArchLevel::V2);
+		assert!(matches!(arch, Arch::V2(_)));
+
+		struct Sum<'a>(&'a [f64]);
+		impl WithSimd for Sum<'_> {
+			type Output = (usize, f64);
+
+			#[inline(always)]
+			fn with_simd<S: Simd>(self, simd: S) -> Self::Output {
+				let (head, tail) = S::as_simd_f64s(self.0);
+				let mut acc = simd.splat_f64s(0.0);
+				for &x in head {
+					acc = simd.add_f64s(acc, x);
+				}
+				(
+					S::F64_LANES,
+					simd.reduce_sum_f64s(acc) + tail.iter().sum::<f64>(),
+				)
+			}
+		}
+
+		let v = (0..1001).map(|i| i as f64).collect::<Vec<_>>();
+		assert_eq!(arch.dispatch(Sum(&v)), (2, 500500.0));
+	}
+
+	#[test]
+	fn test_arch_register_width() {
+		struct Lanes;
+		impl WithSimd for Lanes {
+			type Output = (usize, usize);
+
+			#[inline(always)]
+			fn with_simd<S: Simd>(self, simd: S) -> Self::Output {
+				let _ = simd;
+				(S::F64_LANES, S::REGISTER_COUNT)
+			}
+		}
+
+		for bits in [0, 64, 128, 256, 512, usize::MAX] {
+			let arch = Arch::with_max_level_and_register_width(ArchLevel::V4, bits);
+			let (lanes, _) = arch.dispatch(Lanes);
+			assert!(lanes == 1 || 64 * lanes <= bits);
+		}
+
+		#[cfg(feature = "x86-v3")]
+		if V3::try_new().is_some() {
+			let arch = Arch::with_max_level_and_register_width(ArchLevel::V4, 128);
+			assert!(matches!(arch, Arch::V3_128b(_)));
+			assert_eq!(arch.level(), ArchLevel::V3);
+			assert_eq!(arch.dispatch(Lanes), (2, 16));
+
+			let arch = Arch::with_max_level_and_register_width(ArchLevel::V3, 512);
+			assert!(matches!(arch, Arch::V3(_)));
+		}
+
+		#[cfg(feature = "x86-v4")]
+		if V4::try_new().is_some() {
+			let arch = Arch::with_max_level_and_register_width(ArchLevel::V4, 256);
+			assert!(matches!(arch, Arch::V4_256b(_)));
+			assert_eq!(arch.level(), ArchLevel::V4);
+			assert_eq!(arch.dispatch(Lanes), (4, 32));
+
+			let arch = Arch::with_max_level_and_register_width(ArchLevel::V4, 512);
+			assert!(matches!(arch, Arch::V4(_)));
+		}
+	}
+
 	#[test]
 	fn times_two() {
 		let n = 1312;
@@ -1141,4 +3408,157 @@ mod tests {
 			assert_eq!(simd.negate_mul_add_f64s(a, b, c), d);
 		}
 	}
+
+	#[cfg(feature = "x86-v4")]
+	#[test]
+	fn test_v4_first_true() {
+		if let Some(simd) = V4::try_new() {
+			assert_eq!(simd.first_true_m32s(b16(0b0100)), 2);
+			assert_eq!(simd.first_true_m32s(b16(0b1000_0000_0000_0001)), 0);
+			assert_eq!(simd.first_true_m32s(b16(0)), 16);
+			assert_eq!(simd.first_true_m64s(b8(0b1000_0000)), 7);
+			assert_eq!(simd.first_true_m64s(b8(0b0110_0000)), 5);
+			assert_eq!(simd.first_true_m64s(b8(0)), 8);
+		}
+	}
+
+	#[cfg(feature = "x86-v4")]
+	#[test]
+	fn test_v4_mask_bitops() {
+		if let Some(simd) = V4::try_new() {
+			let a = 0xF0F0_F0F0_0000_FFFFu64;
+			let b = 0xFF00_FF00_FF00_FF00u64;
+			assert_eq!(simd.and_m8s(b64(a), b64(b)).0, a & b);
+			assert_eq!(simd.or_m8s(b64(a), b64(b)).0, a | b);
+			assert_eq!(simd.xor_m8s(b64(a), b64(b)).0, a ^ b);
+
+			let (a, b) = (a as u32, b as u32);
+			assert_eq!(simd.and_m16s(b32(a), b32(b)).0, a & b);
+			assert_eq!(simd.or_m16s(b32(a), b32(b)).0, a | b);
+			assert_eq!(simd.xor_m16s(b32(a), b32(b)).0, a ^ b);
+		}
+	}
+
+	#[cfg(feature = "x86-v4")]
+	#[test]
+	fn test_v4_256b_mask_load_store() {
+		if let Some(simd) = V4::try_new() {
+			let simd = V4_256b(simd);
+
+			macro_rules! check {
+				($bits: literal, $lanes: literal) => {
+					paste! {
+						let src: [[<u $bits>]; $lanes] = core::array::from_fn(|i| i as [<u $bits>] + 1);
+						for (start, end) in [(0, $lanes), (3, $lanes - 2), (5, 2), ($lanes, 100)] {
+							let expected: [[<u $bits>]; $lanes] = core::array::from_fn(|i| {
+								if (start..end).contains(&i) { src[i] } else { 0 }
+							});
+							let mask = simd.[<mask_between_m $bits s>](start as _, end as _);
+							let mut dst = [0; $lanes];
+							let loaded: [[<u $bits>]; $lanes] = unsafe {
+								cast!(simd.[<mask_load_ptr_u $bits s>](mask, src.as_ptr()))
+							};
+							unsafe { simd.[<mask_store_ptr_u $bits s>](mask, dst.as_mut_ptr(), cast!(src)) };
+							assert_eq!(loaded, expected);
+							assert_eq!(dst, expected);
+						}
+					}
+				};
+			}
+			check!(8, 32);
+			check!(16, 16);
+			check!(32, 8);
+			check!(64, 4);
+
+			// the 64-bit lane masks only have 4 lanes
+			let none = simd.from_bitmask_m64s(0);
+			assert!(simd.all_m64s(simd.not_m64s(none)));
+			assert_eq!(simd.count_true_m64s(simd.not_m64s(none)), 4);
+			assert_eq!(simd.first_true_m64s(none), 4);
+		}
+	}
+
+	#[test]
+	fn test_v3_512b_mask_load_store() {
+		if let Some(simd) = V3::try_new() {
+			let simd = V3_512b(simd);
+
+			let src: [u32; 16] = core::array::from_fn(|i| i as u32 + 1);
+			let expected: [u32; 16] =
+				core::array::from_fn(|i| if (3..13).contains(&i) { src[i] } else { 0 });
+			let mask = simd.mask_between_m32s(3, 13);
+			let mut dst = [0u32; 16];
+			let loaded: [u32; 16] = unsafe { cast!(simd.mask_load_ptr_u32s(mask, src.as_ptr())) };
+			unsafe { simd.mask_store_ptr_u32s(mask, dst.as_mut_ptr(), cast!(src)) };
+			assert_eq!(loaded, expected);
+			assert_eq!(dst, expected);
+
+			let src: [c32; 8] =
+				core::array::from_fn(|i| c32::new(i as f32 + 1.0, -(i as f32) - 1.0));
+			let expected: [c32; 8] = core::array::from_fn(|i| {
+				if (1..7).contains(&i) {
+					src[i]
+				} else {
+					c32::new(0.0, 0.0)
+				}
+			});
+			let mask = simd.mask_between_m32s(2, 14);
+			let mut dst = [c32::new(0.0, 0.0); 8];
+			let loaded: [c32; 8] = unsafe { cast!(simd.mask_load_ptr_c32s(mask, src.as_ptr())) };
+			unsafe { simd.mask_store_ptr_c32s(mask, dst.as_mut_ptr(), cast!(src)) };
+			assert_eq!(loaded, expected);
+			assert_eq!(dst, expected);
+
+			let src: [u64; 8] = core::array::from_fn(|i| i as u64 + 1);
+			let expected: [u64; 8] =
+				core::array::from_fn(|i| if (1..7).contains(&i) { src[i] } else { 0 });
+			let mask = simd.mask_between_m64s(1, 7);
+			let mut dst = [0u64; 8];
+			let loaded: [u64; 8] = unsafe { cast!(simd.mask_load_ptr_u64s(mask, src.as_ptr())) };
+			unsafe { simd.mask_store_ptr_u64s(mask, dst.as_mut_ptr(), cast!(src)) };
+			assert_eq!(loaded, expected);
+			assert_eq!(dst, expected);
+
+			let src: [c64; 4] =
+				core::array::from_fn(|i| c64::new(i as f64 + 1.0, -(i as f64) - 1.0));
+			let expected: [c64; 4] = core::array::from_fn(|i| {
+				if (1..3).contains(&i) {
+					src[i]
+				} else {
+					c64::new(0.0, 0.0)
+				}
+			});
+			let mask = simd.mask_between_m64s(2, 6);
+			let mut dst = [c64::new(0.0, 0.0); 4];
+			let loaded: [c64; 4] = unsafe { cast!(simd.mask_load_ptr_c64s(mask, src.as_ptr())) };
+			unsafe { simd.mask_store_ptr_c64s(mask, dst.as_mut_ptr(), cast!(src)) };
+			assert_eq!(loaded, expected);
+			assert_eq!(dst, expected);
+		}
+	}
+
+	#[cfg(feature = "x86-v4")]
+	#[test]
+	fn test_round_truncate_f32x16() {
+		if let Some(simd) = V4::try_new() {
+			let a = f32x16(
+				-2.5, -1.5, -0.5, 0.5, 1.5, 2.5, 3.7, -3.7, 0.2, -0.2, 100.5, 1e8, -1e8, 7.0, 8.9,
+				-8.9,
+			);
+			assert_eq!(
+				simd.round_f32x16(a),
+				f32x16(
+					-2.0, -2.0, -0.0, 0.0, 2.0, 2.0, 4.0, -4.0, 0.0, -0.0, 100.0, 1e8, -1e8, 7.0,
+					9.0, -9.0,
+				)
+			);
+			assert_eq!(
+				simd.truncate_f32x16(a),
+				f32x16(
+					-2.0, -1.0, -0.0, 0.0, 1.0, 2.0, 3.0, -3.0, 0.0, -0.0, 100.0, 1e8, -1e8, 7.0,
+					8.0, -8.0,
+				)
+			);
+		}
+	}
 }
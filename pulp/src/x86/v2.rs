@@ -585,6 +585,9 @@ macro_rules! impl_simd_unop {
 			}
 		}
 	};
+	($func: ident, $op: ident, $($ty: ident x $factor: literal),*) => {
+		$(impl_simd_unop!($func, $op, $ty, $ty, $factor);)*
+	};
 	($func: ident, $($ty: ident x $factor: literal),*) => {
 		$(impl_simd_unop!($func, $func, $ty, $ty, $factor);)*
 	};
@@ -622,8 +625,10 @@ macro_rules! splat {
 }
 
 impl Simd for V2 {
+	type bf16s = u16x8;
 	type c32s = f32x4;
 	type c64s = f64x2;
+	type f16s = u16x8;
 	type f32s = f32x4;
 	type f64s = f64x2;
 	type i16s = i16x8;
@@ -679,6 +684,12 @@ impl Simd for V2 {
 
 	impl_simd_binop!(max, u8 x 16, i8 x 16, u16 x 8, i16 x 8, u32 x 4, i32 x 4, f32 x 4, f64 x 2);
 
+	impl_simd_binop!(saturating_add, u8 x 16, i8 x 16, u16 x 8, i16 x 8);
+
+	impl_simd_binop!(saturating_sub, u8 x 16, i8 x 16, u16 x 8, i16 x 8);
+
+	impl_simd_binop!(average, u8 x 16, u16 x 8);
+
 	impl_scalar_binop!(max, u64, i64);
 
 	impl_simd_binop!(min, u8 x 16, i8 x 16, u16 x 8, i16 x 8, u32 x 4, i32 x 4, f32 x 4, f64 x 2);
@@ -687,6 +698,36 @@ impl Simd for V2 {
 
 	impl_simd_unop!(not, m8 x 16, u8 x 16, m16 x 8, u16 x 8, m32 x 4, u32 x 4, m64 x 2, u64 x 2);
 
+	impl_simd_unop!(floor, f32 x 4, f64 x 2);
+
+	impl_simd_unop!(ceil, f32 x 4, f64 x 2);
+
+	impl_simd_unop!(trunc, truncate, f32 x 4, f64 x 2);
+
+	impl_simd_unop!(round_ties_even, round, f32 x 4, f64 x 2);
+
+	impl_uniform_shift!(shl, shl, u16 x 8, u32 x 4, u64 x 2);
+
+	impl_uniform_shift!(shr, shr, u16 x 8, u32 x 4, u64 x 2);
+
+	impl_uniform_shift!(sra, shr, i16 x 8, i32 x 4);
+
+	impl_derived_shift!(u8, i64);
+
+	impl_derived_convert!(i32 x 4, u32, i64);
+
+	impl_widen_narrow!(128);
+
+	impl_compress_expand!(128);
+
+	impl_mask_query!(128);
+
+	impl_mul_high!(sse2, sse2, _mm_mul_epu32, _mm_mulhi_epu16, _mm_mulhi_epi16);
+	impl_fixed_point!(ssse3, _mm_mulhrs_epi16, sse4_1, _mm_mul_epi32);
+	impl_recip_approx!(sse, _mm_rcp_ps, _mm_rsqrt_ps);
+	impl_int_reduce!(128);
+	impl_bit_count!(ssse3, _mm_shuffle_epi8, 1);
+
 	fn abs2_c32s(self, a: Self::c32s) -> Self::c32s {
 		let sqr = self.mul_f32s(a, a);
 		let sqr_rev = self
@@ -1043,6 +1084,15 @@ impl Simd for V2 {
 		Scalar128b.rotate_right_u64s(a, amount)
 	}
 
+	#[inline(always)]
+	fn table_lookup_u8s(self, table: Self::u8s, idx: Self::u8s) -> Self::u8s {
+		// indices past 15 saturate to values with the high bit set, which `pshufb` maps to zero
+		let idx = self
+			.sse2
+			._mm_adds_epu8(cast!(idx), self.sse2._mm_set1_epi8(0x70));
+		cast!(self.ssse3._mm_shuffle_epi8(cast!(table), idx))
+	}
+
 	#[inline(always)]
 	fn select_u32s(
 		self,
@@ -1071,6 +1121,21 @@ impl Simd for V2 {
 		cast!(self.sse4_1._mm_blendv_pd(if_false, if_true, mask))
 	}
 
+	#[inline(always)]
+	fn select_u8s(self, mask: Self::m8s, if_true: Self::u8s, if_false: Self::u8s) -> Self::u8s {
+		self.select_u8x16(mask, if_true, if_false)
+	}
+
+	#[inline(always)]
+	fn select_u16s(
+		self,
+		mask: Self::m16s,
+		if_true: Self::u16s,
+		if_false: Self::u16s,
+	) -> Self::u16s {
+		self.select_u16x8(mask, if_true, if_false)
+	}
+
 	#[inline(always)]
 	fn splat_c32s(self, value: c32) -> Self::c32s {
 		cast!(self.splat_f64s(cast!(value)))
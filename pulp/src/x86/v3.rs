@@ -24,6 +24,7 @@ simd_type!({
 		pub bmi2: f!("bmi2"),
 		pub fma: f!("fma"),
 		pub lzcnt: f!("lzcnt"),
+		pub f16c: f!("f16c"),
 	}
 });
 
@@ -321,6 +322,9 @@ macro_rules! impl_simd_unop {
 			}
 		}
 	};
+	($func: ident, $op: ident, $($ty: ident x $factor: literal),*) => {
+		$(impl_simd_unop!($func, $op, $ty, $ty, $factor);)*
+	};
 	($func: ident, $($ty: ident x $factor: literal),*) => {
 		$(impl_simd_unop!($func, $func, $ty, $ty, $factor);)*
 	};
@@ -344,8 +348,10 @@ macro_rules! impl_scalar_binop {
 }
 
 impl Simd for V3 {
+	type bf16s = u16x16;
 	type c32s = f32x8;
 	type c64s = f64x4;
+	type f16s = u16x16;
 	type f32s = f32x8;
 	type f64s = f64x4;
 	type i16s = i16x16;
@@ -399,6 +405,12 @@ impl Simd for V3 {
 
 	impl_simd_binop!(max, u8 x 32, i8 x 32, u16 x 16, i16 x 16, u32 x 8, i32 x 8, f32 x 8, f64 x 4);
 
+	impl_simd_binop!(saturating_add, u8 x 32, i8 x 32, u16 x 16, i16 x 16);
+
+	impl_simd_binop!(saturating_sub, u8 x 32, i8 x 32, u16 x 16, i16 x 16);
+
+	impl_simd_binop!(average, u8 x 32, u16 x 16);
+
 	impl_scalar_binop!(max, u64, i64);
 
 	impl_simd_binop!(min, u8 x 32, i8 x 32, u16 x 16, i16 x 16, u32 x 8, i32 x 8, f32 x 8, f64 x 4);
@@ -407,6 +419,52 @@ impl Simd for V3 {
 
 	impl_simd_unop!(not, m8 x 32, u8 x 32, m16 x 16, u16 x 16, m32 x 8, u32 x 8, m64 x 4, u64 x 4);
 
+	impl_simd_unop!(floor, f32 x 8, f64 x 4);
+
+	impl_simd_unop!(ceil, f32 x 8, f64 x 4);
+
+	impl_simd_unop!(trunc, truncate, f32 x 8, f64 x 4);
+
+	impl_simd_unop!(round_ties_even, round, f32 x 8, f64 x 4);
+
+	impl_uniform_shift!(shl, shl, u16 x 16, u32 x 8, u64 x 4);
+
+	impl_uniform_shift!(shr, shr, u16 x 16, u32 x 8, u64 x 4);
+
+	impl_uniform_shift!(sra, shr, i16 x 16, i32 x 8);
+
+	impl_dyn_shift!(shl, shl, u64 x 4 => u64);
+
+	impl_dyn_shift!(shr, shr, u64 x 4 => u64);
+
+	impl_dyn_shift!(sra, shr, i32 x 8 => u32);
+
+	impl_derived_shift!(u8, i64, dyn i64);
+
+	impl_derived_convert!(i32 x 8, u32, i64);
+
+	impl_widen_narrow!(256);
+	impl_f16_convert!(256);
+
+	impl_compress_expand!(256);
+
+	impl_mask_query!(256);
+
+	impl_mul_sub!(f32x8, f64x4);
+
+	impl_mul_high!(
+		avx2,
+		avx2,
+		_mm256_mul_epu32,
+		_mm256_mulhi_epu16,
+		_mm256_mulhi_epi16
+	);
+	impl_fixed_point!(avx2, _mm256_mulhrs_epi16, avx2, _mm256_mul_epi32);
+	impl_recip_approx!(avx, _mm256_rcp_ps, _mm256_rsqrt_ps);
+	impl_gather_scatter!(256);
+	impl_int_reduce!(split self => *self);
+	impl_bit_count!(avx2, _mm256_shuffle_epi8, 2);
+
 	#[inline(always)]
 	fn abs2_c32s(self, a: Self::c32s) -> Self::c32s {
 		let sqr = self.mul_f32s(a, a);
@@ -1153,6 +1211,11 @@ impl Simd for V3 {
 		))
 	}
 
+	#[inline(always)]
+	fn table_lookup_u8s(self, table: Self::u8s, idx: Self::u8s) -> Self::u8s {
+		cast!(avx2_pshufb(self, cast!(table), cast!(idx)))
+	}
+
 	#[inline(always)]
 	fn select_u32s(
 		self,
@@ -1181,6 +1244,21 @@ impl Simd for V3 {
 		cast!(self.avx._mm256_blendv_pd(if_false, if_true, mask))
 	}
 
+	#[inline(always)]
+	fn select_u8s(self, mask: Self::m8s, if_true: Self::u8s, if_false: Self::u8s) -> Self::u8s {
+		self.select_u8x32(mask, if_true, if_false)
+	}
+
+	#[inline(always)]
+	fn select_u16s(
+		self,
+		mask: Self::m16s,
+		if_true: Self::u16s,
+		if_false: Self::u16s,
+	) -> Self::u16s {
+		self.select_u16x16(mask, if_true, if_false)
+	}
+
 	#[inline(always)]
 	fn splat_c32s(self, value: c32) -> Self::c32s {
 		cast!(self.splat_f64s(cast!(value)))
@@ -1315,8 +1393,10 @@ macro_rules! impl_scalar_binop {
 }
 
 impl Simd for V3_128b {
+	type bf16s = u16x8;
 	type c32s = f32x4;
 	type c64s = f64x2;
+	type f16s = u16x8;
 	type f32s = f32x4;
 	type f64s = f64x2;
 	type i16s = i16x8;
@@ -1372,6 +1452,12 @@ impl Simd for V3_128b {
 
 	impl_simd_binop!(max, u8 x 16, i8 x 16, u16 x 8, i16 x 8, u32 x 4, i32 x 4, f32 x 4, f64 x 2);
 
+	impl_simd_binop!(saturating_add, u8 x 16, i8 x 16, u16 x 8, i16 x 8);
+
+	impl_simd_binop!(saturating_sub, u8 x 16, i8 x 16, u16 x 8, i16 x 8);
+
+	impl_simd_binop!(average, u8 x 16, u16 x 8);
+
 	impl_scalar_binop!(max, u64, i64);
 
 	impl_simd_binop!(min, u8 x 16, i8 x 16, u16 x 8, i16 x 8, u32 x 4, i32 x 4, f32 x 4, f64 x 2);
@@ -1380,6 +1466,46 @@ impl Simd for V3_128b {
 
 	impl_simd_unop!(not, m8 x 16, u8 x 16, m16 x 8, u16 x 8, m32 x 4, u32 x 4, m64 x 2, u64 x 2);
 
+	impl_simd_unop!(floor, f32 x 4, f64 x 2);
+
+	impl_simd_unop!(ceil, f32 x 4, f64 x 2);
+
+	impl_simd_unop!(trunc, truncate, f32 x 4, f64 x 2);
+
+	impl_simd_unop!(round_ties_even, round, f32 x 4, f64 x 2);
+
+	impl_uniform_shift!(shl, shl, u16 x 8, u32 x 4, u64 x 2);
+
+	impl_uniform_shift!(shr, shr, u16 x 8, u32 x 4, u64 x 2);
+
+	impl_uniform_shift!(sra, shr, i16 x 8, i32 x 4);
+
+	impl_dyn_shift!(shl, shl, u64 x 2 => u64);
+
+	impl_dyn_shift!(shr, shr, u64 x 2 => u64);
+
+	impl_dyn_shift!(sra, shr, i32 x 4 => u32);
+
+	impl_derived_shift!(u8, i64, dyn i64);
+
+	impl_derived_convert!(i32 x 4, u32, i64);
+
+	impl_widen_narrow!(128);
+	impl_f16_convert!(128);
+
+	impl_compress_expand!(128);
+
+	impl_mask_query!(128);
+
+	impl_mul_sub!(f32x4, f64x2);
+
+	impl_mul_high!(sse2, sse2, _mm_mul_epu32, _mm_mulhi_epu16, _mm_mulhi_epi16);
+	impl_fixed_point!(ssse3, _mm_mulhrs_epi16, sse4_1, _mm_mul_epi32);
+	impl_recip_approx!(sse, _mm_rcp_ps, _mm_rsqrt_ps);
+	impl_gather_scatter!(128);
+	impl_int_reduce!(128);
+	impl_bit_count!(ssse3, _mm_shuffle_epi8, 1);
+
 	#[inline(always)]
 	fn abs2_c32s(self, a: Self::c32s) -> Self::c32s {
 		let sqr = self.mul_f32s(a, a);
@@ -1860,6 +1986,15 @@ impl Simd for V3_128b {
 		)
 	}
 
+	#[inline(always)]
+	fn table_lookup_u8s(self, table: Self::u8s, idx: Self::u8s) -> Self::u8s {
+		// indices past 15 saturate to values with the high bit set, which `pshufb` maps to zero
+		let idx = self
+			.sse2
+			._mm_adds_epu8(cast!(idx), self.sse2._mm_set1_epi8(0x70));
+		cast!(self.ssse3._mm_shuffle_epi8(cast!(table), idx))
+	}
+
 	#[inline(always)]
 	fn select_u32s(
 		self,
@@ -1880,6 +2015,21 @@ impl Simd for V3_128b {
 		self.select_u64x2(mask, if_true, if_false)
 	}
 
+	#[inline(always)]
+	fn select_u8s(self, mask: Self::m8s, if_true: Self::u8s, if_false: Self::u8s) -> Self::u8s {
+		self.select_u8x16(mask, if_true, if_false)
+	}
+
+	#[inline(always)]
+	fn select_u16s(
+		self,
+		mask: Self::m16s,
+		if_true: Self::u16s,
+		if_false: Self::u16s,
+	) -> Self::u16s {
+		self.select_u16x8(mask, if_true, if_false)
+	}
+
 	#[inline(always)]
 	fn splat_c32s(self, value: c32) -> Self::c32s {
 		cast!(self.splat_f64x2(cast!(value)))
@@ -1912,7 +2062,19 @@ impl Simd for V3_128b {
 
 	#[inline(always)]
 	fn vectorize<Op: WithSimd>(self, op: Op) -> Op::Output {
-		Simd::vectorize(self.0, op)
+		struct Impl<Op> {
+			this: V3_128b,
+			op: Op,
+		}
+		impl<Op: WithSimd> crate::NullaryFnOnce for Impl<Op> {
+			type Output = Op::Output;
+
+			#[inline(always)]
+			fn call(self) -> Self::Output {
+				self.op.with_simd(self.this)
+			}
+		}
+		self.0.vectorize(Impl { this: self, op })
 	}
 
 	#[inline(always)]
@@ -1942,8 +2104,10 @@ impl Simd for V3_128b {
 }
 
 impl Simd for V3_256b {
+	type bf16s = u16x16;
 	type c32s = f32x8;
 	type c64s = f64x4;
+	type f16s = u16x16;
 	type f32s = f32x8;
 	type f64s = f64x4;
 	type i16s = i16x16;
@@ -1995,23 +2159,27 @@ impl Simd for V3_256b {
 
 	impl_simd_binop!(max, u8 x 32, i8 x 32, u16 x 16, i16 x 16, u32 x 8, i32 x 8, f32 x 8, f64 x 4);
 
+	impl_simd_binop!(saturating_add, u8 x 32, i8 x 32, u16 x 16, i16 x 16);
+
+	impl_simd_binop!(saturating_sub, u8 x 32, i8 x 32, u16 x 16, i16 x 16);
+
+	impl_simd_binop!(average, u8 x 32, u16 x 16);
+
 	impl_simd_binop!(min, u8 x 32, i8 x 32, u16 x 16, i16 x 16, u32 x 8, i32 x 8, f32 x 8, f64 x 4);
 
 	impl_simd_unop!(not, m8 x 32, u8 x 32, m16 x 16, u16 x 16, m32 x 8, u32 x 8, m64 x 4, u64 x 4);
 
+	impl_simd_unop!(floor, f32 x 8, f64 x 4);
+
+	impl_simd_unop!(ceil, f32 x 8, f64 x 4);
+
+	impl_simd_unop!(trunc, truncate, f32 x 8, f64 x 4);
+
+	impl_simd_unop!(round_ties_even, round, f32 x 8, f64 x 4);
+
+	inherit_256b!();
+
 	inherit!({
-		fn abs2_c32s(self, a: Self::c32s) -> Self::c32s;
-		fn abs2_c64s(self, a: Self::c64s) -> Self::c64s;
-		fn abs_max_c32s(self, a: Self::c32s) -> Self::c32s;
-		fn abs_max_c64s(self, a: Self::c64s) -> Self::c64s;
-		fn add_c32s(self, a: Self::c32s, b: Self::c32s) -> Self::c32s;
-		fn add_c64s(self, a: Self::c64s, b: Self::c64s) -> Self::c64s;
-		fn conj_c32s(self, a: Self::c32s) -> Self::c32s;
-		fn conj_c64s(self, a: Self::c64s) -> Self::c64s;
-		fn conj_mul_add_c32s(self, a: Self::c32s, b: Self::c32s, c: Self::c32s) -> Self::c32s;
-		fn conj_mul_add_c64s(self, a: Self::c64s, b: Self::c64s, c: Self::c64s) -> Self::c64s;
-		fn conj_mul_c32s(self, a: Self::c32s, b: Self::c32s) -> Self::c32s;
-		fn conj_mul_c64s(self, a: Self::c64s, b: Self::c64s) -> Self::c64s;
 		fn equal_c32s(self, a: Self::c32s, b: Self::c32s) -> Self::m32s;
 		fn equal_c64s(self, a: Self::c64s, b: Self::c64s) -> Self::m64s;
 		fn mask_between_m32s(self, start: u32, end: u32) -> MemMask<Self::m32s>;
@@ -2063,6 +2231,24 @@ impl Simd for V3_256b {
 		/// # Safety
 		///
 		/// See the trait-level safety documentation.
+		unsafe fn mask_gather_ptr_u32s(
+			self,
+			mask: MemMask<Self::m32s>,
+			ptr: *const u32,
+			idx: Self::u32s,
+		) -> Self::u32s;
+		/// # Safety
+		///
+		/// See the trait-level safety documentation.
+		unsafe fn mask_gather_ptr_u64s(
+			self,
+			mask: MemMask<Self::m64s>,
+			ptr: *const u64,
+			idx: Self::u64s,
+		) -> Self::u64s;
+		/// # Safety
+		///
+		/// See the trait-level safety documentation.
 		unsafe fn mask_store_ptr_c32s(
 			self,
 			mask: MemMask<Self::m32s>,
@@ -2114,48 +2300,6 @@ impl Simd for V3_256b {
 			ptr: *mut u64,
 			values: Self::u64s,
 		);
-		fn mul_add_c32s(self, a: Self::c32s, b: Self::c32s, c: Self::c32s) -> Self::c32s;
-		fn mul_add_c64s(self, a: Self::c64s, b: Self::c64s, c: Self::c64s) -> Self::c64s;
-		fn mul_add_e_f32s(self, a: Self::f32s, b: Self::f32s, c: Self::f32s) -> Self::f32s;
-		fn mul_add_e_f64s(self, a: Self::f64s, b: Self::f64s, c: Self::f64s) -> Self::f64s;
-		fn mul_add_f32s(self, a: Self::f32s, b: Self::f32s, c: Self::f32s) -> Self::f32s;
-		fn mul_add_f64s(self, a: Self::f64s, b: Self::f64s, c: Self::f64s) -> Self::f64s;
-		fn negate_mul_add_e_f32s(self, a: Self::f32s, b: Self::f32s, c: Self::f32s) -> Self::f32s;
-		fn negate_mul_add_e_f64s(self, a: Self::f64s, b: Self::f64s, c: Self::f64s) -> Self::f64s;
-		fn negate_mul_add_f32s(self, a: Self::f32s, b: Self::f32s, c: Self::f32s) -> Self::f32s;
-		fn negate_mul_add_f64s(self, a: Self::f64s, b: Self::f64s, c: Self::f64s) -> Self::f64s;
-		fn mul_c32s(self, a: Self::c32s, b: Self::c32s) -> Self::c32s;
-		fn mul_c64s(self, a: Self::c64s, b: Self::c64s) -> Self::c64s;
-		fn neg_c32s(self, a: Self::c32s) -> Self::c32s;
-		fn neg_c64s(self, a: Self::c64s) -> Self::c64s;
-		fn min_u64s(self, a: Self::u64s, b: Self::u64s) -> Self::u64s;
-		fn min_i64s(self, a: Self::i64s, b: Self::i64s) -> Self::i64s;
-		fn max_u64s(self, a: Self::u64s, b: Self::u64s) -> Self::u64s;
-		fn max_i64s(self, a: Self::i64s, b: Self::i64s) -> Self::i64s;
-		fn mul_u64s(self, a: Self::u64s, b: Self::u64s) -> Self::u64s;
-		fn mul_i64s(self, a: Self::i64s, b: Self::i64s) -> Self::i64s;
-		fn partial_load_u32s(self, slice: &[u32]) -> Self::u32s;
-		fn partial_load_u64s(self, slice: &[u64]) -> Self::u64s;
-		fn partial_store_u32s(self, slice: &mut [u32], values: Self::u32s);
-		fn partial_store_u64s(self, slice: &mut [u64], values: Self::u64s);
-		fn reduce_max_c32s(self, a: Self::c32s) -> c32;
-		fn reduce_max_c64s(self, a: Self::c64s) -> c64;
-		fn reduce_max_f32s(self, a: Self::f32s) -> f32;
-		fn reduce_max_f64s(self, a: Self::f64s) -> f64;
-		fn reduce_min_c32s(self, a: Self::c32s) -> c32;
-		fn reduce_min_c64s(self, a: Self::c64s) -> c64;
-		fn reduce_min_f32s(self, a: Self::f32s) -> f32;
-		fn reduce_min_f64s(self, a: Self::f64s) -> f64;
-		fn reduce_product_f32s(self, a: Self::f32s) -> f32;
-		fn reduce_product_f64s(self, a: Self::f64s) -> f64;
-		fn reduce_sum_c32s(self, a: Self::c32s) -> c32;
-		fn reduce_sum_c64s(self, a: Self::c64s) -> c64;
-		fn reduce_sum_f32s(self, a: Self::f32s) -> f32;
-		fn reduce_sum_f64s(self, a: Self::f64s) -> f64;
-		fn rotate_right_c32s(self, a: Self::c32s, amount: usize) -> Self::c32s;
-		fn rotate_right_c64s(self, a: Self::c64s, amount: usize) -> Self::c64s;
-		fn rotate_right_u32s(self, a: Self::u32s, amount: usize) -> Self::u32s;
-		fn rotate_right_u64s(self, a: Self::u64s, amount: usize) -> Self::u64s;
 		fn select_u32s(
 			self,
 			mask: Self::m32s,
@@ -2168,20 +2312,52 @@ impl Simd for V3_256b {
 			if_true: Self::u64s,
 			if_false: Self::u64s,
 		) -> Self::u64s;
-		fn splat_c32s(self, a: c32) -> Self::c32s;
-		fn splat_c64s(self, a: c64) -> Self::c64s;
-		fn sub_c32s(self, a: Self::c32s, b: Self::c32s) -> Self::c32s;
-		fn sub_c64s(self, a: Self::c64s, b: Self::c64s) -> Self::c64s;
-		fn swap_re_im_c32s(self, a: Self::c32s) -> Self::c32s;
-		fn swap_re_im_c64s(self, a: Self::c64s) -> Self::c64s;
-		fn widening_mul_u32s(self, a: Self::u32s, b: Self::u32s) -> (Self::u32s, Self::u32s);
-		fn wrapping_dyn_shl_u32s(self, a: Self::u32s, amount: Self::u32s) -> Self::u32s;
-		fn wrapping_dyn_shr_u32s(self, a: Self::u32s, amount: Self::u32s) -> Self::u32s;
+		fn select_u8s(self, mask: Self::m8s, if_true: Self::u8s, if_false: Self::u8s) -> Self::u8s;
+		fn select_u16s(
+			self,
+			mask: Self::m16s,
+			if_true: Self::u16s,
+			if_false: Self::u16s,
+		) -> Self::u16s;
+		fn compress_store_u32s(
+			self,
+			mask: Self::m32s,
+			values: Self::u32s,
+			out: &mut [u32],
+		) -> usize;
+		fn compress_store_u64s(
+			self,
+			mask: Self::m64s,
+			values: Self::u64s,
+			out: &mut [u64],
+		) -> usize;
+		fn expand_load_u32s(self, mask: Self::m32s, values: &[u32]) -> Self::u32s;
+		fn expand_load_u64s(self, mask: Self::m64s, values: &[u64]) -> Self::u64s;
+		fn to_bitmask_m8s(self, mask: Self::m8s) -> u64;
+		fn to_bitmask_m16s(self, mask: Self::m16s) -> u64;
+		fn to_bitmask_m32s(self, mask: Self::m32s) -> u64;
+		fn to_bitmask_m64s(self, mask: Self::m64s) -> u64;
+		fn from_bitmask_m8s(self, bits: u64) -> Self::m8s;
+		fn from_bitmask_m16s(self, bits: u64) -> Self::m16s;
+		fn from_bitmask_m32s(self, bits: u64) -> Self::m32s;
+		fn from_bitmask_m64s(self, bits: u64) -> Self::m64s;
 	});
 
 	#[inline(always)]
 	fn vectorize<Op: WithSimd>(self, op: Op) -> Op::Output {
-		Simd::vectorize(self.0, op)
+		struct Impl<Op> {
+			this: V3_256b,
+			op: Op,
+		}
+		impl<Op: WithSimd> crate::NullaryFnOnce for Impl<Op> {
+			type Output = Op::Output;
+
+			#[inline(always)]
+			fn call(self) -> Self::Output {
+				self.op.with_simd(self.this)
+			}
+		}
+		self.0.vectorize(Impl { this: self, op })
 	}
 
 	#[inline(always)]
@@ -2196,8 +2372,10 @@ impl Simd for V3_256b {
 }
 
 impl Simd for V3_512b {
+	type bf16s = u16x32;
 	type c32s = f32x16;
 	type c64s = f64x8;
+	type f16s = u16x32;
 	type f32s = f32x16;
 	type f64s = f64x8;
 	type i16s = i16x32;
@@ -2215,7 +2393,21 @@ impl Simd for V3_512b {
 
 	const REGISTER_COUNT: usize = 8;
 
+	impl_int_reduce!(split self => V3_256b(*self));
+
 	inherit_x2!(V3_256b(*self), {
+		fn count_ones_u8s(self, a: Self::u8s) -> Self::u8s;
+		fn count_ones_u16s(self, a: Self::u16s) -> Self::u16s;
+		fn count_ones_u32s(self, a: Self::u32s) -> Self::u32s;
+		fn count_ones_u64s(self, a: Self::u64s) -> Self::u64s;
+		fn leading_zeros_u8s(self, a: Self::u8s) -> Self::u8s;
+		fn leading_zeros_u16s(self, a: Self::u16s) -> Self::u16s;
+		fn leading_zeros_u32s(self, a: Self::u32s) -> Self::u32s;
+		fn leading_zeros_u64s(self, a: Self::u64s) -> Self::u64s;
+		fn trailing_zeros_u8s(self, a: Self::u8s) -> Self::u8s;
+		fn trailing_zeros_u16s(self, a: Self::u16s) -> Self::u16s;
+		fn trailing_zeros_u32s(self, a: Self::u32s) -> Self::u32s;
+		fn trailing_zeros_u64s(self, a: Self::u64s) -> Self::u64s;
 		fn abs2_c32s(self, a: Self::c32s) -> Self::c32s;
 		fn abs2_c64s(self, a: Self::c64s) -> Self::c64s;
 		fn abs_max_c32s(self, a: Self::c32s) -> Self::c32s;
@@ -2242,6 +2434,16 @@ impl Simd for V3_512b {
 		fn conj_mul_add_c64s(self, a: Self::c64s, b: Self::c64s, c: Self::c64s) -> Self::c64s;
 		fn conj_mul_c32s(self, a: Self::c32s, b: Self::c32s) -> Self::c32s;
 		fn conj_mul_c64s(self, a: Self::c64s, b: Self::c64s) -> Self::c64s;
+		fn convert_f32s_i32s(self, a: Self::f32s) -> Self::i32s;
+		fn convert_f32s_u32s(self, a: Self::f32s) -> Self::u32s;
+		fn convert_f64s_i64s(self, a: Self::f64s) -> Self::i64s;
+		fn convert_f64s_u64s(self, a: Self::f64s) -> Self::u64s;
+		fn convert_i32s_f32s(self, a: Self::i32s) -> Self::f32s;
+		fn convert_u32s_f32s(self, a: Self::u32s) -> Self::f32s;
+		fn convert_i64s_f64s(self, a: Self::i64s) -> Self::f64s;
+		fn convert_u64s_f64s(self, a: Self::u64s) -> Self::f64s;
+		fn ceil_f32s(self, a: Self::f32s) -> Self::f32s;
+		fn ceil_f64s(self, a: Self::f64s) -> Self::f64s;
 		fn div_f32s(self, a: Self::f32s, b: Self::f32s) -> Self::f32s;
 		fn div_f64s(self, a: Self::f64s, b: Self::f64s) -> Self::f64s;
 		fn equal_f32s(self, a: Self::f32s, b: Self::f32s) -> Self::m32s;
@@ -2256,6 +2458,8 @@ impl Simd for V3_512b {
 		fn equal_i32s(self, a: Self::i32s, b: Self::i32s) -> Self::m32s;
 		fn equal_u64s(self, a: Self::u64s, b: Self::u64s) -> Self::m64s;
 		fn equal_i64s(self, a: Self::i64s, b: Self::i64s) -> Self::m64s;
+		fn floor_f32s(self, a: Self::f32s) -> Self::f32s;
+		fn floor_f64s(self, a: Self::f64s) -> Self::f64s;
 		fn greater_than_or_equal_u8s(self, a: Self::u8s, b: Self::u8s) -> Self::m8s;
 		fn greater_than_or_equal_i8s(self, a: Self::i8s, b: Self::i8s) -> Self::m8s;
 		fn greater_than_or_equal_u16s(self, a: Self::u16s, b: Self::u16s) -> Self::m16s;
@@ -2298,6 +2502,16 @@ impl Simd for V3_512b {
 		fn less_than_f64s(self, a: Self::f64s, b: Self::f64s) -> Self::m64s;
 		fn min_u8s(self, a: Self::u8s, b: Self::u8s) -> Self::u8s;
 		fn max_u8s(self, a: Self::u8s, b: Self::u8s) -> Self::u8s;
+		fn saturating_add_u8s(self, a: Self::u8s, b: Self::u8s) -> Self::u8s;
+		fn saturating_add_i8s(self, a: Self::i8s, b: Self::i8s) -> Self::i8s;
+		fn saturating_add_u16s(self, a: Self::u16s, b: Self::u16s) -> Self::u16s;
+		fn saturating_add_i16s(self, a: Self::i16s, b: Self::i16s) -> Self::i16s;
+		fn saturating_sub_u8s(self, a: Self::u8s, b: Self::u8s) -> Self::u8s;
+		fn saturating_sub_i8s(self, a: Self::i8s, b: Self::i8s) -> Self::i8s;
+		fn saturating_sub_u16s(self, a: Self::u16s, b: Self::u16s) -> Self::u16s;
+		fn saturating_sub_i16s(self, a: Self::i16s, b: Self::i16s) -> Self::i16s;
+		fn average_u8s(self, a: Self::u8s, b: Self::u8s) -> Self::u8s;
+		fn average_u16s(self, a: Self::u16s, b: Self::u16s) -> Self::u16s;
 		fn min_i8s(self, a: Self::i8s, b: Self::i8s) -> Self::i8s;
 		fn max_i8s(self, a: Self::i8s, b: Self::i8s) -> Self::i8s;
 		fn min_u16s(self, a: Self::u16s, b: Self::u16s) -> Self::u16s;
@@ -2322,6 +2536,26 @@ impl Simd for V3_512b {
 		fn negate_mul_add_e_f64s(self, a: Self::f64s, b: Self::f64s, c: Self::f64s) -> Self::f64s;
 		fn negate_mul_add_f32s(self, a: Self::f32s, b: Self::f32s, c: Self::f32s) -> Self::f32s;
 		fn negate_mul_add_f64s(self, a: Self::f64s, b: Self::f64s, c: Self::f64s) -> Self::f64s;
+		fn mul_sub_e_f32s(self, a: Self::f32s, b: Self::f32s, c: Self::f32s) -> Self::f32s;
+		fn mul_sub_e_f64s(self, a: Self::f64s, b: Self::f64s, c: Self::f64s) -> Self::f64s;
+		fn mul_sub_f32s(self, a: Self::f32s, b: Self::f32s, c: Self::f32s) -> Self::f32s;
+		fn mul_sub_f64s(self, a: Self::f64s, b: Self::f64s, c: Self::f64s) -> Self::f64s;
+		fn negate_mul_sub_e_f32s(self, a: Self::f32s, b: Self::f32s, c: Self::f32s) -> Self::f32s;
+		fn negate_mul_sub_e_f64s(self, a: Self::f64s, b: Self::f64s, c: Self::f64s) -> Self::f64s;
+		fn negate_mul_sub_f32s(self, a: Self::f32s, b: Self::f32s, c: Self::f32s) -> Self::f32s;
+		fn negate_mul_sub_f64s(self, a: Self::f64s, b: Self::f64s, c: Self::f64s) -> Self::f64s;
+		fn mul_addsub_e_f32s(self, a: Self::f32s, b: Self::f32s, c: Self::f32s) -> Self::f32s;
+		fn mul_addsub_e_f64s(self, a: Self::f64s, b: Self::f64s, c: Self::f64s) -> Self::f64s;
+		fn mul_addsub_f32s(self, a: Self::f32s, b: Self::f32s, c: Self::f32s) -> Self::f32s;
+		fn mul_addsub_f64s(self, a: Self::f64s, b: Self::f64s, c: Self::f64s) -> Self::f64s;
+		fn mul_subadd_e_f32s(self, a: Self::f32s, b: Self::f32s, c: Self::f32s) -> Self::f32s;
+		fn mul_subadd_e_f64s(self, a: Self::f64s, b: Self::f64s, c: Self::f64s) -> Self::f64s;
+		fn mul_subadd_f32s(self, a: Self::f32s, b: Self::f32s, c: Self::f32s) -> Self::f32s;
+		fn mul_subadd_f64s(self, a: Self::f64s, b: Self::f64s, c: Self::f64s) -> Self::f64s;
+		fn recip_approx_f32s(self, a: Self::f32s) -> Self::f32s;
+		fn rsqrt_approx_f32s(self, a: Self::f32s) -> Self::f32s;
+		fn recip_approx_refined_f32s(self, a: Self::f32s) -> Self::f32s;
+		fn rsqrt_approx_refined_f32s(self, a: Self::f32s) -> Self::f32s;
 		fn mul_c32s(self, a: Self::c32s, b: Self::c32s) -> Self::c32s;
 		fn mul_c64s(self, a: Self::c64s, b: Self::c64s) -> Self::c64s;
 		fn mul_f32s(self, a: Self::f32s, b: Self::f32s) -> Self::f32s;
@@ -2332,6 +2566,11 @@ impl Simd for V3_512b {
 		fn mul_i32s(self, a: Self::i32s, b: Self::i32s) -> Self::i32s;
 		fn mul_u64s(self, a: Self::u64s, b: Self::u64s) -> Self::u64s;
 		fn mul_i64s(self, a: Self::i64s, b: Self::i64s) -> Self::i64s;
+		fn mul_u8s(self, a: Self::u8s, b: Self::u8s) -> Self::u8s;
+		fn mul_high_u16s(self, a: Self::u16s, b: Self::u16s) -> Self::u16s;
+		fn mul_high_i16s(self, a: Self::i16s, b: Self::i16s) -> Self::i16s;
+		fn mul_round_q15_i16s(self, a: Self::i16s, b: Self::i16s) -> Self::i16s;
+		fn mul_round_q31_i32s(self, a: Self::i32s, b: Self::i32s) -> Self::i32s;
 		fn neg_c32s(self, a: Self::c32s) -> Self::c32s;
 		fn neg_c64s(self, a: Self::c64s) -> Self::c64s;
 		fn not_m8s(self, a: Self::m8s) -> Self::m8s;
@@ -2354,6 +2593,8 @@ impl Simd for V3_512b {
 		fn or_u16s(self, a: Self::u16s, b: Self::u16s) -> Self::u16s;
 		fn or_u32s(self, a: Self::u32s, b: Self::u32s) -> Self::u32s;
 		fn or_u64s(self, a: Self::u64s, b: Self::u64s) -> Self::u64s;
+		fn round_ties_even_f32s(self, a: Self::f32s) -> Self::f32s;
+		fn round_ties_even_f64s(self, a: Self::f64s) -> Self::f64s;
 		fn select_u32s(
 			self,
 			mask: Self::m32s,
@@ -2366,6 +2607,13 @@ impl Simd for V3_512b {
 			if_true: Self::u64s,
 			if_false: Self::u64s,
 		) -> Self::u64s;
+		fn select_u8s(self, mask: Self::m8s, if_true: Self::u8s, if_false: Self::u8s) -> Self::u8s;
+		fn select_u16s(
+			self,
+			mask: Self::m16s,
+			if_true: Self::u16s,
+			if_false: Self::u16s,
+		) -> Self::u16s;
 		fn sub_c32s(self, a: Self::c32s, b: Self::c32s) -> Self::c32s;
 		fn sub_c64s(self, a: Self::c64s, b: Self::c64s) -> Self::c64s;
 		fn sub_f32s(self, a: Self::f32s, b: Self::f32s) -> Self::f32s;
@@ -2380,8 +2628,14 @@ impl Simd for V3_512b {
 		fn sub_i64s(self, a: Self::i64s, b: Self::i64s) -> Self::i64s;
 		fn swap_re_im_c32s(self, a: Self::c32s) -> Self::c32s;
 		fn swap_re_im_c64s(self, a: Self::c64s) -> Self::c64s;
+		fn trunc_f32s(self, a: Self::f32s) -> Self::f32s;
+		fn trunc_f64s(self, a: Self::f64s) -> Self::f64s;
 		fn wrapping_dyn_shl_u32s(self, a: Self::u32s, amount: Self::u32s) -> Self::u32s;
 		fn wrapping_dyn_shr_u32s(self, a: Self::u32s, amount: Self::u32s) -> Self::u32s;
+		fn wrapping_dyn_shl_u64s(self, a: Self::u64s, amount: Self::u64s) -> Self::u64s;
+		fn wrapping_dyn_shr_u64s(self, a: Self::u64s, amount: Self::u64s) -> Self::u64s;
+		fn wrapping_dyn_sra_i32s(self, a: Self::i32s, amount: Self::u32s) -> Self::i32s;
+		fn wrapping_dyn_sra_i64s(self, a: Self::i64s, amount: Self::u64s) -> Self::i64s;
 		fn xor_m8s(self, a: Self::m8s, b: Self::m8s) -> Self::m8s;
 		fn xor_m16s(self, a: Self::m16s, b: Self::m16s) -> Self::m16s;
 		fn xor_m32s(self, a: Self::m32s, b: Self::m32s) -> Self::m32s;
@@ -2394,6 +2648,21 @@ impl Simd for V3_512b {
 		fn xor_u64s(self, a: Self::u64s, b: Self::u64s) -> Self::u64s;
 	});
 
+	inherit_x2!(V3_256b(*self), first, {
+		fn wrapping_shl_u8s(self, a: Self::u8s, amount: u32) -> Self::u8s;
+		fn wrapping_shl_u16s(self, a: Self::u16s, amount: u32) -> Self::u16s;
+		fn wrapping_shl_u32s(self, a: Self::u32s, amount: u32) -> Self::u32s;
+		fn wrapping_shl_u64s(self, a: Self::u64s, amount: u32) -> Self::u64s;
+		fn wrapping_shr_u8s(self, a: Self::u8s, amount: u32) -> Self::u8s;
+		fn wrapping_shr_u16s(self, a: Self::u16s, amount: u32) -> Self::u16s;
+		fn wrapping_shr_u32s(self, a: Self::u32s, amount: u32) -> Self::u32s;
+		fn wrapping_shr_u64s(self, a: Self::u64s, amount: u32) -> Self::u64s;
+		fn wrapping_sra_i8s(self, a: Self::i8s, amount: u32) -> Self::i8s;
+		fn wrapping_sra_i16s(self, a: Self::i16s, amount: u32) -> Self::i16s;
+		fn wrapping_sra_i32s(self, a: Self::i32s, amount: u32) -> Self::i32s;
+		fn wrapping_sra_i64s(self, a: Self::i64s, amount: u32) -> Self::i64s;
+	});
+
 	inherit_x2!(V3_256b(*self), splat, {
 		fn splat_c32s(self, value: c32) -> Self::c32s;
 		fn splat_c64s(self, value: c64) -> Self::c64s;
@@ -2409,10 +2678,99 @@ impl Simd for V3_512b {
 		fn splat_i64s(self, value: i64) -> Self::i64s;
 	});
 
+	inherit_x2!(V3_256b(*self), widen, {
+		fn widen_low_u8s(self, a: Self::u8s) -> Self::u16s;
+		fn widen_high_u8s(self, a: Self::u8s) -> Self::u16s;
+		fn widen_low_i8s(self, a: Self::i8s) -> Self::i16s;
+		fn widen_high_i8s(self, a: Self::i8s) -> Self::i16s;
+		fn widen_low_u16s(self, a: Self::u16s) -> Self::u32s;
+		fn widen_high_u16s(self, a: Self::u16s) -> Self::u32s;
+		fn widen_low_i16s(self, a: Self::i16s) -> Self::i32s;
+		fn widen_high_i16s(self, a: Self::i16s) -> Self::i32s;
+		fn widen_low_u32s(self, a: Self::u32s) -> Self::u64s;
+		fn widen_high_u32s(self, a: Self::u32s) -> Self::u64s;
+		fn widen_low_i32s(self, a: Self::i32s) -> Self::i64s;
+		fn widen_high_i32s(self, a: Self::i32s) -> Self::i64s;
+		fn widen_low_f32s(self, a: Self::f32s) -> Self::f64s;
+		fn widen_high_f32s(self, a: Self::f32s) -> Self::f64s;
+	});
+
+	inherit_x2!(V3_256b(*self), narrow, {
+		fn narrow_u16s(self, a: Self::u16s, b: Self::u16s) -> Self::u8s;
+		fn narrow_i16s(self, a: Self::i16s, b: Self::i16s) -> Self::i8s;
+		fn narrow_u32s(self, a: Self::u32s, b: Self::u32s) -> Self::u16s;
+		fn narrow_i32s(self, a: Self::i32s, b: Self::i32s) -> Self::i16s;
+		fn narrow_u64s(self, a: Self::u64s, b: Self::u64s) -> Self::u32s;
+		fn narrow_i64s(self, a: Self::i64s, b: Self::i64s) -> Self::i32s;
+		fn narrow_f64s(self, a: Self::f64s, b: Self::f64s) -> Self::f32s;
+		fn narrow_saturating_u16s(self, a: Self::u16s, b: Self::u16s) -> Self::u8s;
+		fn narrow_saturating_i16s(self, a: Self::i16s, b: Self::i16s) -> Self::i8s;
+		fn narrow_saturating_u32s(self, a: Self::u32s, b: Self::u32s) -> Self::u16s;
+		fn narrow_saturating_i32s(self, a: Self::i32s, b: Self::i32s) -> Self::i16s;
+		fn narrow_saturating_u64s(self, a: Self::u64s, b: Self::u64s) -> Self::u32s;
+		fn narrow_saturating_i64s(self, a: Self::i64s, b: Self::i64s) -> Self::i32s;
+		fn narrow_saturating_unsigned_i16s(self, a: Self::i16s, b: Self::i16s) -> Self::u8s;
+		fn narrow_saturating_unsigned_i32s(self, a: Self::i32s, b: Self::i32s) -> Self::u16s;
+		fn narrow_saturating_unsigned_i64s(self, a: Self::i64s, b: Self::i64s) -> Self::u32s;
+		fn convert_f32s_f16s(self, a: Self::f32s, b: Self::f32s) -> Self::f16s;
+	});
+
 	inherit_x2!(V3_256b(*self), wide, {
 		fn widening_mul_u32s(self, a: Self::u32s, b: Self::u32s) -> (Self::u32s, Self::u32s);
+		fn widening_mul_u64s(self, a: Self::u64s, b: Self::u64s) -> (Self::u64s, Self::u64s);
 	});
 
+	#[inline(always)]
+	fn convert_f16s_f32s(self, a: Self::f16s) -> (Self::f32s, Self::f32s) {
+		let simd = V3_256b(*self);
+		let a: [_; 2] = cast!(a);
+		let (a0, a1) = simd.convert_f16s_f32s(a[0]);
+		let (a2, a3) = simd.convert_f16s_f32s(a[1]);
+		(cast!([a0, a1]), cast!([a2, a3]))
+	}
+
+	impl_mask_query!(x2);
+
+	#[inline(always)]
+	fn compress_store_u32s(self, mask: Self::m32s, values: Self::u32s, out: &mut [u32]) -> usize {
+		let simd = V3_256b(*self);
+		let [m0, m1]: [_; 2] = cast!(mask);
+		let [v0, v1]: [_; 2] = cast!(values);
+		let n = simd.compress_store_u32s(m0, v0, out);
+		n + simd.compress_store_u32s(m1, v1, &mut out[n..])
+	}
+
+	#[inline(always)]
+	fn compress_store_u64s(self, mask: Self::m64s, values: Self::u64s, out: &mut [u64]) -> usize {
+		let simd = V3_256b(*self);
+		let [m0, m1]: [_; 2] = cast!(mask);
+		let [v0, v1]: [_; 2] = cast!(values);
+		let n = simd.compress_store_u64s(m0, v0, out);
+		n + simd.compress_store_u64s(m1, v1, &mut out[n..])
+	}
+
+	#[inline(always)]
+	fn expand_load_u32s(self, mask: Self::m32s, values: &[u32]) -> Self::u32s {
+		let simd = V3_256b(*self);
+		let [m0, m1]: [_; 2] = cast!(mask);
+		let n = self.avx._mm256_movemask_ps(cast!(m0)).count_ones() as usize;
+		cast!([
+			simd.expand_load_u32s(m0, values),
+			simd.expand_load_u32s(m1, &values[n..]),
+		])
+	}
+
+	#[inline(always)]
+	fn expand_load_u64s(self, mask: Self::m64s, values: &[u64]) -> Self::u64s {
+		let simd = V3_256b(*self);
+		let [m0, m1]: [_; 2] = cast!(mask);
+		let n = self.avx._mm256_movemask_pd(cast!(m0)).count_ones() as usize;
+		cast!([
+			simd.expand_load_u64s(m0, values),
+			simd.expand_load_u64s(m1, &values[n..]),
+		])
+	}
+
 	#[inline(always)]
 	fn rotate_right_c32s(self, a: Self::c32s, amount: usize) -> Self::c32s {
 		let simd = V3_256b(*self);
@@ -2489,6 +2847,23 @@ impl Simd for V3_512b {
 		])
 	}
 
+	#[inline(always)]
+	fn table_lookup_u8s(self, table: Self::u8s, idx: Self::u8s) -> Self::u8s {
+		let simd = V3_256b(*self);
+		let [t0, t1]: [_; 2] = cast!(table);
+		let [i0, i1]: [_; 2] = cast!(idx);
+		let half = simd.splat_u8s(V3_256b::U8_LANES as u8);
+
+		// each half of the table is looked up separately, and out of range lanes are zero
+		let lookup = |i| {
+			simd.or_u8s(
+				simd.table_lookup_u8s(t0, i),
+				simd.table_lookup_u8s(t1, simd.sub_u8s(i, half)),
+			)
+		};
+		cast!([lookup(i0), lookup(i1)])
+	}
+
 	/// # Safety
 	///
 	/// See the trait-level safety documentation.
@@ -2498,7 +2873,7 @@ impl Simd for V3_512b {
 		let mask: [_; 2] = cast!(mask.mask());
 		cast!([
 			simd.mask_load_ptr_c32s(MemMask::new(mask[0]), ptr.wrapping_add(0)),
-			simd.mask_load_ptr_c32s(MemMask::new(mask[1]), ptr.wrapping_add(Self::C32_LANES)),
+			simd.mask_load_ptr_c32s(MemMask::new(mask[1]), ptr.wrapping_add(V3_256b::C32_LANES)),
 		])
 	}
 
@@ -2511,7 +2886,7 @@ impl Simd for V3_512b {
 		let mask: [_; 2] = cast!(mask.mask());
 		cast!([
 			simd.mask_load_ptr_c64s(MemMask::new(mask[0]), ptr.wrapping_add(0)),
-			simd.mask_load_ptr_c64s(MemMask::new(mask[1]), ptr.wrapping_add(Self::C64_LANES)),
+			simd.mask_load_ptr_c64s(MemMask::new(mask[1]), ptr.wrapping_add(V3_256b::C64_LANES)),
 		])
 	}
 
@@ -2541,6 +2916,70 @@ impl Simd for V3_512b {
 		])
 	}
 
+	/// # Safety
+	///
+	/// See the trait-level safety documentation.
+	#[inline(always)]
+	unsafe fn gather_ptr_u32s(self, ptr: *const u32, idx: Self::u32s) -> Self::u32s {
+		let simd = V3_256b(*self);
+		let idx: [_; 2] = cast!(idx);
+		cast!([
+			simd.gather_ptr_u32s(ptr, idx[0]),
+			simd.gather_ptr_u32s(ptr, idx[1])
+		])
+	}
+
+	/// # Safety
+	///
+	/// See the trait-level safety documentation.
+	#[inline(always)]
+	unsafe fn gather_ptr_u64s(self, ptr: *const u64, idx: Self::u64s) -> Self::u64s {
+		let simd = V3_256b(*self);
+		let idx: [_; 2] = cast!(idx);
+		cast!([
+			simd.gather_ptr_u64s(ptr, idx[0]),
+			simd.gather_ptr_u64s(ptr, idx[1])
+		])
+	}
+
+	/// # Safety
+	///
+	/// See the trait-level safety documentation.
+	#[inline(always)]
+	unsafe fn mask_gather_ptr_u32s(
+		self,
+		mask: MemMask<Self::m32s>,
+		ptr: *const u32,
+		idx: Self::u32s,
+	) -> Self::u32s {
+		let simd = V3_256b(*self);
+		let mask: [_; 2] = cast!(mask.mask());
+		let idx: [_; 2] = cast!(idx);
+		cast!([
+			simd.mask_gather_ptr_u32s(MemMask::new(mask[0]), ptr, idx[0]),
+			simd.mask_gather_ptr_u32s(MemMask::new(mask[1]), ptr, idx[1]),
+		])
+	}
+
+	/// # Safety
+	///
+	/// See the trait-level safety documentation.
+	#[inline(always)]
+	unsafe fn mask_gather_ptr_u64s(
+		self,
+		mask: MemMask<Self::m64s>,
+		ptr: *const u64,
+		idx: Self::u64s,
+	) -> Self::u64s {
+		let simd = V3_256b(*self);
+		let mask: [_; 2] = cast!(mask.mask());
+		let idx: [_; 2] = cast!(idx);
+		cast!([
+			simd.mask_gather_ptr_u64s(MemMask::new(mask[0]), ptr, idx[0]),
+			simd.mask_gather_ptr_u64s(MemMask::new(mask[1]), ptr, idx[1]),
+		])
+	}
+
 	/// # Safety
 	///
 	/// See the trait-level safety documentation.
@@ -2550,7 +2989,7 @@ impl Simd for V3_512b {
 		let mask: [_; 2] = cast!(mask.mask());
 		cast!([
 			simd.mask_load_ptr_u32s(MemMask::new(mask[0]), ptr.wrapping_add(0)),
-			simd.mask_load_ptr_u32s(MemMask::new(mask[1]), ptr.wrapping_add(Self::U32_LANES)),
+			simd.mask_load_ptr_u32s(MemMask::new(mask[1]), ptr.wrapping_add(V3_256b::U32_LANES)),
 		])
 	}
 
@@ -2563,7 +3002,7 @@ impl Simd for V3_512b {
 		let mask: [_; 2] = cast!(mask.mask());
 		cast!([
 			simd.mask_load_ptr_u64s(MemMask::new(mask[0]), ptr.wrapping_add(0)),
-			simd.mask_load_ptr_u64s(MemMask::new(mask[1]), ptr.wrapping_add(Self::U64_LANES)),
+			simd.mask_load_ptr_u64s(MemMask::new(mask[1]), ptr.wrapping_add(V3_256b::U64_LANES)),
 		])
 	}
 
@@ -2584,7 +3023,7 @@ impl Simd for V3_512b {
 			simd.mask_store_ptr_c32s(MemMask::new(mask[0]), ptr.wrapping_add(0), values[0]),
 			simd.mask_store_ptr_c32s(
 				MemMask::new(mask[1]),
-				ptr.wrapping_add(Self::C32_LANES),
+				ptr.wrapping_add(V3_256b::C32_LANES),
 				values[1]
 			),
 		])
@@ -2607,7 +3046,7 @@ impl Simd for V3_512b {
 			simd.mask_store_ptr_c64s(MemMask::new(mask[0]), ptr.wrapping_add(0), values[0]),
 			simd.mask_store_ptr_c64s(
 				MemMask::new(mask[1]),
-				ptr.wrapping_add(Self::C64_LANES),
+				ptr.wrapping_add(V3_256b::C64_LANES),
 				values[1]
 			),
 		])
@@ -2671,7 +3110,7 @@ impl Simd for V3_512b {
 			simd.mask_store_ptr_u32s(MemMask::new(mask[0]), ptr.wrapping_add(0), values[0]),
 			simd.mask_store_ptr_u32s(
 				MemMask::new(mask[1]),
-				ptr.wrapping_add(Self::U32_LANES),
+				ptr.wrapping_add(V3_256b::U32_LANES),
 				values[1]
 			),
 		])
@@ -2694,7 +3133,7 @@ impl Simd for V3_512b {
 			simd.mask_store_ptr_u64s(MemMask::new(mask[0]), ptr.wrapping_add(0), values[0]),
 			simd.mask_store_ptr_u64s(
 				MemMask::new(mask[1]),
-				ptr.wrapping_add(Self::U64_LANES),
+				ptr.wrapping_add(V3_256b::U64_LANES),
 				values[1]
 			),
 		])
@@ -2800,7 +3239,19 @@ impl Simd for V3_512b {
 
 	#[inline(always)]
 	fn vectorize<Op: WithSimd>(self, op: Op) -> Op::Output {
-		Simd::vectorize(self.0, op)
+		struct Impl<Op> {
+			this: V3_512b,
+			op: Op,
+		}
+		impl<Op: WithSimd> crate::NullaryFnOnce for Impl<Op> {
+			type Output = Op::Output;
+
+			#[inline(always)]
+			fn call(self) -> Self::Output {
+				self.op.with_simd(self.this)
+			}
+		}
+		self.0.vectorize(Impl { this: self, op })
 	}
 
 	#[inline(always)]
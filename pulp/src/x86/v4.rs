@@ -23,6 +23,7 @@ simd_type!({
 		pub bmi2: f!("bmi2"),
 		pub fma: f!("fma"),
 		pub lzcnt: f!("lzcnt"),
+		pub f16c: f!("f16c"),
 		pub avx512f: f!("avx512f"),
 		pub avx512bw: f!("avx512bw"),
 		pub avx512cd: f!("avx512cd"),
@@ -51,6 +52,7 @@ impl core::ops::Deref for V4 {
 			bmi2: self.bmi2,
 			fma: self.fma,
 			lzcnt: self.lzcnt,
+			f16c: self.f16c,
 		}
 		.to_ref()
 	}
@@ -144,6 +146,29 @@ static V4_U64_LAST_MASKS: [u8; 9] = [
 
 impl Seal for V4 {}
 
+/// AVX512 instruction set restricted to 256-bit vectors.
+///
+/// Has the same vector types as [`V3_256b`], but masks are composed of bits like in [`V4`], and
+/// it can make use of the 32 vector registers and the encodings that are available with
+/// AVX512VL, while avoiding the frequency penalty that some processors apply to 512-bit
+/// instructions.
+#[derive(Copy, Clone, Debug)]
+#[repr(transparent)]
+pub struct V4_256b(pub V4);
+
+impl core::ops::Deref for V4_256b {
+	type Target = V3_256b;
+
+	#[inline]
+	fn deref(&self) -> &Self::Target {
+		let v3: &V3 = &self.0;
+		// SAFETY: `V3_256b` is a `repr(transparent)` wrapper around `V3`
+		unsafe { &*(v3 as *const V3 as *const V3_256b) }
+	}
+}
+
+impl Seal for V4_256b {}
+
 macro_rules! x86_call_512 {
 	($ext: expr, $func: ident, f32, $($arg: expr),*) => {
 		paste!($ext.[<_mm512_ $func _ ps>]($($arg),*))
@@ -385,6 +410,15 @@ macro_rules! impl_simd_binop_mask {
 	($func: ident, $($ty: ident x $factor: literal),*) => {
 		$(impl_simd_binop_mask!($func, $func, $ty, $factor);)*
 	};
+	// the 256-bit comparisons of `V4`, for `V4_256b`
+	(256, $func: ident, $op: ident, $($ty: ident x $factor: literal => $mask: ident),*) => {
+		paste! {$(
+			#[inline(always)]
+			fn [<$func _ $ty s>](self, a: Self::[<$ty s>], b: Self::[<$ty s>]) -> $mask {
+				self.0.[<$op _ $ty x $factor>](a, b)
+			}
+		)*}
+	};
 }
 
 macro_rules! impl_simd_unop {
@@ -396,14 +430,19 @@ macro_rules! impl_simd_unop {
 			}
 		}
 	};
+	($func: ident, $op: ident, $($ty: ident x $factor: literal),*) => {
+		$(impl_simd_unop!($func, $op, $ty, $ty, $factor);)*
+	};
 	($func: ident, $($ty: ident x $factor: literal),*) => {
 		$(impl_simd_unop!($func, $func, $ty, $ty, $factor);)*
 	};
 }
 
 impl Simd for V4 {
+	type bf16s = u16x32;
 	type c32s = f32x16;
 	type c64s = f64x8;
+	type f16s = u16x32;
 	type f32s = f32x16;
 	type f64s = f64x8;
 	type i16s = i16x32;
@@ -455,10 +494,243 @@ impl Simd for V4 {
 
 	impl_simd_binop!(max, u8 x 64, i8 x 64, u16 x 32, i16 x 32, u32 x 16, i32 x 16, u64 x 8, i64 x 8, f32 x 16, f64 x 8);
 
+	impl_simd_binop!(saturating_add, u8 x 64, i8 x 64, u16 x 32, i16 x 32);
+
+	impl_simd_binop!(saturating_sub, u8 x 64, i8 x 64, u16 x 32, i16 x 32);
+
+	impl_simd_binop!(average, u8 x 64, u16 x 32);
+
 	impl_simd_binop!(min, u8 x 64, i8 x 64, u16 x 32, i16 x 32, u32 x 16, i32 x 16, u64 x 8, i64 x 8, f32 x 16, f64 x 8);
 
 	impl_simd_unop!(not, u8 x 64, u16 x 32, u32 x 16, u64 x 8);
 
+	impl_simd_unop!(floor, f32 x 16, f64 x 8);
+
+	impl_simd_unop!(ceil, f32 x 16, f64 x 8);
+
+	impl_simd_unop!(trunc, truncate, f32 x 16, f64 x 8);
+
+	impl_simd_unop!(round_ties_even, round, f32 x 16, f64 x 8);
+
+	impl_uniform_shift!(shl, shl, u16 x 32, u32 x 16, u64 x 8);
+
+	impl_uniform_shift!(shr, shr, u16 x 32, u32 x 16, u64 x 8);
+
+	impl_uniform_shift!(sra, shr, i16 x 32, i32 x 16, i64 x 8);
+
+	impl_dyn_shift!(shl, shl, u16 x 32 => u16, u64 x 8 => u64);
+
+	impl_dyn_shift!(shr, shr, u16 x 32 => u16, u64 x 8 => u64);
+
+	impl_dyn_shift!(sra, shr, i16 x 32 => u16, i32 x 16 => u32, i64 x 8 => u64);
+
+	impl_derived_shift!(u8);
+
+	impl_widen_narrow!(512);
+	impl_f16_convert!(512);
+
+	impl_mask_query!(512);
+
+	impl_mul_sub!(f32x16, f64x8);
+
+	impl_mul_high!(
+		avx512f,
+		avx512bw,
+		_mm512_mul_epu32,
+		_mm512_mulhi_epu16,
+		_mm512_mulhi_epi16
+	);
+	impl_fixed_point!(avx512bw, _mm512_mulhrs_epi16, avx512f, _mm512_mul_epi32);
+	impl_recip_approx!(avx512f, _mm512_rcp14_ps, _mm512_rsqrt14_ps);
+
+	// compressing directly to memory is slow on some cpus, so the lanes are compressed in registers
+	// and then stored with a masked store
+	//
+	// avx512vbmi2 is not part of any of the x86 levels, so the 8-bit and 16-bit versions only use
+	// it when it's enabled at compile time, rather than checking for it on every call
+	#[inline(always)]
+	fn compress_store_u8s(self, mask: Self::m8s, values: Self::u8s, out: &mut [u8]) -> usize {
+		#[cfg(target_feature = "avx512vbmi2")]
+		{
+			// SAFETY: the feature is enabled at compile time
+			let vbmi2 = unsafe { Avx512vbmi2::new_unchecked() };
+			let count = mask.0.count_ones() as usize;
+			let values = vbmi2._mm512_maskz_compress_epi8(mask.0, cast!(values));
+			self.partial_store_u8s(&mut out[..count], cast!(values));
+			count
+		}
+		#[cfg(not(target_feature = "avx512vbmi2"))]
+		{
+			compress_store_fallback(mask, values, out)
+		}
+	}
+
+	#[inline(always)]
+	fn compress_store_u16s(self, mask: Self::m16s, values: Self::u16s, out: &mut [u16]) -> usize {
+		#[cfg(target_feature = "avx512vbmi2")]
+		{
+			// SAFETY: the feature is enabled at compile time
+			let vbmi2 = unsafe { Avx512vbmi2::new_unchecked() };
+			let count = mask.0.count_ones() as usize;
+			let values = vbmi2._mm512_maskz_compress_epi16(mask.0, cast!(values));
+			self.partial_store_u16s(&mut out[..count], cast!(values));
+			count
+		}
+		#[cfg(not(target_feature = "avx512vbmi2"))]
+		{
+			compress_store_fallback(mask, values, out)
+		}
+	}
+
+	#[inline(always)]
+	fn compress_store_u32s(self, mask: Self::m32s, values: Self::u32s, out: &mut [u32]) -> usize {
+		let count = mask.0.count_ones() as usize;
+		let values = self
+			.avx512f
+			._mm512_maskz_compress_epi32(mask.0, cast!(values));
+		self.partial_store_u32s(&mut out[..count], cast!(values));
+		count
+	}
+
+	#[inline(always)]
+	fn compress_store_u64s(self, mask: Self::m64s, values: Self::u64s, out: &mut [u64]) -> usize {
+		let count = mask.0.count_ones() as usize;
+		let values = self
+			.avx512f
+			._mm512_maskz_compress_epi64(mask.0, cast!(values));
+		self.partial_store_u64s(&mut out[..count], cast!(values));
+		count
+	}
+
+	#[inline(always)]
+	fn expand_load_u8s(self, mask: Self::m8s, values: &[u8]) -> Self::u8s {
+		#[cfg(target_feature = "avx512vbmi2")]
+		{
+			// SAFETY: the feature is enabled at compile time
+			let vbmi2 = unsafe { Avx512vbmi2::new_unchecked() };
+			let count = mask.0.count_ones() as usize;
+			let values = self.partial_load_u8s(&values[..count]);
+			cast!(vbmi2._mm512_maskz_expand_epi8(mask.0, cast!(values)))
+		}
+		#[cfg(not(target_feature = "avx512vbmi2"))]
+		{
+			expand_load_fallback(mask, values)
+		}
+	}
+
+	#[inline(always)]
+	fn expand_load_u16s(self, mask: Self::m16s, values: &[u16]) -> Self::u16s {
+		#[cfg(target_feature = "avx512vbmi2")]
+		{
+			// SAFETY: the feature is enabled at compile time
+			let vbmi2 = unsafe { Avx512vbmi2::new_unchecked() };
+			let count = mask.0.count_ones() as usize;
+			let values = self.partial_load_u16s(&values[..count]);
+			cast!(vbmi2._mm512_maskz_expand_epi16(mask.0, cast!(values)))
+		}
+		#[cfg(not(target_feature = "avx512vbmi2"))]
+		{
+			expand_load_fallback(mask, values)
+		}
+	}
+
+	#[inline(always)]
+	fn expand_load_u32s(self, mask: Self::m32s, values: &[u32]) -> Self::u32s {
+		let count = mask.0.count_ones() as usize;
+		let values = self.partial_load_u32s(&values[..count]);
+		cast!(
+			self.avx512f
+				._mm512_maskz_expand_epi32(mask.0, cast!(values))
+		)
+	}
+
+	#[inline(always)]
+	fn expand_load_u64s(self, mask: Self::m64s, values: &[u64]) -> Self::u64s {
+		let count = mask.0.count_ones() as usize;
+		let values = self.partial_load_u64s(&values[..count]);
+		cast!(
+			self.avx512f
+				._mm512_maskz_expand_epi64(mask.0, cast!(values))
+		)
+	}
+	impl_gather_scatter!(512);
+	impl_int_reduce!(split self => *self);
+	impl_bit_count!(@common avx512bw, _mm512_shuffle_epi8, 4);
+
+	#[inline(always)]
+	fn leading_zeros_u32s(self, a: Self::u32s) -> Self::u32s {
+		cast!(self.avx512cd._mm512_lzcnt_epi32(cast!(a)))
+	}
+
+	#[inline(always)]
+	fn leading_zeros_u64s(self, a: Self::u64s) -> Self::u64s {
+		cast!(self.avx512cd._mm512_lzcnt_epi64(cast!(a)))
+	}
+
+	#[inline(always)]
+	fn convert_f32s_i32s(self, a: Self::f32s) -> Self::i32s {
+		let r = self.convert_f32x16_to_i32x16(a);
+		let r = self.select_i32s(
+			self.greater_than_or_equal_f32s(a, self.splat_f32s(2147483648.0)),
+			self.splat_i32s(i32::MAX),
+			r,
+		);
+		self.select_i32s(self.equal_f32s(a, a), r, self.splat_i32s(0))
+	}
+
+	#[inline(always)]
+	fn convert_f32s_u32s(self, a: Self::f32s) -> Self::u32s {
+		// out of range inputs return `u32::MAX`, which is already correct for large positive values
+		let r = self.convert_f32x16_to_u32x16(a);
+		self.select_u32s(
+			self.greater_than_f32s(a, self.splat_f32s(-1.0)),
+			r,
+			self.splat_u32s(0),
+		)
+	}
+
+	#[inline(always)]
+	fn convert_f64s_i64s(self, a: Self::f64s) -> Self::i64s {
+		let r = self.convert_f64x8_to_i64x8(a);
+		let r = self.select_i64s(
+			self.greater_than_or_equal_f64s(a, self.splat_f64s(9223372036854775808.0)),
+			self.splat_i64s(i64::MAX),
+			r,
+		);
+		self.select_i64s(self.equal_f64s(a, a), r, self.splat_i64s(0))
+	}
+
+	#[inline(always)]
+	fn convert_f64s_u64s(self, a: Self::f64s) -> Self::u64s {
+		// out of range inputs return `u64::MAX`, which is already correct for large positive values
+		let r = self.convert_f64x8_to_u64x8(a);
+		self.select_u64s(
+			self.greater_than_f64s(a, self.splat_f64s(-1.0)),
+			r,
+			self.splat_u64s(0),
+		)
+	}
+
+	#[inline(always)]
+	fn convert_i32s_f32s(self, a: Self::i32s) -> Self::f32s {
+		self.convert_i32x16_to_f32x16(a)
+	}
+
+	#[inline(always)]
+	fn convert_u32s_f32s(self, a: Self::u32s) -> Self::f32s {
+		self.convert_u32x16_to_f32x16(a)
+	}
+
+	#[inline(always)]
+	fn convert_i64s_f64s(self, a: Self::i64s) -> Self::f64s {
+		self.convert_i64x8_to_f64x8(a)
+	}
+
+	#[inline(always)]
+	fn convert_u64s_f64s(self, a: Self::u64s) -> Self::f64s {
+		self.convert_u64x8_to_f64x8(a)
+	}
+
 	#[inline(always)]
 	fn abs2_c32s(self, a: Self::c32s) -> Self::c32s {
 		let sqr = self.mul_f32s(a, a);
@@ -515,6 +787,16 @@ impl Simd for V4 {
 		self.equal_f64s(a, b)
 	}
 
+	#[inline(always)]
+	fn and_m8s(self, a: Self::m8s, b: Self::m8s) -> Self::m8s {
+		b64(a.0 & b.0)
+	}
+
+	#[inline(always)]
+	fn and_m16s(self, a: Self::m16s, b: Self::m16s) -> Self::m16s {
+		b32(a.0 & b.0)
+	}
+
 	#[inline(always)]
 	fn and_m32s(self, a: Self::m32s, b: Self::m32s) -> Self::m32s {
 		b16(a.0 & b.0)
@@ -1086,6 +1368,16 @@ impl Simd for V4 {
 		b8(!a.0)
 	}
 
+	#[inline(always)]
+	fn or_m8s(self, a: Self::m8s, b: Self::m8s) -> Self::m8s {
+		b64(a.0 | b.0)
+	}
+
+	#[inline(always)]
+	fn or_m16s(self, a: Self::m16s, b: Self::m16s) -> Self::m16s {
+		b32(a.0 | b.0)
+	}
+
 	#[inline(always)]
 	fn or_m32s(self, a: Self::m32s, b: Self::m32s) -> Self::m32s {
 		b16(a.0 | b.0)
@@ -1241,165 +1533,855 @@ impl Simd for V4 {
 	}
 
 	#[inline(always)]
-	fn reduce_sum_f32s(self, a: Self::f32s) -> f32 {
-		let a: __m512 = cast!(a);
-		let r = self.avx._mm256_add_ps(
-			self.avx512f._mm512_castps512_ps256(a),
-			cast!(self.avx512f._mm512_extractf64x4_pd::<1>(cast!(a))),
-		);
-		(*self).reduce_sum_f32s(cast!(r))
+	fn reduce_sum_f32s(self, a: Self::f32s) -> f32 {
+		let a: __m512 = cast!(a);
+		let r = self.avx._mm256_add_ps(
+			self.avx512f._mm512_castps512_ps256(a),
+			cast!(self.avx512f._mm512_extractf64x4_pd::<1>(cast!(a))),
+		);
+		(*self).reduce_sum_f32s(cast!(r))
+	}
+
+	#[inline(always)]
+	fn reduce_sum_f64s(self, a: Self::f64s) -> f64 {
+		let a: __m512d = cast!(a);
+		let r = self.avx._mm256_add_pd(
+			self.avx512f._mm512_castpd512_pd256(a),
+			self.avx512f._mm512_extractf64x4_pd::<1>(a),
+		);
+		(*self).reduce_sum_f64s(cast!(r))
+	}
+
+	#[inline(always)]
+	fn rotate_right_c32s(self, a: Self::c32s, amount: usize) -> Self::c32s {
+		cast!(
+			self.avx512f
+				._mm512_permutexvar_epi32(cast!(AVX512_ROTATE_IDX[2 * (amount % 8)]), cast!(a),)
+		)
+	}
+
+	#[inline(always)]
+	fn rotate_right_c64s(self, a: Self::c64s, amount: usize) -> Self::c64s {
+		cast!(
+			self.avx512f
+				._mm512_permutexvar_epi32(cast!(AVX512_ROTATE_IDX[4 * (amount % 4)]), cast!(a),)
+		)
+	}
+
+	#[inline(always)]
+	fn rotate_right_u32s(self, a: Self::u32s, amount: usize) -> Self::u32s {
+		cast!(
+			self.avx512f
+				._mm512_permutexvar_epi32(cast!(AVX512_ROTATE_IDX[amount % 16]), cast!(a),)
+		)
+	}
+
+	#[inline(always)]
+	fn rotate_right_u64s(self, a: Self::u64s, amount: usize) -> Self::u64s {
+		cast!(
+			self.avx512f
+				._mm512_permutexvar_epi32(cast!(AVX512_ROTATE_IDX[2 * (amount % 8)]), cast!(a),)
+		)
+	}
+
+	#[inline(always)]
+	fn table_lookup_u8s(self, table: Self::u8s, idx: Self::u8s) -> Self::u8s {
+		let table: __m512i = cast!(table);
+		let idx: __m512i = cast!(idx);
+		let in_range = self
+			.avx512bw
+			._mm512_cmplt_epu8_mask(idx, self.avx512f._mm512_set1_epi8(64));
+
+		// avx512vbmi is not part of any of the x86 levels, so it's only used when it's enabled at
+		// compile time, rather than checking for it on every call
+		#[cfg(target_feature = "avx512vbmi")]
+		{
+			// SAFETY: the feature is enabled at compile time
+			let vbmi = unsafe { Avx512vbmi::new_unchecked() };
+			cast!(vbmi._mm512_maskz_permutexvar_epi8(in_range, idx, table))
+		}
+		#[cfg(not(target_feature = "avx512vbmi"))]
+		{
+			// broadcast each 128-bit block of the table, and look it up with `pshufb` in the lanes
+			// whose index falls in that block
+			let block = self
+				.avx512f
+				._mm512_and_si512(idx, self.avx512f._mm512_set1_epi8(0x30));
+			let lookup = |out: __m512i, b: i8, table: __m512i| {
+				let mask = self
+					.avx512bw
+					._mm512_cmpeq_epi8_mask(block, self.avx512f._mm512_set1_epi8(b << 4));
+				self.avx512bw
+					._mm512_mask_shuffle_epi8(out, in_range & mask, table, idx)
+			};
+
+			let out = self.avx512f._mm512_setzero_si512();
+			let out = lookup(
+				out,
+				0,
+				self.avx512f._mm512_shuffle_i32x4::<0x00>(table, table),
+			);
+			let out = lookup(
+				out,
+				1,
+				self.avx512f._mm512_shuffle_i32x4::<0x55>(table, table),
+			);
+			let out = lookup(
+				out,
+				2,
+				self.avx512f._mm512_shuffle_i32x4::<0xaa>(table, table),
+			);
+			let out = lookup(
+				out,
+				3,
+				self.avx512f._mm512_shuffle_i32x4::<0xff>(table, table),
+			);
+			cast!(out)
+		}
+	}
+
+	#[inline(always)]
+	fn select_u32s(
+		self,
+		mask: Self::m32s,
+		if_true: Self::u32s,
+		if_false: Self::u32s,
+	) -> Self::u32s {
+		let mask: __mmask16 = mask.0;
+		let if_true: __m512 = cast!(if_true);
+		let if_false: __m512 = cast!(if_false);
+
+		cast!(self.avx512f._mm512_mask_blend_ps(mask, if_false, if_true))
+	}
+
+	#[inline(always)]
+	fn select_u64s(
+		self,
+		mask: Self::m64s,
+		if_true: Self::u64s,
+		if_false: Self::u64s,
+	) -> Self::u64s {
+		let mask: __mmask8 = mask.0;
+		let if_true: __m512d = cast!(if_true);
+		let if_false: __m512d = cast!(if_false);
+
+		cast!(self.avx512f._mm512_mask_blend_pd(mask, if_false, if_true))
+	}
+
+	#[inline(always)]
+	fn select_u8s(self, mask: Self::m8s, if_true: Self::u8s, if_false: Self::u8s) -> Self::u8s {
+		cast!(
+			self.avx512bw
+				._mm512_mask_blend_epi8(mask.0, cast!(if_false), cast!(if_true))
+		)
+	}
+
+	#[inline(always)]
+	fn select_u16s(
+		self,
+		mask: Self::m16s,
+		if_true: Self::u16s,
+		if_false: Self::u16s,
+	) -> Self::u16s {
+		cast!(
+			self.avx512bw
+				._mm512_mask_blend_epi16(mask.0, cast!(if_false), cast!(if_true))
+		)
+	}
+
+	#[inline(always)]
+	fn splat_c32s(self, value: c32) -> Self::c32s {
+		cast!(self.splat_f64s(cast!(value)))
+	}
+
+	#[inline(always)]
+	fn splat_c64s(self, value: c64) -> Self::c64s {
+		cast!(self.avx512f._mm512_broadcast_f32x4(cast!(value)))
+	}
+
+	#[inline(always)]
+	fn sub_c32s(self, a: Self::c32s, b: Self::c32s) -> Self::c32s {
+		self.sub_f32s(a, b)
+	}
+
+	#[inline(always)]
+	fn sub_c64s(self, a: Self::c64s, b: Self::c64s) -> Self::c64s {
+		self.sub_f64s(a, b)
+	}
+
+	#[inline(always)]
+	fn swap_re_im_c32s(self, a: Self::c32s) -> Self::c32s {
+		cast!(self.avx512f._mm512_permute_ps::<0b10_11_00_01>(cast!(a)))
+	}
+
+	#[inline(always)]
+	fn swap_re_im_c64s(self, a: Self::c64s) -> Self::c64s {
+		cast!(self.avx512f._mm512_permute_pd::<0b01010101>(cast!(a)))
+	}
+
+	#[inline(always)]
+	fn vectorize<Op: WithSimd>(self, op: Op) -> Op::Output {
+		struct Impl<Op> {
+			this: V4,
+			op: Op,
+		}
+		impl<Op: WithSimd> crate::NullaryFnOnce for Impl<Op> {
+			type Output = Op::Output;
+
+			#[inline(always)]
+			fn call(self) -> Self::Output {
+				self.op.with_simd(self.this)
+			}
+		}
+		self.vectorize(Impl { this: self, op })
+	}
+
+	#[inline(always)]
+	fn widening_mul_u32s(self, a: Self::u32s, b: Self::u32s) -> (Self::u32s, Self::u32s) {
+		self.widening_mul_u32x16(a, b)
+	}
+
+	#[inline(always)]
+	fn wrapping_dyn_shl_u32s(self, a: Self::u32s, amount: Self::u32s) -> Self::u32s {
+		self.shl_dyn_u32x16(a, self.and_u32x16(amount, self.splat_u32x16(32 - 1)))
+	}
+
+	#[inline(always)]
+	fn wrapping_dyn_shr_u32s(self, a: Self::u32s, amount: Self::u32s) -> Self::u32s {
+		self.shr_dyn_u32x16(a, self.and_u32x16(amount, self.splat_u32x16(32 - 1)))
+	}
+
+	#[inline(always)]
+	fn xor_m8s(self, a: Self::m8s, b: Self::m8s) -> Self::m8s {
+		b64(a.0 ^ b.0)
+	}
+
+	#[inline(always)]
+	fn xor_m16s(self, a: Self::m16s, b: Self::m16s) -> Self::m16s {
+		b32(a.0 ^ b.0)
+	}
+
+	#[inline(always)]
+	fn xor_m32s(self, a: Self::m32s, b: Self::m32s) -> Self::m32s {
+		b16(a.0 ^ b.0)
+	}
+
+	#[inline(always)]
+	fn xor_m64s(self, a: Self::m64s, b: Self::m64s) -> Self::m64s {
+		b8(a.0 ^ b.0)
+	}
+
+	#[inline(always)]
+	fn sqrt_f32s(self, a: Self::f32s) -> Self::f32s {
+		self.sqrt_f32x16(a)
+	}
+
+	#[inline(always)]
+	fn sqrt_f64s(self, a: Self::f64s) -> Self::f64s {
+		self.sqrt_f64x8(a)
+	}
+}
+
+/// Returns the bits `start..end` of a mask with `lanes` lanes.
+#[inline(always)]
+fn mask_bits_between(start: u64, end: u64, lanes: u64) -> u64 {
+	let start = start.min(lanes);
+	let end = end.min(lanes);
+	(u64::MAX << start) & !(u64::MAX << end)
+}
+
+impl Simd for V4_256b {
+	type bf16s = u16x16;
+	type c32s = f32x8;
+	type c64s = f64x4;
+	type f16s = u16x16;
+	type f32s = f32x8;
+	type f64s = f64x4;
+	type i16s = i16x16;
+	type i32s = i32x8;
+	type i64s = i64x4;
+	type i8s = i8x32;
+	type m16s = b16;
+	type m32s = b8;
+	type m64s = b8;
+	type m8s = b32;
+	type u16s = u16x16;
+	type u32s = u32x8;
+	type u64s = u64x4;
+	type u8s = u8x32;
+
+	const REGISTER_COUNT: usize = 32;
+
+	impl_simd_binop!(add, f32 x 8, f64 x 4);
+
+	impl_simd_binop!(add, wrapping_add, u8 x 32, i8 x 32, u16 x 16, i16 x 16, u32 x 8, i32 x 8, u64 x 4, i64 x 4);
+
+	impl_simd_binop!(sub, f32 x 8, f64 x 4);
+
+	impl_simd_binop!(sub, wrapping_sub, u8 x 32, i8 x 32, u16 x 16, i16 x 16, u32 x 8, i32 x 8, u64 x 4, i64 x 4);
+
+	impl_simd_binop!(mul, f32 x 8, f64 x 4);
+
+	impl_simd_binop!(mul, wrapping_mul, u16 x 16, i16 x 16, u32 x 8, i32 x 8);
+
+	impl_simd_binop!(and, u8 x 32, i8 x 32, u16 x 16, i16 x 16, u32 x 8, i32 x 8, u64 x 4, i64 x 4, f32 x 8, f64 x 4);
+
+	impl_simd_binop!(or, u8 x 32, i8 x 32, u16 x 16, i16 x 16, u32 x 8, i32 x 8, u64 x 4, i64 x 4, f32 x 8, f64 x 4);
+
+	impl_simd_binop!(xor, u8 x 32, i8 x 32, u16 x 16, i16 x 16, u32 x 8, i32 x 8, u64 x 4, i64 x 4, f32 x 8, f64 x 4);
+
+	impl_simd_binop!(div, f32 x 8, f64 x 4);
+
+	impl_simd_binop_mask!(256, equal, cmp_eq, u8 x 32 => b32, u16 x 16 => b16, u32 x 8 => b8, u64 x 4 => b8, f32 x 8 => b8, f64 x 4 => b8);
+
+	impl_simd_binop_mask!(256, greater_than, cmp_gt, u8 x 32 => b32, i8 x 32 => b32, u16 x 16 => b16, i16 x 16 => b16, u32 x 8 => b8, i32 x 8 => b8, u64 x 4 => b8, i64 x 4 => b8, f32 x 8 => b8, f64 x 4 => b8);
+
+	impl_simd_binop_mask!(256, greater_than_or_equal, cmp_ge, u8 x 32 => b32, i8 x 32 => b32, u16 x 16 => b16, i16 x 16 => b16, u32 x 8 => b8, i32 x 8 => b8, u64 x 4 => b8, i64 x 4 => b8, f32 x 8 => b8, f64 x 4 => b8);
+
+	impl_simd_binop_mask!(256, less_than, cmp_lt, u8 x 32 => b32, i8 x 32 => b32, u16 x 16 => b16, i16 x 16 => b16, u32 x 8 => b8, i32 x 8 => b8, u64 x 4 => b8, i64 x 4 => b8, f32 x 8 => b8, f64 x 4 => b8);
+
+	impl_simd_binop_mask!(256, less_than_or_equal, cmp_le, u8 x 32 => b32, i8 x 32 => b32, u16 x 16 => b16, i16 x 16 => b16, u32 x 8 => b8, i32 x 8 => b8, u64 x 4 => b8, i64 x 4 => b8, f32 x 8 => b8, f64 x 4 => b8);
+
+	splat!(u8 x 32, i8 x 32, u16 x 16, i16 x 16, u32 x 8, i32 x 8, u64 x 4, i64 x 4, f32 x 8, f64 x 4);
+
+	impl_simd_binop!(max, u8 x 32, i8 x 32, u16 x 16, i16 x 16, u32 x 8, i32 x 8, f32 x 8, f64 x 4);
+
+	impl_simd_binop!(saturating_add, u8 x 32, i8 x 32, u16 x 16, i16 x 16);
+
+	impl_simd_binop!(saturating_sub, u8 x 32, i8 x 32, u16 x 16, i16 x 16);
+
+	impl_simd_binop!(average, u8 x 32, u16 x 16);
+
+	impl_simd_binop!(min, u8 x 32, i8 x 32, u16 x 16, i16 x 16, u32 x 8, i32 x 8, f32 x 8, f64 x 4);
+
+	impl_simd_unop!(not, u8 x 32, u16 x 16, u32 x 8, u64 x 4);
+
+	impl_simd_unop!(floor, f32 x 8, f64 x 4);
+
+	impl_simd_unop!(ceil, f32 x 8, f64 x 4);
+
+	impl_simd_unop!(trunc, truncate, f32 x 8, f64 x 4);
+
+	impl_simd_unop!(round_ties_even, round, f32 x 8, f64 x 4);
+
+	inherit_256b!();
+
+	#[inline(always)]
+	fn and_m8s(self, a: Self::m8s, b: Self::m8s) -> Self::m8s {
+		b32(a.0 & b.0)
+	}
+
+	#[inline(always)]
+	fn and_m16s(self, a: Self::m16s, b: Self::m16s) -> Self::m16s {
+		b16(a.0 & b.0)
+	}
+
+	#[inline(always)]
+	fn and_m32s(self, a: Self::m32s, b: Self::m32s) -> Self::m32s {
+		b8(a.0 & b.0)
+	}
+
+	#[inline(always)]
+	fn and_m64s(self, a: Self::m64s, b: Self::m64s) -> Self::m64s {
+		b8(a.0 & b.0)
+	}
+
+	#[inline(always)]
+	fn or_m8s(self, a: Self::m8s, b: Self::m8s) -> Self::m8s {
+		b32(a.0 | b.0)
+	}
+
+	#[inline(always)]
+	fn or_m16s(self, a: Self::m16s, b: Self::m16s) -> Self::m16s {
+		b16(a.0 | b.0)
+	}
+
+	#[inline(always)]
+	fn or_m32s(self, a: Self::m32s, b: Self::m32s) -> Self::m32s {
+		b8(a.0 | b.0)
+	}
+
+	#[inline(always)]
+	fn or_m64s(self, a: Self::m64s, b: Self::m64s) -> Self::m64s {
+		b8(a.0 | b.0)
+	}
+
+	#[inline(always)]
+	fn xor_m8s(self, a: Self::m8s, b: Self::m8s) -> Self::m8s {
+		b32(a.0 ^ b.0)
+	}
+
+	#[inline(always)]
+	fn xor_m16s(self, a: Self::m16s, b: Self::m16s) -> Self::m16s {
+		b16(a.0 ^ b.0)
+	}
+
+	#[inline(always)]
+	fn xor_m32s(self, a: Self::m32s, b: Self::m32s) -> Self::m32s {
+		b8(a.0 ^ b.0)
+	}
+
+	#[inline(always)]
+	fn xor_m64s(self, a: Self::m64s, b: Self::m64s) -> Self::m64s {
+		b8(a.0 ^ b.0)
+	}
+
+	#[inline(always)]
+	fn not_m8s(self, a: Self::m8s) -> Self::m8s {
+		b32(!a.0)
+	}
+
+	#[inline(always)]
+	fn not_m16s(self, a: Self::m16s) -> Self::m16s {
+		b16(!a.0)
+	}
+
+	#[inline(always)]
+	fn not_m32s(self, a: Self::m32s) -> Self::m32s {
+		b8(!a.0)
+	}
+
+	// only the low 4 bits of the 64-bit lane masks are used, and they're kept clear in the others
+	#[inline(always)]
+	fn not_m64s(self, a: Self::m64s) -> Self::m64s {
+		b8(!a.0 & 0b1111)
+	}
+
+	#[inline(always)]
+	fn to_bitmask_m8s(self, mask: Self::m8s) -> u64 {
+		mask.0 as u64
+	}
+
+	#[inline(always)]
+	fn to_bitmask_m16s(self, mask: Self::m16s) -> u64 {
+		mask.0 as u64
+	}
+
+	#[inline(always)]
+	fn to_bitmask_m32s(self, mask: Self::m32s) -> u64 {
+		mask.0 as u64
+	}
+
+	#[inline(always)]
+	fn to_bitmask_m64s(self, mask: Self::m64s) -> u64 {
+		mask.0 as u64
+	}
+
+	#[inline(always)]
+	fn from_bitmask_m8s(self, bits: u64) -> Self::m8s {
+		b32(bits as u32)
+	}
+
+	#[inline(always)]
+	fn from_bitmask_m16s(self, bits: u64) -> Self::m16s {
+		b16(bits as u16)
+	}
+
+	#[inline(always)]
+	fn from_bitmask_m32s(self, bits: u64) -> Self::m32s {
+		b8(bits as u8)
+	}
+
+	#[inline(always)]
+	fn from_bitmask_m64s(self, bits: u64) -> Self::m64s {
+		b8(bits as u8 & 0b1111)
+	}
+
+	#[inline(always)]
+	fn first_true_m8s(self, mask: Self::m8s) -> usize {
+		mask.0.trailing_zeros() as usize
+	}
+
+	#[inline(always)]
+	fn first_true_m16s(self, mask: Self::m16s) -> usize {
+		mask.0.trailing_zeros() as usize
+	}
+
+	#[inline(always)]
+	fn first_true_m32s(self, mask: Self::m32s) -> usize {
+		mask.0.trailing_zeros() as usize
+	}
+
+	#[inline(always)]
+	fn first_true_m64s(self, mask: Self::m64s) -> usize {
+		(mask.0 | 0b10000).trailing_zeros() as usize
+	}
+
+	#[inline(always)]
+	fn mask_between_m8s(self, start: u8, end: u8) -> MemMask<Self::m8s> {
+		b32(mask_bits_between(start as u64, end as u64, 32) as u32).into()
+	}
+
+	#[inline(always)]
+	fn mask_between_m16s(self, start: u16, end: u16) -> MemMask<Self::m16s> {
+		b16(mask_bits_between(start as u64, end as u64, 16) as u16).into()
+	}
+
+	#[inline(always)]
+	fn mask_between_m32s(self, start: u32, end: u32) -> MemMask<Self::m32s> {
+		b8(mask_bits_between(start as u64, end as u64, 8) as u8).into()
+	}
+
+	#[inline(always)]
+	fn mask_between_m64s(self, start: u64, end: u64) -> MemMask<Self::m64s> {
+		b8(mask_bits_between(start, end, 4) as u8).into()
+	}
+
+	#[inline(always)]
+	fn equal_c32s(self, a: Self::c32s, b: Self::c32s) -> Self::m32s {
+		self.equal_f32s(a, b)
+	}
+
+	#[inline(always)]
+	fn equal_c64s(self, a: Self::c64s, b: Self::c64s) -> Self::m64s {
+		self.equal_f64s(a, b)
+	}
+
+	#[inline(always)]
+	fn select_u8s(self, mask: Self::m8s, if_true: Self::u8s, if_false: Self::u8s) -> Self::u8s {
+		self.0.select_u8x32(mask, if_true, if_false)
+	}
+
+	#[inline(always)]
+	fn select_u16s(
+		self,
+		mask: Self::m16s,
+		if_true: Self::u16s,
+		if_false: Self::u16s,
+	) -> Self::u16s {
+		self.0.select_u16x16(mask, if_true, if_false)
+	}
+
+	#[inline(always)]
+	fn select_u32s(
+		self,
+		mask: Self::m32s,
+		if_true: Self::u32s,
+		if_false: Self::u32s,
+	) -> Self::u32s {
+		self.0.select_u32x8(mask, if_true, if_false)
+	}
+
+	#[inline(always)]
+	fn select_u64s(
+		self,
+		mask: Self::m64s,
+		if_true: Self::u64s,
+		if_false: Self::u64s,
+	) -> Self::u64s {
+		self.0.select_u64x4(mask, if_true, if_false)
 	}
 
+	/// # Safety
+	///
+	/// See the trait-level safety documentation.
 	#[inline(always)]
-	fn reduce_sum_f64s(self, a: Self::f64s) -> f64 {
-		let a: __m512d = cast!(a);
-		let r = self.avx._mm256_add_pd(
-			self.avx512f._mm512_castpd512_pd256(a),
-			self.avx512f._mm512_extractf64x4_pd::<1>(a),
-		);
-		(*self).reduce_sum_f64s(cast!(r))
+	unsafe fn mask_load_ptr_c32s(self, mask: MemMask<Self::m32s>, ptr: *const c32) -> Self::c32s {
+		cast!(self.mask_load_ptr_u32s(mask, ptr as _))
 	}
 
+	/// # Safety
+	///
+	/// See the trait-level safety documentation.
 	#[inline(always)]
-	fn rotate_right_c32s(self, a: Self::c32s, amount: usize) -> Self::c32s {
+	unsafe fn mask_load_ptr_c64s(self, mask: MemMask<Self::m64s>, ptr: *const c64) -> Self::c64s {
+		cast!(self.mask_load_ptr_u64s(mask, ptr as _))
+	}
+
+	/// # Safety
+	///
+	/// See the trait-level safety documentation.
+	#[inline(always)]
+	unsafe fn mask_load_ptr_u8s(self, mask: MemMask<Self::m8s>, ptr: *const u8) -> Self::u8s {
 		cast!(
-			self.avx512f
-				._mm512_permutexvar_epi32(cast!(AVX512_ROTATE_IDX[2 * (amount % 8)]), cast!(a),)
+			self.0
+				.avx512bw
+				._mm256_maskz_loadu_epi8(mask.mask.0, ptr as _)
 		)
 	}
 
+	/// # Safety
+	///
+	/// See the trait-level safety documentation.
 	#[inline(always)]
-	fn rotate_right_c64s(self, a: Self::c64s, amount: usize) -> Self::c64s {
+	unsafe fn mask_load_ptr_u16s(self, mask: MemMask<Self::m16s>, ptr: *const u16) -> Self::u16s {
 		cast!(
-			self.avx512f
-				._mm512_permutexvar_epi32(cast!(AVX512_ROTATE_IDX[4 * (amount % 4)]), cast!(a),)
+			self.0
+				.avx512bw
+				._mm256_maskz_loadu_epi16(mask.mask.0, ptr as _)
 		)
 	}
 
+	/// # Safety
+	///
+	/// See the trait-level safety documentation.
 	#[inline(always)]
-	fn rotate_right_u32s(self, a: Self::u32s, amount: usize) -> Self::u32s {
+	unsafe fn mask_load_ptr_u32s(self, mask: MemMask<Self::m32s>, ptr: *const u32) -> Self::u32s {
 		cast!(
-			self.avx512f
-				._mm512_permutexvar_epi32(cast!(AVX512_ROTATE_IDX[amount % 16]), cast!(a),)
+			self.0
+				.avx512f
+				._mm256_maskz_loadu_epi32(mask.mask.0, ptr as _)
 		)
 	}
 
+	/// # Safety
+	///
+	/// See the trait-level safety documentation.
 	#[inline(always)]
-	fn rotate_right_u64s(self, a: Self::u64s, amount: usize) -> Self::u64s {
+	unsafe fn mask_load_ptr_u64s(self, mask: MemMask<Self::m64s>, ptr: *const u64) -> Self::u64s {
 		cast!(
-			self.avx512f
-				._mm512_permutexvar_epi32(cast!(AVX512_ROTATE_IDX[2 * (amount % 8)]), cast!(a),)
+			self.0
+				.avx512f
+				._mm256_maskz_loadu_epi64(mask.mask.0, ptr as _)
 		)
 	}
 
+	/// # Safety
+	///
+	/// See the trait-level safety documentation.
 	#[inline(always)]
-	fn select_u32s(
+	unsafe fn mask_store_ptr_c32s(
 		self,
-		mask: Self::m32s,
-		if_true: Self::u32s,
-		if_false: Self::u32s,
-	) -> Self::u32s {
-		let mask: __mmask16 = mask.0;
-		let if_true: __m512 = cast!(if_true);
-		let if_false: __m512 = cast!(if_false);
-
-		cast!(self.avx512f._mm512_mask_blend_ps(mask, if_false, if_true))
+		mask: MemMask<Self::m32s>,
+		ptr: *mut c32,
+		values: Self::c32s,
+	) {
+		self.mask_store_ptr_u32s(mask, ptr as _, cast!(values))
 	}
 
+	/// # Safety
+	///
+	/// See the trait-level safety documentation.
 	#[inline(always)]
-	fn select_u64s(
+	unsafe fn mask_store_ptr_c64s(
 		self,
-		mask: Self::m64s,
-		if_true: Self::u64s,
-		if_false: Self::u64s,
-	) -> Self::u64s {
-		let mask: __mmask8 = mask.0;
-		let if_true: __m512d = cast!(if_true);
-		let if_false: __m512d = cast!(if_false);
-
-		cast!(self.avx512f._mm512_mask_blend_pd(mask, if_false, if_true))
+		mask: MemMask<Self::m64s>,
+		ptr: *mut c64,
+		values: Self::c64s,
+	) {
+		self.mask_store_ptr_u64s(mask, ptr as _, cast!(values))
 	}
 
+	/// # Safety
+	///
+	/// See the trait-level safety documentation.
 	#[inline(always)]
-	fn splat_c32s(self, value: c32) -> Self::c32s {
-		cast!(self.splat_f64s(cast!(value)))
+	unsafe fn mask_store_ptr_u8s(self, mask: MemMask<Self::m8s>, ptr: *mut u8, values: Self::u8s) {
+		self.0
+			.avx512bw
+			._mm256_mask_storeu_epi8(ptr as _, mask.mask.0, cast!(values))
 	}
 
+	/// # Safety
+	///
+	/// See the trait-level safety documentation.
 	#[inline(always)]
-	fn splat_c64s(self, value: c64) -> Self::c64s {
-		cast!(self.avx512f._mm512_broadcast_f32x4(cast!(value)))
+	unsafe fn mask_store_ptr_u16s(
+		self,
+		mask: MemMask<Self::m16s>,
+		ptr: *mut u16,
+		values: Self::u16s,
+	) {
+		self.0
+			.avx512bw
+			._mm256_mask_storeu_epi16(ptr as _, mask.mask.0, cast!(values))
 	}
 
+	/// # Safety
+	///
+	/// See the trait-level safety documentation.
 	#[inline(always)]
-	fn sub_c32s(self, a: Self::c32s, b: Self::c32s) -> Self::c32s {
-		self.sub_f32s(a, b)
+	unsafe fn mask_store_ptr_u32s(
+		self,
+		mask: MemMask<Self::m32s>,
+		ptr: *mut u32,
+		values: Self::u32s,
+	) {
+		self.0
+			.avx512f
+			._mm256_mask_storeu_epi32(ptr as _, mask.mask.0, cast!(values))
 	}
 
+	/// # Safety
+	///
+	/// See the trait-level safety documentation.
 	#[inline(always)]
-	fn sub_c64s(self, a: Self::c64s, b: Self::c64s) -> Self::c64s {
-		self.sub_f64s(a, b)
+	unsafe fn mask_store_ptr_u64s(
+		self,
+		mask: MemMask<Self::m64s>,
+		ptr: *mut u64,
+		values: Self::u64s,
+	) {
+		self.0
+			.avx512f
+			._mm256_mask_storeu_epi64(ptr as _, mask.mask.0, cast!(values))
 	}
 
+	// avx512vl has no 256-bit gathers, so the mask is expanded for the avx2 ones
+	/// # Safety
+	///
+	/// See the trait-level safety documentation.
 	#[inline(always)]
-	fn swap_re_im_c32s(self, a: Self::c32s) -> Self::c32s {
-		cast!(self.avx512f._mm512_permute_ps::<0b10_11_00_01>(cast!(a)))
+	unsafe fn mask_gather_ptr_u32s(
+		self,
+		mask: MemMask<Self::m32s>,
+		ptr: *const u32,
+		idx: Self::u32s,
+	) -> Self::u32s {
+		let mask = cast!(self.0.convert_mask_b8_to_u32x8(mask.mask));
+		(*self).mask_gather_ptr_u32s(MemMask::new(mask), ptr, idx)
 	}
 
+	/// # Safety
+	///
+	/// See the trait-level safety documentation.
 	#[inline(always)]
-	fn swap_re_im_c64s(self, a: Self::c64s) -> Self::c64s {
-		cast!(self.avx512f._mm512_permute_pd::<0b01010101>(cast!(a)))
+	unsafe fn mask_gather_ptr_u64s(
+		self,
+		mask: MemMask<Self::m64s>,
+		ptr: *const u64,
+		idx: Self::u64s,
+	) -> Self::u64s {
+		let mask = cast!(self.0.convert_mask_b8_to_u64x4(mask.mask));
+		(*self).mask_gather_ptr_u64s(MemMask::new(mask), ptr, idx)
 	}
 
+	// see the comment on `V4::compress_store_u8s`
 	#[inline(always)]
-	fn vectorize<Op: WithSimd>(self, op: Op) -> Op::Output {
-		struct Impl<Op> {
-			this: V4,
-			op: Op,
+	fn compress_store_u8s(self, mask: Self::m8s, values: Self::u8s, out: &mut [u8]) -> usize {
+		#[cfg(target_feature = "avx512vbmi2")]
+		{
+			// SAFETY: the feature is enabled at compile time
+			let vbmi2 = unsafe { Avx512vbmi2::new_unchecked() };
+			let count = mask.0.count_ones() as usize;
+			let values = vbmi2._mm256_maskz_compress_epi8(mask.0, cast!(values));
+			self.partial_store_u8s(&mut out[..count], cast!(values));
+			count
 		}
-		impl<Op: WithSimd> crate::NullaryFnOnce for Impl<Op> {
-			type Output = Op::Output;
+		#[cfg(not(target_feature = "avx512vbmi2"))]
+		{
+			compress_store_fallback(mask, values, out)
+		}
+	}
 
-			#[inline(always)]
-			fn call(self) -> Self::Output {
-				self.op.with_simd(self.this)
-			}
+	#[inline(always)]
+	fn compress_store_u16s(self, mask: Self::m16s, values: Self::u16s, out: &mut [u16]) -> usize {
+		#[cfg(target_feature = "avx512vbmi2")]
+		{
+			// SAFETY: the feature is enabled at compile time
+			let vbmi2 = unsafe { Avx512vbmi2::new_unchecked() };
+			let count = mask.0.count_ones() as usize;
+			let values = vbmi2._mm256_maskz_compress_epi16(mask.0, cast!(values));
+			self.partial_store_u16s(&mut out[..count], cast!(values));
+			count
+		}
+		#[cfg(not(target_feature = "avx512vbmi2"))]
+		{
+			compress_store_fallback(mask, values, out)
 		}
-		self.vectorize(Impl { this: self, op })
 	}
 
 	#[inline(always)]
-	fn widening_mul_u32s(self, a: Self::u32s, b: Self::u32s) -> (Self::u32s, Self::u32s) {
-		self.widening_mul_u32x16(a, b)
+	fn compress_store_u32s(self, mask: Self::m32s, values: Self::u32s, out: &mut [u32]) -> usize {
+		let count = mask.0.count_ones() as usize;
+		let values = self
+			.0
+			.avx512f
+			._mm256_maskz_compress_epi32(mask.0, cast!(values));
+		self.partial_store_u32s(&mut out[..count], cast!(values));
+		count
 	}
 
 	#[inline(always)]
-	fn wrapping_dyn_shl_u32s(self, a: Self::u32s, amount: Self::u32s) -> Self::u32s {
-		self.shl_dyn_u32x16(a, self.and_u32x16(amount, self.splat_u32x16(32 - 1)))
+	fn compress_store_u64s(self, mask: Self::m64s, values: Self::u64s, out: &mut [u64]) -> usize {
+		let count = mask.0.count_ones() as usize;
+		let values = self
+			.0
+			.avx512f
+			._mm256_maskz_compress_epi64(mask.0, cast!(values));
+		self.partial_store_u64s(&mut out[..count], cast!(values));
+		count
 	}
 
 	#[inline(always)]
-	fn wrapping_dyn_shr_u32s(self, a: Self::u32s, amount: Self::u32s) -> Self::u32s {
-		self.shr_dyn_u32x16(a, self.and_u32x16(amount, self.splat_u32x16(32 - 1)))
+	fn expand_load_u8s(self, mask: Self::m8s, values: &[u8]) -> Self::u8s {
+		#[cfg(target_feature = "avx512vbmi2")]
+		{
+			// SAFETY: the feature is enabled at compile time
+			let vbmi2 = unsafe { Avx512vbmi2::new_unchecked() };
+			let count = mask.0.count_ones() as usize;
+			let values = self.partial_load_u8s(&values[..count]);
+			cast!(vbmi2._mm256_maskz_expand_epi8(mask.0, cast!(values)))
+		}
+		#[cfg(not(target_feature = "avx512vbmi2"))]
+		{
+			expand_load_fallback(mask, values)
+		}
 	}
 
 	#[inline(always)]
-	fn xor_m32s(self, a: Self::m32s, b: Self::m32s) -> Self::m32s {
-		b16(a.0 ^ b.0)
+	fn expand_load_u16s(self, mask: Self::m16s, values: &[u16]) -> Self::u16s {
+		#[cfg(target_feature = "avx512vbmi2")]
+		{
+			// SAFETY: the feature is enabled at compile time
+			let vbmi2 = unsafe { Avx512vbmi2::new_unchecked() };
+			let count = mask.0.count_ones() as usize;
+			let values = self.partial_load_u16s(&values[..count]);
+			cast!(vbmi2._mm256_maskz_expand_epi16(mask.0, cast!(values)))
+		}
+		#[cfg(not(target_feature = "avx512vbmi2"))]
+		{
+			expand_load_fallback(mask, values)
+		}
 	}
 
 	#[inline(always)]
-	fn xor_m64s(self, a: Self::m64s, b: Self::m64s) -> Self::m64s {
-		b8(a.0 ^ b.0)
+	fn expand_load_u32s(self, mask: Self::m32s, values: &[u32]) -> Self::u32s {
+		let count = mask.0.count_ones() as usize;
+		let values = self.partial_load_u32s(&values[..count]);
+		cast!(
+			self.0
+				.avx512f
+				._mm256_maskz_expand_epi32(mask.0, cast!(values))
+		)
+	}
+
+	#[inline(always)]
+	fn expand_load_u64s(self, mask: Self::m64s, values: &[u64]) -> Self::u64s {
+		let count = mask.0.count_ones() as usize;
+		let values = self.partial_load_u64s(&values[..count]);
+		cast!(
+			self.0
+				.avx512f
+				._mm256_maskz_expand_epi64(mask.0, cast!(values))
+		)
+	}
+
+	#[inline(always)]
+	fn vectorize<Op: WithSimd>(self, op: Op) -> Op::Output {
+		struct Impl<Op> {
+			this: V4_256b,
+			op: Op,
+		}
+		impl<Op: WithSimd> crate::NullaryFnOnce for Impl<Op> {
+			type Output = Op::Output;
+
+			#[inline(always)]
+			fn call(self) -> Self::Output {
+				self.op.with_simd(self.this)
+			}
+		}
+		self.0.vectorize(Impl { this: self, op })
 	}
 
 	#[inline(always)]
 	fn sqrt_f32s(self, a: Self::f32s) -> Self::f32s {
-		self.sqrt_f32x16(a)
+		self.sqrt_f32x8(a)
 	}
 
 	#[inline(always)]
 	fn sqrt_f64s(self, a: Self::f64s) -> Self::f64s {
-		self.sqrt_f64x8(a)
+		self.sqrt_f64x4(a)
 	}
 }
 
@@ -2338,6 +3320,12 @@ impl V4 {
 		cast!(self.avx512f._mm512_cvtpd_ps(cast!(a)))
 	}
 
+	/// Converts a `f64x8` to `i64x8`, elementwise.
+	#[inline(always)]
+	pub fn convert_f64x8_to_i64x8(self, a: f64x8) -> i64x8 {
+		cast!(self.avx512dq._mm512_cvttpd_epi64(cast!(a)))
+	}
+
 	/// Converts a `f64x8` to `i32x8`, elementwise.
 	#[inline(always)]
 	pub fn convert_f64x8_to_i32x8(self, a: f64x8) -> i32x8 {
@@ -2350,6 +3338,12 @@ impl V4 {
 		cast!(self.avx512f._mm512_cvttpd_epu32(cast!(a)))
 	}
 
+	/// Converts a `f64x8` to `u64x8`, elementwise.
+	#[inline(always)]
+	pub fn convert_f64x8_to_u64x8(self, a: f64x8) -> u64x8 {
+		cast!(self.avx512dq._mm512_cvttpd_epu64(cast!(a)))
+	}
+
 	/// Converts a `i16x16` to `i32x16`, elementwise.
 	#[inline(always)]
 	pub fn convert_i16x16_to_i32x16(self, a: i16x16) -> i32x16 {
@@ -2590,6 +3584,12 @@ impl V4 {
 		cast!(self.avx512f._mm256_cvtepi64_epi8(cast!(a)))
 	}
 
+	/// Converts a `i64x8` to `f64x8`, elementwise.
+	#[inline(always)]
+	pub fn convert_i64x8_to_f64x8(self, a: i64x8) -> f64x8 {
+		cast!(self.avx512dq._mm512_cvtepi64_pd(cast!(a)))
+	}
+
 	/// Converts a `i64x8` to `i16x8`, elementwise.
 	#[inline(always)]
 	pub fn convert_i64x8_to_i16x8(self, a: i64x8) -> i16x8 {
@@ -2818,6 +3818,12 @@ impl V4 {
 		cast!(self.avx512bw._mm_cvtepi16_epi8(cast!(a)))
 	}
 
+	/// Converts a `u32x16` to `f32x16`, elementwise.
+	#[inline(always)]
+	pub fn convert_u32x16_to_f32x16(self, a: u32x16) -> f32x16 {
+		cast!(self.avx512f._mm512_cvtepu32_ps(cast!(a)))
+	}
+
 	/// Converts a `u32x16` to `i16x16`, elementwise.
 	#[inline(always)]
 	pub fn convert_u32x16_to_i16x16(self, a: u32x16) -> i16x16 {
@@ -2980,6 +3986,12 @@ impl V4 {
 		cast!(self.avx512f._mm256_cvtepi64_epi8(cast!(a)))
 	}
 
+	/// Converts a `u64x8` to `f64x8`, elementwise.
+	#[inline(always)]
+	pub fn convert_u64x8_to_f64x8(self, a: u64x8) -> f64x8 {
+		cast!(self.avx512dq._mm512_cvtepu64_pd(cast!(a)))
+	}
+
 	/// Converts a `u64x8` to `i16x8`, elementwise.
 	#[inline(always)]
 	pub fn convert_u64x8_to_i16x8(self, a: u64x8) -> i16x8 {
@@ -3342,7 +4354,7 @@ impl V4 {
 	pub fn round_f32x16(self, a: f32x16) -> f32x16 {
 		cast!(
 			self.avx512f
-				._mm512_roundscale_pd::<_MM_FROUND_TO_NEAREST_INT>(cast!(a)),
+				._mm512_roundscale_ps::<_MM_FROUND_TO_NEAREST_INT>(cast!(a)),
 		)
 	}
 
@@ -3698,6 +4710,14 @@ impl V4 {
 		cast!(self.avx512f._mm512_slli_epi64::<AMOUNT>(cast!(a)))
 	}
 
+	/// Shift the bits of each lane of `a` to the left by the element in the corresponding lane in
+	/// `amount`, while shifting in zeros.  
+	/// Shifting by a value greater than the bit width of the type sets the result to zero.
+	#[inline(always)]
+	pub fn shl_dyn_i16x32(self, a: i16x32, amount: u16x32) -> i16x32 {
+		cast!(self.avx512bw._mm512_sllv_epi16(cast!(a), cast!(amount)))
+	}
+
 	/// Shift the bits of each lane of `a` to the left by the element in the corresponding lane in
 	/// `amount`, while shifting in zeros.  
 	/// Shifting by a value greater than the bit width of the type sets the result to zero.
@@ -3714,6 +4734,14 @@ impl V4 {
 		cast!(self.avx512f._mm512_sllv_epi64(cast!(a), cast!(amount)))
 	}
 
+	/// Shift the bits of each lane of `a` to the left by the element in the corresponding lane in
+	/// `amount`, while shifting in zeros.  
+	/// Shifting by a value greater than the bit width of the type sets the result to zero.
+	#[inline(always)]
+	pub fn shl_dyn_u16x32(self, a: u16x32, amount: u16x32) -> u16x32 {
+		cast!(self.avx512bw._mm512_sllv_epi16(cast!(a), cast!(amount)))
+	}
+
 	/// Shift the bits of each lane of `a` to the left by the element in the corresponding lane in
 	/// `amount`, while shifting in zeros.  
 	/// Shifting by a value greater than the bit width of the type sets the result to zero.
@@ -3839,6 +4867,15 @@ impl V4 {
 		cast!(self.avx512f._mm512_srli_epi64::<AMOUNT>(cast!(a)))
 	}
 
+	/// Shift the bits of each lane of `a` to the right by the element in the corresponding lane in
+	/// `amount`, while shifting in sign bits.  
+	/// Shifting by a value greater than the bit width of the type sets the result to zero if the
+	/// sign bit is not set, and to `-1` if the sign bit is set.
+	#[inline(always)]
+	pub fn shr_dyn_i16x32(self, a: i16x32, amount: u16x32) -> i16x32 {
+		cast!(self.avx512bw._mm512_srav_epi16(cast!(a), cast!(amount)))
+	}
+
 	/// Shift the bits of each lane of `a` to the right by the element in the corresponding lane in
 	/// `amount`, while shifting in sign bits.  
 	/// Shifting by a value greater than the bit width of the type sets the result to zero if the
@@ -3875,6 +4912,14 @@ impl V4 {
 		cast!(self.avx512f._mm512_srav_epi64(cast!(a), cast!(amount)))
 	}
 
+	/// Shift the bits of each lane of `a` to the right by the element in the corresponding lane in
+	/// `amount`, while shifting in zeros.  
+	/// Shifting by a value greater than the bit width of the type sets the result to zero.
+	#[inline(always)]
+	pub fn shr_dyn_u16x32(self, a: u16x32, amount: u16x32) -> u16x32 {
+		cast!(self.avx512bw._mm512_srlv_epi16(cast!(a), cast!(amount)))
+	}
+
 	/// Shift the bits of each lane of `a` to the right by the element in the corresponding lane in
 	/// `amount`, while shifting in zeros.  
 	/// Shifting by a value greater than the bit width of the type sets the result to zero.
@@ -4043,7 +5088,7 @@ impl V4 {
 	pub fn truncate_f32x16(self, a: f32x16) -> f32x16 {
 		cast!(
 			self.avx512f
-				._mm512_roundscale_pd::<_MM_FROUND_TO_ZERO>(cast!(a)),
+				._mm512_roundscale_ps::<_MM_FROUND_TO_ZERO>(cast!(a)),
 		)
 	}
 